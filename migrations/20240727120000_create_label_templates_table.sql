@@ -0,0 +1,11 @@
+CREATE TABLE label_templates (
+    id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    organization VARCHAR(255) NOT NULL,
+    name VARCHAR(255) NOT NULL,
+    page_width REAL NOT NULL,
+    page_height REAL NOT NULL,
+    font VARCHAR(32) NOT NULL,
+    font_size REAL NOT NULL,
+    fields VARCHAR(255) NOT NULL
+);
+CREATE UNIQUE INDEX label_templates_organization_name_key ON label_templates (organization, name);
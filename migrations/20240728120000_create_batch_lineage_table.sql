@@ -0,0 +1,9 @@
+CREATE TABLE batch_lineage (
+    parent_id INTEGER NOT NULL,
+    child_id INTEGER NOT NULL,
+    quantity REAL NOT NULL,
+    PRIMARY KEY (parent_id, child_id),
+    FOREIGN KEY (parent_id) REFERENCES batches (id),
+    FOREIGN KEY (child_id) REFERENCES batches (id)
+);
+CREATE INDEX batch_lineage_child_id_key ON batch_lineage (child_id);
@@ -0,0 +1,10 @@
+CREATE TABLE batch_events (
+    id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    batch_id INTEGER NOT NULL,
+    event_type VARCHAR(32) NOT NULL,
+    occurred_at DATETIME NOT NULL,
+    operator VARCHAR(255),
+    notes TEXT,
+    FOREIGN KEY (batch_id) REFERENCES batches (id)
+);
+CREATE INDEX batch_events_batch_id_key ON batch_events (batch_id, occurred_at);
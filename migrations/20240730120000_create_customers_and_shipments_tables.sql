@@ -0,0 +1,25 @@
+CREATE TABLE customers (
+    id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    name VARCHAR(255) NOT NULL,
+    document VARCHAR(32),
+    address VARCHAR(255),
+    email VARCHAR(255)
+);
+CREATE TABLE shipments (
+    id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    customer_id INTEGER NOT NULL,
+    dispatched_at DATE NOT NULL,
+    invoice_number VARCHAR(64) NOT NULL,
+    FOREIGN KEY (customer_id) REFERENCES customers (id)
+);
+CREATE TABLE shipment_items (
+    id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    shipment_id INTEGER NOT NULL,
+    batch_id INTEGER NOT NULL,
+    quantity REAL NOT NULL,
+    FOREIGN KEY (shipment_id) REFERENCES shipments (id) ON DELETE CASCADE,
+    FOREIGN KEY (batch_id) REFERENCES batches (id)
+);
+CREATE INDEX shipments_customer_id_key ON shipments (customer_id);
+CREATE INDEX shipment_items_shipment_id_key ON shipment_items (shipment_id);
+CREATE INDEX shipment_items_batch_id_key ON shipment_items (batch_id);
@@ -0,0 +1,21 @@
+CREATE TABLE recalls (
+    id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    crop_id INTEGER,
+    batch_id INTEGER,
+    cultivation VARCHAR(255),
+    start_date DATE,
+    end_date DATE,
+    reason TEXT NOT NULL,
+    status VARCHAR(32) NOT NULL DEFAULT 'open',
+    created_at DATETIME NOT NULL,
+    FOREIGN KEY (crop_id) REFERENCES crops (id),
+    FOREIGN KEY (batch_id) REFERENCES batches (id)
+);
+CREATE TABLE recall_batches (
+    recall_id INTEGER NOT NULL,
+    batch_id INTEGER NOT NULL,
+    PRIMARY KEY (recall_id, batch_id),
+    FOREIGN KEY (recall_id) REFERENCES recalls (id) ON DELETE CASCADE,
+    FOREIGN KEY (batch_id) REFERENCES batches (id)
+);
+CREATE INDEX recall_batches_batch_id_key ON recall_batches (batch_id);
@@ -0,0 +1,7 @@
+ALTER TABLE batches ADD COLUMN quantity_unit VARCHAR(16) NOT NULL DEFAULT 'kg';
+ALTER TABLE crops ADD COLUMN area_unit VARCHAR(16) NOT NULL DEFAULT 'ha';
+CREATE TABLE packings (
+    id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    name VARCHAR(255) NOT NULL UNIQUE,
+    kg_per_unit REAL NOT NULL
+);
@@ -0,0 +1,16 @@
+CREATE TABLE farms (
+    id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    name VARCHAR(255) NOT NULL,
+    document VARCHAR(32),
+    address VARCHAR(255)
+);
+CREATE TABLE plots (
+    id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    farm_id INTEGER NOT NULL,
+    name VARCHAR(255) NOT NULL,
+    boundary TEXT NOT NULL,
+    area REAL NOT NULL,
+    FOREIGN KEY (farm_id) REFERENCES farms (id)
+);
+CREATE INDEX plots_farm_id_key ON plots (farm_id);
+ALTER TABLE crops ADD COLUMN plot_id INTEGER REFERENCES plots (id);
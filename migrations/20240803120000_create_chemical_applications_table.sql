@@ -0,0 +1,12 @@
+CREATE TABLE chemical_applications (
+    id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    crop_id INTEGER NOT NULL,
+    product VARCHAR(255) NOT NULL,
+    active_ingredient VARCHAR(255) NOT NULL,
+    dose REAL NOT NULL,
+    dose_unit VARCHAR(32) NOT NULL,
+    applied_at DATE NOT NULL,
+    withdrawal_days INTEGER NOT NULL,
+    FOREIGN KEY (crop_id) REFERENCES crops (id)
+);
+CREATE INDEX chemical_applications_crop_id_key ON chemical_applications (crop_id, applied_at);
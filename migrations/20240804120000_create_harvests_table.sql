@@ -0,0 +1,27 @@
+CREATE TABLE harvests (
+    id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    crop_id INTEGER NOT NULL,
+    harvested_at DATE NOT NULL,
+    quantity REAL NOT NULL,
+    quantity_unit VARCHAR(16) NOT NULL DEFAULT 'kg',
+    team VARCHAR(255),
+    FOREIGN KEY (crop_id) REFERENCES crops (id)
+);
+CREATE INDEX harvests_crop_id_key ON harvests (crop_id, harvested_at);
+
+-- Each harvest date recorded on a crop becomes its first harvest. The
+-- harvested quantity was never recorded, so it starts at zero.
+INSERT INTO harvests (crop_id, harvested_at, quantity)
+SELECT id, harvested_at, 0
+FROM crops
+WHERE harvested_at IS NOT NULL;
+
+ALTER TABLE batches ADD COLUMN harvest_id INTEGER REFERENCES harvests (id);
+UPDATE batches
+SET harvest_id = (SELECT id FROM harvests WHERE harvests.crop_id = batches.crop_id)
+WHERE date >= (SELECT harvested_at FROM crops WHERE crops.id = batches.crop_id);
+
+-- A harvest date does not say the crop cycle is over, so every crop starts
+-- open and is closed explicitly.
+ALTER TABLE crops DROP COLUMN harvested_at;
+ALTER TABLE crops ADD COLUMN closed_at DATE;
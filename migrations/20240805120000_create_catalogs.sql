@@ -0,0 +1,77 @@
+CREATE TABLE catalog_entries (
+    id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    kind VARCHAR(32) NOT NULL,
+    code VARCHAR(255) NOT NULL COLLATE NOCASE,
+    description VARCHAR(255) NOT NULL,
+    active BOOLEAN NOT NULL DEFAULT TRUE,
+    UNIQUE (kind, code)
+);
+
+-- Values differing only in case or spacing ("caixa 20kg", "Caixa 20 kg")
+-- become a single entry.
+INSERT INTO catalog_entries (kind, code, description)
+SELECT 'classification', MIN(TRIM(classification)), MIN(TRIM(classification))
+FROM batches
+WHERE TRIM(classification) <> ''
+GROUP BY LOWER(REPLACE(TRIM(classification), ' ', ''));
+
+INSERT INTO catalog_entries (kind, code, description)
+SELECT 'processing', MIN(TRIM(processing)), MIN(TRIM(processing))
+FROM batches
+WHERE TRIM(processing) <> ''
+GROUP BY LOWER(REPLACE(TRIM(processing), ' ', ''));
+
+UPDATE batches
+SET classification = (
+    SELECT e.code FROM catalog_entries e
+    WHERE e.kind = 'classification'
+        AND LOWER(REPLACE(e.code, ' ', '')) = LOWER(REPLACE(TRIM(batches.classification), ' ', ''))
+)
+WHERE classification IS NOT NULL;
+
+UPDATE batches
+SET processing = (
+    SELECT e.code FROM catalog_entries e
+    WHERE e.kind = 'processing'
+        AND LOWER(REPLACE(e.code, ' ', '')) = LOWER(REPLACE(TRIM(batches.processing), ' ', ''))
+)
+WHERE processing IS NOT NULL;
+
+-- Packings become a catalog too. The weight is now optional, since packings
+-- found only in batches have none registered.
+CREATE TABLE packings_catalog (
+    id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    code VARCHAR(255) NOT NULL UNIQUE COLLATE NOCASE,
+    description VARCHAR(255) NOT NULL,
+    kg_per_unit REAL,
+    active BOOLEAN NOT NULL DEFAULT TRUE
+);
+
+INSERT INTO packings_catalog (id, code, description, kg_per_unit)
+SELECT id, name, name, kg_per_unit
+FROM packings;
+
+DROP TABLE packings;
+ALTER TABLE packings_catalog RENAME TO packings;
+
+INSERT INTO packings (code, description)
+SELECT MIN(TRIM(b.packing)), MIN(TRIM(b.packing))
+FROM batches b
+WHERE TRIM(b.packing) <> ''
+    AND NOT EXISTS (
+        SELECT 1 FROM packings p
+        WHERE LOWER(REPLACE(p.code, ' ', '')) = LOWER(REPLACE(TRIM(b.packing), ' ', ''))
+    )
+GROUP BY LOWER(REPLACE(TRIM(b.packing), ' ', ''));
+
+UPDATE batches
+SET packing = COALESCE((
+    SELECT MIN(p.code) FROM packings p
+    WHERE LOWER(REPLACE(p.code, ' ', '')) = LOWER(REPLACE(TRIM(batches.packing), ' ', ''))
+), packing);
+
+-- Batches keep the code itself. These back the lookups made before a catalog
+-- entry is renamed or removed.
+CREATE INDEX batches_classification_key ON batches (classification);
+CREATE INDEX batches_processing_key ON batches (processing);
+CREATE INDEX batches_packing_key ON batches (packing);
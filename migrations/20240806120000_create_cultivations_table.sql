@@ -0,0 +1,24 @@
+CREATE TABLE cultivations (
+    id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    name VARCHAR(255) NOT NULL COLLATE NOCASE,
+    scientific_name VARCHAR(255),
+    variety VARCHAR(255) COLLATE NOCASE,
+    cycle_days INTEGER,
+    shelf_life_days INTEGER
+);
+CREATE UNIQUE INDEX cultivations_name_variety_key ON cultivations (name, COALESCE(variety, ''));
+
+-- Cultivations were free text and could be left blank. Those crops share a
+-- placeholder cultivation, since a cultivation must have a name.
+INSERT INTO cultivations (name)
+SELECT MIN(name)
+FROM (SELECT COALESCE(NULLIF(TRIM(cultivation), ''), 'Não informada') AS name FROM crops)
+GROUP BY LOWER(name);
+
+ALTER TABLE crops ADD COLUMN cultivation_id INTEGER REFERENCES cultivations (id);
+UPDATE crops
+SET cultivation_id = (
+    SELECT id FROM cultivations
+    WHERE name = COALESCE(NULLIF(TRIM(crops.cultivation), ''), 'Não informada') AND variety IS NULL
+);
+ALTER TABLE crops DROP COLUMN cultivation;
@@ -0,0 +1,7 @@
+CREATE TABLE products (
+    id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    gtin VARCHAR(14) NOT NULL UNIQUE,
+    name VARCHAR(255) NOT NULL,
+    description VARCHAR(255)
+);
+ALTER TABLE batches ADD COLUMN product_id INTEGER REFERENCES products (id);
@@ -0,0 +1,9 @@
+CREATE TABLE epcis_events (
+    id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    event_id VARCHAR(255) UNIQUE,
+    event_type VARCHAR(32) NOT NULL,
+    event_date DATE NOT NULL,
+    document TEXT NOT NULL,
+    captured_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+CREATE INDEX epcis_events_event_date_key ON epcis_events (event_date);
@@ -0,0 +1,11 @@
+CREATE TABLE audit_log (
+    id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    entity VARCHAR(32) NOT NULL,
+    entity_id INTEGER NOT NULL,
+    action VARCHAR(16) NOT NULL,
+    actor VARCHAR(255),
+    changed_at DATETIME NOT NULL,
+    before TEXT,
+    after TEXT
+);
+CREATE INDEX audit_log_entity_key ON audit_log (entity, entity_id);
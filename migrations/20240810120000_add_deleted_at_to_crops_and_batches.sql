@@ -0,0 +1,2 @@
+ALTER TABLE crops ADD COLUMN deleted_at DATETIME;
+ALTER TABLE batches ADD COLUMN deleted_at DATETIME;
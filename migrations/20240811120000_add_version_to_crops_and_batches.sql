@@ -0,0 +1,2 @@
+ALTER TABLE crops ADD COLUMN version INTEGER NOT NULL DEFAULT 1;
+ALTER TABLE batches ADD COLUMN version INTEGER NOT NULL DEFAULT 1;
@@ -0,0 +1,2 @@
+ALTER TABLE label_templates ADD COLUMN is_default BOOLEAN NOT NULL DEFAULT FALSE;
+CREATE UNIQUE INDEX label_templates_organization_default_key ON label_templates (organization) WHERE is_default;
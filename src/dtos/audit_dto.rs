@@ -0,0 +1,40 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::models::{AuditAction, AuditEntity, AuditEntry};
+
+#[derive(Deserialize)]
+pub struct AuditQueryDTO {
+    pub entity: Option<AuditEntity>,
+    /// ID of the record, only meaningful along with `entity`.
+    pub id: Option<i64>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuditEntryResponseDTO {
+    pub id: i64,
+    pub entity: AuditEntity,
+    pub entity_id: i64,
+    pub action: AuditAction,
+    pub actor: Option<String>,
+    pub changed_at: NaiveDateTime,
+    pub before: Option<Value>,
+    pub after: Option<Value>,
+}
+
+impl From<&AuditEntry> for AuditEntryResponseDTO {
+    fn from(entry: &AuditEntry) -> Self {
+        Self {
+            id: entry.id().unwrap(),
+            entity: entry.entity(),
+            entity_id: entry.entity_id(),
+            action: entry.action(),
+            actor: entry.actor().clone(),
+            changed_at: entry.changed_at(),
+            before: entry.before().clone(),
+            after: entry.after().clone(),
+        }
+    }
+}
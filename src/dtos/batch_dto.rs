@@ -1,4 +1,7 @@
-use crate::misc::date_validation::past_or_present_validation;
+use crate::{
+    misc::date_validation::past_or_present_validation,
+    models::{Batch, QuantityUnit},
+};
 use serde::{Deserialize, Serialize};
 use validator::Validate;
 
@@ -6,13 +9,20 @@ use validator::Validate;
 #[serde(rename_all = "camelCase")]
 pub struct BatchRequestDTO {
     pub crop_id: i64,
+    /// Code from the classification catalog.
     pub classification: Option<String>,
+    /// Code from the processing catalog.
     pub processing: Option<String>,
+    /// Code from the packing catalog.
     pub packing: String,
     #[validate(range(min = 0.0))]
     pub quantity: f64,
+    #[serde(default)]
+    pub quantity_unit: QuantityUnit,
     #[validate(custom(function = "past_or_present_validation"))]
     pub date: chrono::NaiveDate,
+    pub harvest_id: Option<i64>,
+    pub product_id: Option<i64>,
 }
 
 #[derive(Serialize)]
@@ -24,5 +34,84 @@ pub struct BatchResponseDTO {
     pub processing: Option<String>,
     pub packing: String,
     pub quantity: f64,
+    pub quantity_unit: QuantityUnit,
+    pub available_quantity: f64,
     pub tracking_code: String,
+    pub harvest: Option<i64>,
+    pub product: Option<i64>,
+    pub version: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deleted_at: Option<chrono::NaiveDateTime>,
+}
+
+impl From<&Batch> for BatchResponseDTO {
+    fn from(batch: &Batch) -> Self {
+        Self {
+            id: batch.id().unwrap(),
+            crop: batch.crop().id().unwrap(),
+            classification: batch.classification().clone(),
+            processing: batch.processing().clone(),
+            packing: batch.packing().to_string(),
+            quantity: batch.quantity(),
+            quantity_unit: batch.quantity_unit(),
+            available_quantity: batch.available_quantity(),
+            tracking_code: batch.tracking_code().as_ref().unwrap().to_string(),
+            harvest: batch.harvest_id(),
+            product: batch.product_id(),
+            version: batch.version(),
+            deleted_at: *batch.deleted_at(),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QuantityUnitQueryDTO {
+    pub unit: Option<QuantityUnit>,
+    /// Also returns deleted batches.
+    #[serde(default)]
+    pub include_deleted: bool,
+}
+
+#[derive(Serialize, Deserialize, Clone, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchSplitChildDTO {
+    pub packing: String,
+    #[validate(range(exclusive_min = 0.0))]
+    pub quantity: f64,
+}
+
+#[derive(Deserialize, Clone, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchSplitRequestDTO {
+    #[validate(length(min = 1), nested)]
+    pub children: Vec<BatchSplitChildDTO>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchSplitResponseDTO {
+    pub parent: BatchResponseDTO,
+    pub children: Vec<BatchResponseDTO>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchMergeSourceDTO {
+    pub batch_id: i64,
+    #[validate(range(exclusive_min = 0.0))]
+    pub quantity: Option<f64>,
+}
+
+#[derive(Deserialize, Clone, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchMergeRequestDTO {
+    #[validate(length(min = 2), nested)]
+    pub sources: Vec<BatchMergeSourceDTO>,
+    pub classification: Option<String>,
+    pub processing: Option<String>,
+    pub packing: String,
+    #[validate(custom(function = "past_or_present_validation"))]
+    pub date: chrono::NaiveDate,
+    pub product_id: Option<i64>,
 }
@@ -0,0 +1,43 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::{
+    misc::date_validation::past_or_present_datetime_validation,
+    models::{BatchEvent, BatchEventType},
+};
+
+#[derive(Deserialize, Clone, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchEventRequestDTO {
+    pub event_type: BatchEventType,
+    #[validate(custom(function = "past_or_present_datetime_validation"))]
+    pub occurred_at: NaiveDateTime,
+    #[validate(length(max = 255))]
+    pub operator: Option<String>,
+    pub notes: Option<String>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchEventResponseDTO {
+    pub id: i64,
+    pub batch: i64,
+    pub event_type: BatchEventType,
+    pub occurred_at: NaiveDateTime,
+    pub operator: Option<String>,
+    pub notes: Option<String>,
+}
+
+impl From<&BatchEvent> for BatchEventResponseDTO {
+    fn from(event: &BatchEvent) -> Self {
+        Self {
+            id: event.id().unwrap(),
+            batch: event.batch_id(),
+            event_type: event.event_type(),
+            occurred_at: event.occurred_at(),
+            operator: event.operator().clone(),
+            notes: event.notes().clone(),
+        }
+    }
+}
@@ -0,0 +1,46 @@
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::models::{CatalogEntry, CatalogKind};
+
+fn default_active() -> bool {
+    true
+}
+
+#[derive(Deserialize, Clone, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct CatalogEntryRequestDTO {
+    #[validate(length(min = 1, max = 255))]
+    pub code: String,
+    #[validate(length(min = 1, max = 255))]
+    pub description: String,
+    #[serde(default = "default_active")]
+    pub active: bool,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CatalogEntryResponseDTO {
+    pub id: i64,
+    pub kind: CatalogKind,
+    pub code: String,
+    pub description: String,
+    pub active: bool,
+}
+
+impl From<&CatalogEntry> for CatalogEntryResponseDTO {
+    fn from(entry: &CatalogEntry) -> Self {
+        Self {
+            id: entry.id().unwrap(),
+            kind: entry.kind(),
+            code: entry.code().to_string(),
+            description: entry.description().to_string(),
+            active: entry.active(),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+pub struct CatalogQueryDTO {
+    pub active: Option<bool>,
+}
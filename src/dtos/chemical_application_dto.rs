@@ -0,0 +1,88 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::{
+    misc::date_validation::past_or_present_validation,
+    models::{ChemicalApplication, Crop},
+};
+
+use super::CropResponseDTO;
+
+#[derive(Deserialize, Clone, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct ChemicalApplicationRequestDTO {
+    #[validate(length(min = 1, max = 255))]
+    pub product: String,
+    #[validate(length(min = 1, max = 255))]
+    pub active_ingredient: String,
+    #[validate(range(exclusive_min = 0.0))]
+    pub dose: f64,
+    #[validate(length(min = 1, max = 32))]
+    pub dose_unit: String,
+    #[validate(custom(function = "past_or_present_validation"))]
+    pub applied_at: NaiveDate,
+    #[validate(range(min = 0, max = 365))]
+    pub withdrawal_days: i64,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChemicalApplicationResponseDTO {
+    pub id: i64,
+    pub crop: i64,
+    pub product: String,
+    pub active_ingredient: String,
+    pub dose: f64,
+    pub dose_unit: String,
+    pub applied_at: NaiveDate,
+    pub withdrawal_days: i64,
+    pub withdrawal_ends_at: NaiveDate,
+}
+
+impl From<&ChemicalApplication> for ChemicalApplicationResponseDTO {
+    fn from(application: &ChemicalApplication) -> Self {
+        Self {
+            id: application.id().unwrap(),
+            crop: application.crop_id(),
+            product: application.product().to_string(),
+            active_ingredient: application.active_ingredient().to_string(),
+            dose: application.dose(),
+            dose_unit: application.dose_unit().to_string(),
+            applied_at: application.applied_at(),
+            withdrawal_days: application.withdrawal_days(),
+            withdrawal_ends_at: application.withdrawal_ends_at(),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+pub struct WithdrawalQueryDTO {
+    pub date: Option<NaiveDate>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CropWithdrawalDTO {
+    pub crop: CropResponseDTO,
+    /// Last of the withdrawal end dates, i.e. when harvest is allowed again.
+    pub withdrawal_ends_at: NaiveDate,
+    pub applications: Vec<ChemicalApplicationResponseDTO>,
+}
+
+impl CropWithdrawalDTO {
+    pub fn new(crop: &Crop, applications: &[ChemicalApplication]) -> Self {
+        Self {
+            crop: CropResponseDTO::from(crop),
+            withdrawal_ends_at: applications
+                .iter()
+                .map(ChemicalApplication::withdrawal_ends_at)
+                .max()
+                .unwrap(),
+            applications: applications
+                .iter()
+                .map(ChemicalApplicationResponseDTO::from)
+                .collect(),
+        }
+    }
+}
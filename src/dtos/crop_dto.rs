@@ -1,4 +1,7 @@
-use crate::misc::date_validation::past_or_present_validation;
+use crate::{
+    misc::date_validation::past_or_present_validation,
+    models::{AreaUnit, Crop},
+};
 use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
 use validator::Validate;
@@ -7,12 +10,22 @@ use validator::Validate;
 #[serde(rename_all = "camelCase")]
 pub struct CropRequestDTO {
     pub name: String,
-    pub area: f64,
-    pub cultivation: String,
+    /// Defaults to the plot's area when omitted.
+    #[validate(range(min = 0.0))]
+    pub area: Option<f64>,
+    #[serde(default)]
+    pub area_unit: AreaUnit,
+    pub cultivation_id: i64,
     #[validate(custom(function = "past_or_present_validation"))]
     pub planted_at: NaiveDate,
+    pub plot_id: Option<i64>,
+}
+
+#[derive(Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct CropCloseRequestDTO {
     #[validate(custom(function = "past_or_present_validation"))]
-    pub harvested_at: Option<NaiveDate>,
+    pub closed_at: NaiveDate,
 }
 
 #[derive(Serialize)]
@@ -21,7 +34,42 @@ pub struct CropResponseDTO {
     pub id: i64,
     pub name: String,
     pub area: f64,
+    pub area_unit: AreaUnit,
+    pub cultivation_id: i64,
     pub cultivation: String,
+    pub variety: Option<String>,
     pub planted_at: NaiveDate,
-    pub harvested_at: Option<NaiveDate>,
+    pub closed_at: Option<NaiveDate>,
+    pub plot: Option<i64>,
+    pub version: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deleted_at: Option<chrono::NaiveDateTime>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AreaUnitQueryDTO {
+    pub unit: Option<AreaUnit>,
+    /// Also returns deleted crops.
+    #[serde(default)]
+    pub include_deleted: bool,
+}
+
+impl From<&Crop> for CropResponseDTO {
+    fn from(crop: &Crop) -> Self {
+        Self {
+            id: crop.id().unwrap(),
+            name: crop.name().to_string(),
+            area: crop.area(),
+            area_unit: crop.area_unit(),
+            cultivation_id: crop.cultivation().id().unwrap(),
+            cultivation: crop.cultivation().name().to_string(),
+            variety: crop.cultivation().variety().clone(),
+            planted_at: crop.planted_at(),
+            closed_at: *crop.closed_at(),
+            plot: crop.plot_id(),
+            version: crop.version(),
+            deleted_at: *crop.deleted_at(),
+        }
+    }
 }
@@ -0,0 +1,50 @@
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::models::Cultivation;
+
+#[derive(Deserialize, Clone, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct CultivationRequestDTO {
+    #[validate(length(min = 1, max = 255))]
+    pub name: String,
+    #[validate(length(max = 255))]
+    pub scientific_name: Option<String>,
+    #[validate(length(max = 255))]
+    pub variety: Option<String>,
+    #[validate(range(min = 1))]
+    pub cycle_days: Option<i64>,
+    #[validate(range(min = 1))]
+    pub shelf_life_days: Option<i64>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CultivationResponseDTO {
+    pub id: i64,
+    pub name: String,
+    pub scientific_name: Option<String>,
+    pub variety: Option<String>,
+    pub cycle_days: Option<i64>,
+    pub shelf_life_days: Option<i64>,
+}
+
+impl From<&Cultivation> for CultivationResponseDTO {
+    fn from(cultivation: &Cultivation) -> Self {
+        Self {
+            id: cultivation.id().unwrap(),
+            name: cultivation.name().to_string(),
+            scientific_name: cultivation.scientific_name().clone(),
+            variety: cultivation.variety().clone(),
+            cycle_days: cultivation.cycle_days(),
+            shelf_life_days: cultivation.shelf_life_days(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CultivationImportResponseDTO {
+    pub inserted: usize,
+    pub updated: usize,
+}
@@ -0,0 +1,40 @@
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::{misc::document::cpf_cnpj_validation, models::Customer};
+
+#[derive(Deserialize, Clone, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomerRequestDTO {
+    #[validate(length(min = 1, max = 255))]
+    pub name: String,
+    /// CPF or CNPJ.
+    #[validate(length(max = 32), custom(function = "cpf_cnpj_validation"))]
+    pub document: Option<String>,
+    #[validate(length(max = 255))]
+    pub address: Option<String>,
+    #[validate(email, length(max = 255))]
+    pub email: Option<String>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomerResponseDTO {
+    pub id: i64,
+    pub name: String,
+    pub document: Option<String>,
+    pub address: Option<String>,
+    pub email: Option<String>,
+}
+
+impl From<&Customer> for CustomerResponseDTO {
+    fn from(customer: &Customer) -> Self {
+        Self {
+            id: customer.id().unwrap(),
+            name: customer.name().to_string(),
+            document: customer.document().clone(),
+            address: customer.address().clone(),
+            email: customer.email().clone(),
+        }
+    }
+}
@@ -0,0 +1,64 @@
+use chrono::{DateTime, FixedOffset, NaiveDate};
+use serde::{Deserialize, Serialize};
+
+use crate::models::EpcisEvent;
+
+#[derive(Deserialize)]
+pub struct EpcisQueryDTO {
+    pub from: Option<NaiveDate>,
+    pub to: Option<NaiveDate>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EpcisBodyDTO {
+    /// Parsed one by one so errors can point at the event.
+    pub event_list: Vec<serde_json::Value>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EpcisDocumentDTO {
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub epcis_body: EpcisBodyDTO,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EpcisResultsBodyDTO {
+    pub event_list: Vec<EpcisEvent>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EpcisQueryResultsDTO {
+    pub query_name: &'static str,
+    pub results_body: EpcisResultsBodyDTO,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EpcisQueryBodyDTO {
+    pub query_results: EpcisQueryResultsDTO,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EpcisQueryDocumentDTO {
+    #[serde(rename = "@context")]
+    pub context: Vec<&'static str>,
+    #[serde(rename = "type")]
+    pub kind: &'static str,
+    pub schema_version: &'static str,
+    pub creation_date: DateTime<FixedOffset>,
+    pub epcis_body: EpcisQueryBodyDTO,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EpcisCaptureResponseDTO {
+    pub captured: usize,
+    /// Events skipped because their `eventID` had already been captured.
+    pub duplicates: usize,
+}
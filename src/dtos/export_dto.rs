@@ -0,0 +1,31 @@
+use serde::Deserialize;
+
+use crate::models::{AreaUnit, QuantityUnit};
+
+#[derive(Deserialize, Clone, Copy, Default, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ExportFormat {
+    #[default]
+    Csv,
+    Xlsx,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CropExportQueryDTO {
+    #[serde(default)]
+    pub format: ExportFormat,
+    pub unit: Option<AreaUnit>,
+    #[serde(default)]
+    pub include_deleted: bool,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchExportQueryDTO {
+    #[serde(default)]
+    pub format: ExportFormat,
+    pub unit: Option<QuantityUnit>,
+    #[serde(default)]
+    pub include_deleted: bool,
+}
@@ -0,0 +1,36 @@
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::{misc::document::cpf_cnpj_validation, models::Farm};
+
+#[derive(Deserialize, Clone, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct FarmRequestDTO {
+    #[validate(length(min = 1, max = 255))]
+    pub name: String,
+    /// CPF or CNPJ.
+    #[validate(length(max = 32), custom(function = "cpf_cnpj_validation"))]
+    pub document: Option<String>,
+    #[validate(length(max = 255))]
+    pub address: Option<String>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FarmResponseDTO {
+    pub id: i64,
+    pub name: String,
+    pub document: Option<String>,
+    pub address: Option<String>,
+}
+
+impl From<&Farm> for FarmResponseDTO {
+    fn from(farm: &Farm) -> Self {
+        Self {
+            id: farm.id().unwrap(),
+            name: farm.name().to_string(),
+            document: farm.document().clone(),
+            address: farm.address().clone(),
+        }
+    }
+}
@@ -0,0 +1,45 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::{
+    misc::date_validation::past_or_present_validation,
+    models::{Harvest, QuantityUnit},
+};
+
+#[derive(Deserialize, Clone, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct HarvestRequestDTO {
+    #[validate(custom(function = "past_or_present_validation"))]
+    pub harvested_at: NaiveDate,
+    #[validate(range(min = 0.0))]
+    pub quantity: f64,
+    #[serde(default)]
+    pub quantity_unit: QuantityUnit,
+    #[validate(length(max = 255))]
+    pub team: Option<String>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HarvestResponseDTO {
+    pub id: i64,
+    pub crop: i64,
+    pub harvested_at: NaiveDate,
+    pub quantity: f64,
+    pub quantity_unit: QuantityUnit,
+    pub team: Option<String>,
+}
+
+impl From<&Harvest> for HarvestResponseDTO {
+    fn from(harvest: &Harvest) -> Self {
+        Self {
+            id: harvest.id().unwrap(),
+            crop: harvest.crop_id(),
+            harvested_at: harvest.harvested_at(),
+            quantity: harvest.quantity(),
+            quantity_unit: harvest.quantity_unit(),
+            team: harvest.team().clone(),
+        }
+    }
+}
@@ -0,0 +1,46 @@
+use serde::{Deserialize, Serialize};
+
+use crate::models::{ImportReport, ImportRowError};
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportQueryDTO {
+    /// Only checks the rows, without inserting them.
+    #[serde(default)]
+    pub dry_run: bool,
+}
+
+#[derive(Serialize)]
+pub struct ImportRowErrorDTO {
+    pub line: u64,
+    pub message: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportResponseDTO {
+    pub dry_run: bool,
+    pub rows: usize,
+    pub inserted: usize,
+    pub errors: Vec<ImportRowErrorDTO>,
+}
+
+impl From<&ImportRowError> for ImportRowErrorDTO {
+    fn from(error: &ImportRowError) -> Self {
+        Self {
+            line: error.line,
+            message: error.message.clone(),
+        }
+    }
+}
+
+impl ImportResponseDTO {
+    pub fn new(report: &ImportReport, dry_run: bool) -> Self {
+        Self {
+            dry_run,
+            rows: report.rows,
+            inserted: report.inserted,
+            errors: report.errors.iter().map(ImportRowErrorDTO::from).collect(),
+        }
+    }
+}
@@ -0,0 +1,67 @@
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::{
+    misc::codes::ImageFormat,
+    models::{LabelField, LabelFont, LabelTemplate},
+};
+
+#[derive(Deserialize)]
+pub struct CodeImageQueryDTO {
+    pub format: Option<ImageFormat>,
+}
+
+#[derive(Deserialize)]
+pub struct LabelQueryDTO {
+    pub template: Option<i64>,
+}
+
+#[derive(Deserialize)]
+pub struct LabelTemplateQueryDTO {
+    pub organization: Option<String>,
+}
+
+#[derive(Deserialize, Clone, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct LabelTemplateRequestDTO {
+    pub organization: String,
+    pub name: String,
+    pub page_width: f64,
+    pub page_height: f64,
+    pub font: LabelFont,
+    pub font_size: f64,
+    #[validate(length(min = 1))]
+    pub fields: Vec<LabelField>,
+    #[serde(default)]
+    pub is_default: bool,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LabelTemplateResponseDTO {
+    pub id: i64,
+    pub organization: String,
+    pub name: String,
+    pub page_width: f64,
+    pub page_height: f64,
+    pub font: LabelFont,
+    pub font_size: f64,
+    pub fields: Vec<LabelField>,
+    pub is_default: bool,
+}
+
+impl From<&LabelTemplate> for LabelTemplateResponseDTO {
+    fn from(template: &LabelTemplate) -> Self {
+        Self {
+            id: template.id().unwrap(),
+            organization: template.organization().to_string(),
+            name: template.name().to_string(),
+            page_width: template.page_width(),
+            page_height: template.page_height(),
+            font: template.font(),
+            font_size: template.font_size(),
+            fields: template.fields().to_vec(),
+            is_default: template.is_default(),
+        }
+    }
+}
@@ -0,0 +1,53 @@
+use serde::{Deserialize, Serialize};
+
+use crate::models::{LineageDirection, LineageGraph};
+
+use super::BatchResponseDTO;
+
+#[derive(Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum LineageFormat {
+    Json,
+    Dot,
+}
+
+#[derive(Deserialize)]
+pub struct LineageQueryDTO {
+    pub direction: Option<LineageDirection>,
+    pub depth: Option<i64>,
+    pub format: Option<LineageFormat>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LineageEdgeDTO {
+    pub parent: i64,
+    pub child: i64,
+    pub quantity: f64,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LineageGraphDTO {
+    pub root: i64,
+    pub batches: Vec<BatchResponseDTO>,
+    pub edges: Vec<LineageEdgeDTO>,
+}
+
+impl From<&LineageGraph> for LineageGraphDTO {
+    fn from(graph: &LineageGraph) -> Self {
+        Self {
+            root: graph.root_id(),
+            batches: graph.batches().iter().map(BatchResponseDTO::from).collect(),
+            edges: graph
+                .edges()
+                .iter()
+                .map(|(parent, child, quantity)| LineageEdgeDTO {
+                    parent: *parent,
+                    child: *child,
+                    quantity: *quantity,
+                })
+                .collect(),
+        }
+    }
+}
@@ -1,7 +1,67 @@
+mod audit_dto;
 mod batch_dto;
+mod batch_event_dto;
+mod catalog_dto;
+mod chemical_application_dto;
 mod crop_dto;
+mod cultivation_dto;
+mod customer_dto;
+mod epcis_dto;
+mod export_dto;
+mod farm_dto;
+mod harvest_dto;
+mod import_dto;
+mod label_dto;
+mod lineage_dto;
+mod packing_dto;
+mod plot_dto;
+mod product_dto;
+mod recall_dto;
+mod report_dto;
+mod shipment_dto;
+mod tracking_dto;
 
 pub use self::{
-    batch_dto::{BatchRequestDTO, BatchResponseDTO},
-    crop_dto::{CropRequestDTO, CropResponseDTO},
+    audit_dto::{AuditEntryResponseDTO, AuditQueryDTO},
+    batch_dto::{
+        BatchMergeRequestDTO, BatchRequestDTO, BatchResponseDTO, BatchSplitRequestDTO,
+        BatchSplitResponseDTO, QuantityUnitQueryDTO,
+    },
+    batch_event_dto::{BatchEventRequestDTO, BatchEventResponseDTO},
+    catalog_dto::{CatalogEntryRequestDTO, CatalogEntryResponseDTO, CatalogQueryDTO},
+    chemical_application_dto::{
+        ChemicalApplicationRequestDTO, ChemicalApplicationResponseDTO, CropWithdrawalDTO,
+        WithdrawalQueryDTO,
+    },
+    crop_dto::{AreaUnitQueryDTO, CropCloseRequestDTO, CropRequestDTO, CropResponseDTO},
+    cultivation_dto::{
+        CultivationImportResponseDTO, CultivationRequestDTO, CultivationResponseDTO,
+    },
+    customer_dto::{CustomerRequestDTO, CustomerResponseDTO},
+    epcis_dto::{
+        EpcisCaptureResponseDTO, EpcisDocumentDTO, EpcisQueryBodyDTO, EpcisQueryDTO,
+        EpcisQueryDocumentDTO, EpcisQueryResultsDTO, EpcisResultsBodyDTO,
+    },
+    export_dto::{BatchExportQueryDTO, CropExportQueryDTO, ExportFormat},
+    farm_dto::{FarmRequestDTO, FarmResponseDTO},
+    harvest_dto::{HarvestRequestDTO, HarvestResponseDTO},
+    import_dto::{ImportQueryDTO, ImportResponseDTO},
+    label_dto::{
+        CodeImageQueryDTO, LabelQueryDTO, LabelTemplateQueryDTO, LabelTemplateRequestDTO,
+        LabelTemplateResponseDTO,
+    },
+    lineage_dto::{LineageFormat, LineageGraphDTO, LineageQueryDTO},
+    packing_dto::{PackingRequestDTO, PackingResponseDTO},
+    plot_dto::{PlotFeatureCollectionDTO, PlotQueryDTO, PlotRequestDTO, PlotResponseDTO},
+    product_dto::{Gs1ResponseDTO, ProductRequestDTO, ProductResponseDTO},
+    recall_dto::{
+        RecallReportDTO, RecallReportFormat, RecallReportQueryDTO, RecallRequestDTO,
+        RecallResponseDTO, RecallStatusRequestDTO,
+    },
+    report_dto::{
+        ComplianceReportFormat, ComplianceReportQueryDTO, YieldReportDTO, YieldReportFormat,
+        YieldReportQueryDTO,
+    },
+    shipment_dto::{ShipmentRequestDTO, ShipmentResponseDTO},
+    tracking_dto::{TrackingEventDTO, TrackingResponseDTO},
 };
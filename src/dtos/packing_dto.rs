@@ -0,0 +1,43 @@
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::models::Packing;
+
+fn default_active() -> bool {
+    true
+}
+
+#[derive(Deserialize, Clone, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct PackingRequestDTO {
+    #[validate(length(min = 1, max = 255))]
+    pub code: String,
+    #[validate(length(min = 1, max = 255))]
+    pub description: String,
+    #[validate(range(exclusive_min = 0.0))]
+    pub kg_per_unit: Option<f64>,
+    #[serde(default = "default_active")]
+    pub active: bool,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PackingResponseDTO {
+    pub id: i64,
+    pub code: String,
+    pub description: String,
+    pub kg_per_unit: Option<f64>,
+    pub active: bool,
+}
+
+impl From<&Packing> for PackingResponseDTO {
+    fn from(packing: &Packing) -> Self {
+        Self {
+            id: packing.id().unwrap(),
+            code: packing.code().to_string(),
+            description: packing.description().to_string(),
+            kg_per_unit: packing.kg_per_unit(),
+            active: packing.active(),
+        }
+    }
+}
@@ -0,0 +1,89 @@
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::models::{Plot, PlotBoundary};
+
+#[derive(Deserialize)]
+pub struct PlotQueryDTO {
+    pub farm: Option<i64>,
+}
+
+#[derive(Deserialize, Clone, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct PlotRequestDTO {
+    pub farm_id: i64,
+    #[validate(length(min = 1, max = 255))]
+    pub name: String,
+    pub boundary: PlotBoundary,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlotResponseDTO {
+    pub id: i64,
+    pub farm: i64,
+    pub name: String,
+    pub boundary: PlotBoundary,
+    /// Area enclosed by the boundary, in hectares.
+    pub area: f64,
+}
+
+impl From<&Plot> for PlotResponseDTO {
+    fn from(plot: &Plot) -> Self {
+        Self {
+            id: plot.id().unwrap(),
+            farm: plot.farm_id(),
+            name: plot.name().to_string(),
+            boundary: plot.boundary().clone(),
+            area: plot.area(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlotPropertiesDTO {
+    pub farm: i64,
+    pub name: String,
+    pub area: f64,
+}
+
+#[derive(Serialize)]
+pub struct PlotFeatureDTO {
+    #[serde(rename = "type")]
+    pub kind: &'static str,
+    pub id: i64,
+    pub geometry: PlotBoundary,
+    pub properties: PlotPropertiesDTO,
+}
+
+impl From<&Plot> for PlotFeatureDTO {
+    fn from(plot: &Plot) -> Self {
+        Self {
+            kind: "Feature",
+            id: plot.id().unwrap(),
+            geometry: plot.boundary().clone(),
+            properties: PlotPropertiesDTO {
+                farm: plot.farm_id(),
+                name: plot.name().to_string(),
+                area: plot.area(),
+            },
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct PlotFeatureCollectionDTO {
+    #[serde(rename = "type")]
+    pub kind: &'static str,
+    pub features: Vec<PlotFeatureDTO>,
+}
+
+impl PlotFeatureCollectionDTO {
+    pub fn new(plots: &[Plot]) -> Self {
+        Self {
+            kind: "FeatureCollection",
+            features: plots.iter().map(PlotFeatureDTO::from).collect(),
+        }
+    }
+}
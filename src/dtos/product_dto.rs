@@ -0,0 +1,46 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::models::Product;
+
+#[derive(Deserialize, Clone, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct ProductRequestDTO {
+    pub gtin: String,
+    #[validate(length(min = 1, max = 255))]
+    pub name: String,
+    #[validate(length(max = 255))]
+    pub description: Option<String>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProductResponseDTO {
+    pub id: i64,
+    pub gtin: String,
+    pub name: String,
+    pub description: Option<String>,
+}
+
+impl From<&Product> for ProductResponseDTO {
+    fn from(product: &Product) -> Self {
+        Self {
+            id: product.id().unwrap(),
+            gtin: product.gtin().to_string(),
+            name: product.name().to_string(),
+            description: product.description().clone(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Gs1ResponseDTO {
+    pub gtin: String,
+    pub lot: String,
+    pub pack_date: NaiveDate,
+    /// GS1-128 element string in human readable form.
+    pub element_string: String,
+    pub digital_link: String,
+}
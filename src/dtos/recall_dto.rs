@@ -0,0 +1,98 @@
+use chrono::{NaiveDate, NaiveDateTime};
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::models::{Recall, RecallReport, RecallStatus};
+
+use super::{BatchResponseDTO, CustomerResponseDTO, ShipmentResponseDTO};
+
+#[derive(Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum RecallReportFormat {
+    Json,
+    Csv,
+}
+
+#[derive(Deserialize)]
+pub struct RecallReportQueryDTO {
+    pub format: Option<RecallReportFormat>,
+}
+
+#[derive(Deserialize, Clone, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct RecallRequestDTO {
+    pub crop_id: Option<i64>,
+    pub batch_id: Option<i64>,
+    #[validate(length(min = 1, max = 255))]
+    pub cultivation: Option<String>,
+    pub start_date: Option<NaiveDate>,
+    pub end_date: Option<NaiveDate>,
+    #[validate(length(min = 1))]
+    pub reason: String,
+}
+
+#[derive(Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct RecallStatusRequestDTO {
+    pub status: RecallStatus,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecallResponseDTO {
+    pub id: i64,
+    pub crop: Option<i64>,
+    pub batch: Option<i64>,
+    pub cultivation: Option<String>,
+    pub start_date: Option<NaiveDate>,
+    pub end_date: Option<NaiveDate>,
+    pub reason: String,
+    pub status: RecallStatus,
+    pub created_at: NaiveDateTime,
+    pub batches: Vec<i64>,
+}
+
+impl From<&Recall> for RecallResponseDTO {
+    fn from(recall: &Recall) -> Self {
+        Self {
+            id: recall.id().unwrap(),
+            crop: recall.crop_id(),
+            batch: recall.batch_id(),
+            cultivation: recall.cultivation().clone(),
+            start_date: recall.start_date(),
+            end_date: recall.end_date(),
+            reason: recall.reason().to_string(),
+            status: recall.status(),
+            created_at: recall.created_at(),
+            batches: recall.batch_ids().to_vec(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecallReportDTO {
+    pub recall: RecallResponseDTO,
+    pub batches: Vec<BatchResponseDTO>,
+    pub shipments: Vec<ShipmentResponseDTO>,
+    pub customers: Vec<CustomerResponseDTO>,
+}
+
+impl From<&RecallReport> for RecallReportDTO {
+    fn from(report: &RecallReport) -> Self {
+        Self {
+            recall: RecallResponseDTO::from(&report.recall),
+            batches: report.batches.iter().map(BatchResponseDTO::from).collect(),
+            shipments: report
+                .shipments
+                .iter()
+                .map(ShipmentResponseDTO::from)
+                .collect(),
+            customers: report
+                .customers
+                .iter()
+                .map(CustomerResponseDTO::from)
+                .collect(),
+        }
+    }
+}
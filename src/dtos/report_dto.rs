@@ -0,0 +1,97 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+use crate::models::{YieldGroupBy, YieldReport, YieldRow};
+
+#[derive(Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum YieldReportFormat {
+    Json,
+    Csv,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct YieldReportQueryDTO {
+    pub from: Option<NaiveDate>,
+    pub to: Option<NaiveDate>,
+    #[serde(default)]
+    pub group_by: YieldGroupBy,
+    pub format: Option<YieldReportFormat>,
+}
+
+#[derive(Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ComplianceReportFormat {
+    Pdf,
+    Csv,
+}
+
+#[derive(Deserialize)]
+pub struct ComplianceReportQueryDTO {
+    pub from: NaiveDate,
+    pub to: NaiveDate,
+    pub format: Option<ComplianceReportFormat>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct YieldRowDTO {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub period: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub crop_id: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub crop: Option<String>,
+    pub cultivation: String,
+    pub crops: usize,
+    pub batches: usize,
+    /// Kilograms.
+    pub quantity: f64,
+    /// Hectares.
+    pub area: f64,
+    /// Kilograms per hectare.
+    pub productivity: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cultivation_productivity: Option<f64>,
+    /// Batches left out of the quantity, as their packing has no weight.
+    pub unconverted: usize,
+}
+
+impl From<&YieldRow> for YieldRowDTO {
+    fn from(row: &YieldRow) -> Self {
+        Self {
+            period: row.period.clone(),
+            crop_id: row.crop_id,
+            crop: row.crop.clone(),
+            cultivation: row.cultivation.clone(),
+            crops: row.crops,
+            batches: row.batches,
+            quantity: row.quantity,
+            area: row.area,
+            productivity: row.productivity,
+            cultivation_productivity: row.cultivation_productivity,
+            unconverted: row.unconverted,
+        }
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct YieldReportDTO {
+    pub from: Option<NaiveDate>,
+    pub to: Option<NaiveDate>,
+    pub group_by: YieldGroupBy,
+    pub rows: Vec<YieldRowDTO>,
+}
+
+impl From<&YieldReport> for YieldReportDTO {
+    fn from(report: &YieldReport) -> Self {
+        Self {
+            from: report.from,
+            to: report.to,
+            group_by: report.group_by,
+            rows: report.rows.iter().map(YieldRowDTO::from).collect(),
+        }
+    }
+}
@@ -0,0 +1,59 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::{
+    misc::date_validation::past_or_present_validation,
+    models::{Shipment, ShipmentItem},
+};
+
+#[derive(Serialize, Deserialize, Clone, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct ShipmentItemDTO {
+    pub batch_id: i64,
+    #[validate(range(exclusive_min = 0.0))]
+    pub quantity: f64,
+}
+
+impl From<&ShipmentItem> for ShipmentItemDTO {
+    fn from(item: &ShipmentItem) -> Self {
+        Self {
+            batch_id: item.batch_id(),
+            quantity: item.quantity(),
+        }
+    }
+}
+
+#[derive(Deserialize, Clone, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct ShipmentRequestDTO {
+    pub customer_id: i64,
+    #[validate(custom(function = "past_or_present_validation"))]
+    pub dispatched_at: NaiveDate,
+    #[validate(length(min = 1, max = 64))]
+    pub invoice_number: String,
+    #[validate(length(min = 1), nested)]
+    pub items: Vec<ShipmentItemDTO>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ShipmentResponseDTO {
+    pub id: i64,
+    pub customer: i64,
+    pub dispatched_at: NaiveDate,
+    pub invoice_number: String,
+    pub items: Vec<ShipmentItemDTO>,
+}
+
+impl From<&Shipment> for ShipmentResponseDTO {
+    fn from(shipment: &Shipment) -> Self {
+        Self {
+            id: shipment.id().unwrap(),
+            customer: shipment.customer_id(),
+            dispatched_at: shipment.dispatched_at(),
+            invoice_number: shipment.invoice_number().to_string(),
+            items: shipment.items().iter().map(ShipmentItemDTO::from).collect(),
+        }
+    }
+}
@@ -0,0 +1,29 @@
+use chrono::{NaiveDate, NaiveDateTime};
+use serde::Serialize;
+
+use super::{BatchResponseDTO, CropResponseDTO, PlotResponseDTO};
+
+#[derive(Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct TrackingEventDTO {
+    pub event_type: String,
+    pub date: NaiveDate,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub occurred_at: Option<NaiveDateTime>,
+    pub description: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub operator: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notes: Option<String>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TrackingResponseDTO {
+    pub batch: BatchResponseDTO,
+    pub crop: CropResponseDTO,
+    pub origins: Vec<CropResponseDTO>,
+    /// Plots the origin crops were planted on, with their boundaries.
+    pub plots: Vec<PlotResponseDTO>,
+    pub events: Vec<TrackingEventDTO>,
+}
@@ -19,6 +19,12 @@ pub enum AppError {
     NotFound(String),
     #[error("{0}")]
     BadRequest(String),
+    /// The `If-Match` header names a version other than the current one.
+    #[error("{0}")]
+    PreconditionFailed(String),
+    /// A change was requested without an `If-Match` header.
+    #[error("{0}")]
+    PreconditionRequired(String),
     #[error("An internal error occurred.")]
     InternalServer,
 }
@@ -46,6 +52,48 @@ impl From<InvalidHeaderValue> for AppError {
     }
 }
 
+impl From<qrcode::types::QrError> for AppError {
+    fn from(value: qrcode::types::QrError) -> Self {
+        tracing::error!("QR code error: {:?}", value);
+        Self::InternalServer
+    }
+}
+
+impl From<barcoders::error::Error> for AppError {
+    fn from(value: barcoders::error::Error) -> Self {
+        tracing::error!("Barcode error: {:?}", value);
+        Self::InternalServer
+    }
+}
+
+impl From<image::ImageError> for AppError {
+    fn from(value: image::ImageError) -> Self {
+        tracing::error!("Image error: {:?}", value);
+        Self::InternalServer
+    }
+}
+
+impl From<printpdf::Error> for AppError {
+    fn from(value: printpdf::Error) -> Self {
+        tracing::error!("PDF error: {:?}", value);
+        Self::InternalServer
+    }
+}
+
+impl From<rust_xlsxwriter::XlsxError> for AppError {
+    fn from(value: rust_xlsxwriter::XlsxError) -> Self {
+        tracing::error!("XLSX error: {:?}", value);
+        Self::InternalServer
+    }
+}
+
+impl From<csv::Error> for AppError {
+    fn from(value: csv::Error) -> Self {
+        tracing::error!("CSV error: {:?}", value);
+        Self::InternalServer
+    }
+}
+
 impl IntoResponse for AppError {
     fn into_response(self) -> Response {
         match self {
@@ -59,6 +107,14 @@ impl IntoResponse for AppError {
             AppError::BadRequest(message) => {
                 (StatusCode::BAD_REQUEST, Json(ErrorResponse { message }))
             }
+            AppError::PreconditionFailed(message) => (
+                StatusCode::PRECONDITION_FAILED,
+                Json(ErrorResponse { message }),
+            ),
+            AppError::PreconditionRequired(message) => (
+                StatusCode::PRECONDITION_REQUIRED,
+                Json(ErrorResponse { message }),
+            ),
             AppError::InternalServer => (
                 StatusCode::INTERNAL_SERVER_ERROR,
                 Json(ErrorResponse {
@@ -1,4 +1,7 @@
-use axum::{routing::get, Router};
+use axum::{
+    routing::{delete, get, post, put},
+    Router,
+};
 use sqlx::SqlitePool;
 use tower_http::trace::TraceLayer;
 use tracing::info;
@@ -13,17 +16,35 @@ mod services;
 
 trait StateTrait {
     fn get_pool(&self) -> Box<SqlitePool>;
+    fn get_tracking_url_template(&self) -> String;
+    fn get_digital_link_base_url(&self) -> String;
+    fn get_organization(&self) -> Option<String>;
 }
 
 #[derive(Debug, Clone)]
 pub struct AppState {
     pool: Box<SqlitePool>,
+    tracking_url_template: String,
+    digital_link_base_url: String,
+    organization: Option<String>,
 }
 
 impl StateTrait for AppState {
     fn get_pool(&self) -> Box<SqlitePool> {
         self.pool.clone()
     }
+
+    fn get_tracking_url_template(&self) -> String {
+        self.tracking_url_template.clone()
+    }
+
+    fn get_digital_link_base_url(&self) -> String {
+        self.digital_link_base_url.clone()
+    }
+
+    fn get_organization(&self) -> Option<String> {
+        self.organization.clone()
+    }
 }
 
 #[tokio::main]
@@ -39,35 +60,227 @@ async fn main() -> anyhow::Result<()> {
         .init();
 
     let database_url = std::env::var("DATABASE_URL").expect("DATABASE_URL is required");
+    let tracking_url_template = std::env::var("TRACKING_URL_TEMPLATE")
+        .unwrap_or_else(|_| "http://localhost:3333/track/{code}".to_string());
+    let digital_link_base_url = std::env::var("DIGITAL_LINK_BASE_URL")
+        .unwrap_or_else(|_| "http://localhost:3333".to_string());
+    let organization = std::env::var("ORGANIZATION").ok();
     let pool = SqlitePool::connect(&database_url).await?;
 
     sqlx::migrate!().run(&pool).await?;
 
     let state = AppState {
         pool: Box::new(pool),
+        tracking_url_template,
+        digital_link_base_url,
+        organization,
     };
 
     let app = Router::new()
+        .route("/audit", get(routes::audit::list_audit_entries))
         .route(
             "/batches",
             get(routes::batch::list_batches).post(routes::batch::insert_batch),
         )
+        .route("/batches/export", get(routes::batch::export_batches))
         .route(
             "/batches/:id",
             get(routes::batch::find_batch_by_id)
                 .put(routes::batch::update_batch)
                 .delete(routes::batch::delete_batch),
         )
+        .route("/batches/:id/qrcode", get(routes::batch::get_batch_qrcode))
+        .route(
+            "/batches/:id/barcode",
+            get(routes::batch::get_batch_barcode),
+        )
+        .route("/batches/:id/gs1", get(routes::batch::get_batch_gs1))
+        .route(
+            "/batches/:id/gs1-128",
+            get(routes::batch::get_batch_gs1_128),
+        )
+        .route("/batches/merge", post(routes::batch::merge_batches))
+        .route(
+            "/batches/:id/events",
+            get(routes::batch_event::list_batch_events)
+                .post(routes::batch_event::insert_batch_event),
+        )
+        .route(
+            "/batches/:id/lineage",
+            get(routes::batch::get_batch_lineage),
+        )
+        .route(
+            "/batches/:id/shipments",
+            get(routes::shipment::list_batch_shipments),
+        )
+        .route("/batches/:id/split", post(routes::batch::split_batch))
+        .route("/batches/:id/restore", post(routes::batch::restore_batch))
+        .route(
+            "/batches/:id/label.pdf",
+            get(routes::batch::get_batch_label_pdf),
+        )
+        .route(
+            "/batches/:id/label.zpl",
+            get(routes::batch::get_batch_label_zpl),
+        )
+        .route("/track/:code", get(routes::batch::track_batch))
         .route(
             "/crops",
             get(routes::crop::list_crops).post(routes::crop::insert_crop),
         )
+        .route("/crops/export", get(routes::crop::export_crops))
+        .route(
+            "/crops/in-withdrawal",
+            get(routes::chemical_application::list_crops_in_withdrawal),
+        )
         .route(
             "/crops/:id",
             get(routes::crop::find_crop_by_id)
                 .put(routes::crop::update_crop)
                 .delete(routes::crop::delete_crop),
         )
+        .route(
+            "/crops/:id/applications",
+            get(routes::chemical_application::list_chemical_applications)
+                .post(routes::chemical_application::insert_chemical_application),
+        )
+        .route(
+            "/crops/:id/applications/:application_id",
+            delete(routes::chemical_application::delete_chemical_application),
+        )
+        .route("/crops/:id/close", post(routes::crop::close_crop))
+        .route("/crops/:id/restore", post(routes::crop::restore_crop))
+        .route(
+            "/crops/:id/harvests",
+            get(routes::harvest::list_harvests).post(routes::harvest::insert_harvest),
+        )
+        .route(
+            "/crops/:id/harvests/:harvest_id",
+            delete(routes::harvest::delete_harvest),
+        )
+        .route(
+            "/customers",
+            get(routes::customer::list_customers).post(routes::customer::insert_customer),
+        )
+        .route(
+            "/customers/:id",
+            get(routes::customer::find_customer_by_id)
+                .put(routes::customer::update_customer)
+                .delete(routes::customer::delete_customer),
+        )
+        .route("/epcis/events", get(routes::epcis::list_epcis_events))
+        .route("/epcis/capture", post(routes::epcis::capture_epcis_events))
+        .route("/import/crops", post(routes::import::import_crops))
+        .route("/import/batches", post(routes::import::import_batches))
+        .route(
+            "/farms",
+            get(routes::farm::list_farms).post(routes::farm::insert_farm),
+        )
+        .route(
+            "/farms/:id",
+            get(routes::farm::find_farm_by_id)
+                .put(routes::farm::update_farm)
+                .delete(routes::farm::delete_farm),
+        )
+        .route(
+            "/label-templates",
+            get(routes::label_template::list_label_templates)
+                .post(routes::label_template::insert_label_template),
+        )
+        .route(
+            "/label-templates/:id",
+            get(routes::label_template::find_label_template_by_id)
+                .put(routes::label_template::update_label_template)
+                .delete(routes::label_template::delete_label_template),
+        )
+        .route(
+            "/catalogs/:kind",
+            get(routes::catalog::list_catalog_entries).post(routes::catalog::insert_catalog_entry),
+        )
+        .route(
+            "/catalogs/:kind/:id",
+            get(routes::catalog::find_catalog_entry_by_id)
+                .put(routes::catalog::update_catalog_entry)
+                .delete(routes::catalog::delete_catalog_entry),
+        )
+        .route(
+            "/cultivations",
+            get(routes::cultivation::list_cultivations)
+                .post(routes::cultivation::insert_cultivation),
+        )
+        .route(
+            "/cultivations/import",
+            post(routes::cultivation::import_cultivations),
+        )
+        .route(
+            "/cultivations/:id",
+            get(routes::cultivation::find_cultivation_by_id)
+                .put(routes::cultivation::update_cultivation)
+                .delete(routes::cultivation::delete_cultivation),
+        )
+        .route(
+            "/products",
+            get(routes::product::list_products).post(routes::product::insert_product),
+        )
+        .route(
+            "/products/:id",
+            get(routes::product::find_product_by_id)
+                .put(routes::product::update_product)
+                .delete(routes::product::delete_product),
+        )
+        .route(
+            "/01/:gtin/10/:lot",
+            get(routes::batch::resolve_digital_link),
+        )
+        .route(
+            "/packings",
+            get(routes::packing::list_packings).post(routes::packing::insert_packing),
+        )
+        .route(
+            "/packings/:id",
+            get(routes::packing::find_packing_by_id)
+                .put(routes::packing::update_packing)
+                .delete(routes::packing::delete_packing),
+        )
+        .route(
+            "/plots",
+            get(routes::plot::list_plots).post(routes::plot::insert_plot),
+        )
+        .route("/plots.geojson", get(routes::plot::get_plots_geojson))
+        .route(
+            "/plots/:id",
+            get(routes::plot::find_plot_by_id)
+                .put(routes::plot::update_plot)
+                .delete(routes::plot::delete_plot),
+        )
+        .route(
+            "/recalls",
+            get(routes::recall::list_recalls).post(routes::recall::insert_recall),
+        )
+        .route("/recalls/:id", get(routes::recall::find_recall_by_id))
+        .route(
+            "/recalls/:id/report",
+            get(routes::recall::get_recall_report),
+        )
+        .route("/reports/yield", get(routes::report::get_yield_report))
+        .route(
+            "/reports/compliance",
+            get(routes::report::get_compliance_report),
+        )
+        .route(
+            "/recalls/:id/status",
+            put(routes::recall::update_recall_status),
+        )
+        .route(
+            "/shipments",
+            get(routes::shipment::list_shipments).post(routes::shipment::insert_shipment),
+        )
+        .route(
+            "/shipments/:id",
+            get(routes::shipment::find_shipment_by_id)
+                .put(routes::shipment::update_shipment)
+                .delete(routes::shipment::delete_shipment),
+        )
         .layer(TraceLayer::new_for_http())
         .with_state(state);
 
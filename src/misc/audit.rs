@@ -0,0 +1,129 @@
+use axum::http::request::Parts;
+use serde_json::{json, Map, Value};
+
+use crate::{
+    errors::AppError,
+    models::{Batch, Crop},
+};
+
+/// Header naming who makes the request, recorded as the actor of the
+/// changes it causes. It is taken at its word: nothing checks that the
+/// request really comes from that user.
+pub const ACTOR_HEADER: &str = "x-user";
+
+/// The actor of the request, which every request that may change data must
+/// name.
+pub fn actor(parts: &Parts) -> Result<Option<String>, AppError> {
+    let actor = parts
+        .headers
+        .get(ACTOR_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(str::to_string);
+    if actor.is_none() && !parts.method.is_safe() {
+        return Err(AppError::BadRequest(format!(
+            "Informe no cabeçalho {ACTOR_HEADER} o usuário que faz a alteração."
+        )));
+    }
+
+    Ok(actor)
+}
+
+pub fn crop_snapshot(crop: &Crop) -> Value {
+    json!({
+        "name": crop.name(),
+        "area": crop.area(),
+        "areaUnit": crop.area_unit(),
+        "cultivationId": crop.cultivation().id(),
+        "plantedAt": crop.planted_at(),
+        "closedAt": crop.closed_at(),
+        "plotId": crop.plot_id(),
+    })
+}
+
+pub fn batch_snapshot(batch: &Batch) -> Value {
+    json!({
+        "cropId": batch.crop().id(),
+        "trackingCode": batch.tracking_code(),
+        "classification": batch.classification(),
+        "processing": batch.processing(),
+        "packing": batch.packing(),
+        "quantity": batch.quantity(),
+        "quantityUnit": batch.quantity_unit(),
+        "date": batch.date(),
+        "harvestId": batch.harvest_id(),
+        "productId": batch.product_id(),
+    })
+}
+
+/// Fields of two snapshots whose values differ, as `(before, after)`.
+pub fn diff(before: &Value, after: &Value) -> (Map<String, Value>, Map<String, Value>) {
+    let empty = Map::new();
+    let before = before.as_object().unwrap_or(&empty);
+    let after = after.as_object().unwrap_or(&empty);
+
+    let mut old = Map::new();
+    let mut new = Map::new();
+    for key in before
+        .keys()
+        .chain(after.keys().filter(|key| !before.contains_key(*key)))
+    {
+        let (a, b) = (before.get(key), after.get(key));
+        if a != b {
+            old.insert(key.clone(), a.cloned().unwrap_or(Value::Null));
+            new.insert(key.clone(), b.cloned().unwrap_or(Value::Null));
+        }
+    }
+
+    (old, new)
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::http::{Method, Request};
+
+    use super::*;
+
+    fn parts(method: Method, actor: Option<&str>) -> Parts {
+        let mut request = Request::builder().method(method);
+        if let Some(actor) = actor {
+            request = request.header(ACTOR_HEADER, actor);
+        }
+        request.body(()).unwrap().into_parts().0
+    }
+
+    #[test]
+    fn changes_must_name_their_actor() {
+        assert_eq!(actor(&parts(Method::GET, None)).unwrap(), None);
+        assert_eq!(
+            actor(&parts(Method::PUT, Some(" ana "))).unwrap(),
+            Some("ana".to_string())
+        );
+        assert!(matches!(
+            actor(&parts(Method::POST, None)),
+            Err(AppError::BadRequest(_))
+        ));
+        assert!(matches!(
+            actor(&parts(Method::DELETE, Some(" "))),
+            Err(AppError::BadRequest(_))
+        ));
+    }
+
+    #[test]
+    fn diff_keeps_only_changed_fields() {
+        let (before, after) = diff(
+            &json!({"quantity": 100.0, "packing": "Caixa", "date": "2024-04-02"}),
+            &json!({"quantity": 60.0, "packing": "Caixa", "harvestId": 3}),
+        );
+
+        assert_eq!(
+            Value::Object(before),
+            json!({"quantity": 100.0, "date": "2024-04-02", "harvestId": null})
+        );
+        assert_eq!(
+            Value::Object(after),
+            json!({"quantity": 60.0, "date": null, "harvestId": 3})
+        );
+    }
+}
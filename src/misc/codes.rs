@@ -0,0 +1,114 @@
+use std::io::Cursor;
+
+use barcoders::{generators::svg::SVG, sym::code128::Code128};
+use image::{GrayImage, Luma};
+use qrcode::{render::svg, Color, QrCode};
+use serde::Deserialize;
+
+use crate::errors::AppError;
+
+const QUIET_ZONE: u32 = 4;
+const QRCODE_MODULE_SIZE: u32 = 8;
+const BARCODE_MODULE_WIDTH: u32 = 2;
+const BARCODE_HEIGHT: u32 = 80;
+const BARCODE_QUIET_ZONE: usize = 10;
+const SVG_NAMESPACE: &str = "http://www.w3.org/2000/svg";
+
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ImageFormat {
+    Png,
+    Svg,
+}
+
+impl ImageFormat {
+    /// Picks the format from an `Accept` header, falling back to PNG.
+    pub fn from_accept(accept: Option<&str>) -> Self {
+        match accept {
+            Some(accept) if accept.contains("image/svg+xml") => Self::Svg,
+            _ => Self::Png,
+        }
+    }
+
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            Self::Png => "image/png",
+            Self::Svg => "image/svg+xml",
+        }
+    }
+}
+
+pub fn render_qrcode(payload: &str, format: ImageFormat) -> Result<Vec<u8>, AppError> {
+    let code = QrCode::new(payload)?;
+
+    match format {
+        ImageFormat::Svg => Ok(code
+            .render::<svg::Color>()
+            .module_dimensions(QRCODE_MODULE_SIZE, QRCODE_MODULE_SIZE)
+            .build()
+            .into_bytes()),
+        ImageFormat::Png => {
+            let width = code.width() as u32;
+            let colors = code.to_colors();
+            let size = (width + 2 * QUIET_ZONE) * QRCODE_MODULE_SIZE;
+            let image = GrayImage::from_fn(size, size, |x, y| {
+                let x = (x / QRCODE_MODULE_SIZE).checked_sub(QUIET_ZONE);
+                let y = (y / QRCODE_MODULE_SIZE).checked_sub(QUIET_ZONE);
+                match (x, y) {
+                    (Some(x), Some(y)) if x < width && y < width => {
+                        pixel(colors[(y * width + x) as usize] == Color::Dark)
+                    }
+                    _ => pixel(false),
+                }
+            });
+            encode_png(image)
+        }
+    }
+}
+
+/// Renders `code` as a Code 128 (character set B) barcode.
+pub fn render_barcode(code: &str, format: ImageFormat) -> Result<Vec<u8>, AppError> {
+    render_code128(&format!("Ɓ{code}"), format)
+}
+
+/// Renders `data`, which must start with a character set selector, as a
+/// Code 128 barcode.
+pub fn render_code128(data: &str, format: ImageFormat) -> Result<Vec<u8>, AppError> {
+    let bars = Code128::new(data)?.encode();
+    let quiet_zone = vec![0; BARCODE_QUIET_ZONE];
+    let bars = [
+        quiet_zone.as_slice(),
+        bars.as_slice(),
+        quiet_zone.as_slice(),
+    ]
+    .concat();
+
+    match format {
+        ImageFormat::Svg => Ok(SVG::new(BARCODE_HEIGHT)
+            .xdim(BARCODE_MODULE_WIDTH)
+            .xmlns(SVG_NAMESPACE.to_string())
+            .generate(&bars)?
+            .into_bytes()),
+        ImageFormat::Png => {
+            let width = bars.len() as u32 * BARCODE_MODULE_WIDTH;
+            let image = GrayImage::from_fn(width, BARCODE_HEIGHT, |x, _| {
+                pixel(bars[(x / BARCODE_MODULE_WIDTH) as usize] == 1)
+            });
+            encode_png(image)
+        }
+    }
+}
+
+fn pixel(dark: bool) -> Luma<u8> {
+    if dark {
+        Luma([0])
+    } else {
+        Luma([255])
+    }
+}
+
+fn encode_png(image: GrayImage) -> Result<Vec<u8>, AppError> {
+    let mut buffer = Cursor::new(Vec::new());
+    image.write_to(&mut buffer, image::ImageFormat::Png)?;
+    Ok(buffer.into_inner())
+}
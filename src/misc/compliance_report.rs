@@ -0,0 +1,245 @@
+use printpdf::{BuiltinFont, IndirectFontRef, Line, Mm, PdfDocument, PdfLayerReference, Point};
+
+use crate::{
+    errors::AppError,
+    misc::document::format_cpf_cnpj,
+    models::{ComplianceReport, ComplianceRow},
+};
+
+const HEADER: [&str; 15] = [
+    "producer_name",
+    "producer_document",
+    "producer_address",
+    "product",
+    "variety",
+    "lot",
+    "quantity",
+    "quantity_unit",
+    "received_at",
+    "dispatched_at",
+    "dispatched_quantity",
+    "invoice_number",
+    "recipient_name",
+    "recipient_document",
+    "recipient_address",
+];
+
+const PAGE_WIDTH: f32 = 297.0;
+const PAGE_HEIGHT: f32 = 210.0;
+const MARGIN: f32 = 10.0;
+const TITLE_SIZE: f32 = 11.0;
+const FONT_SIZE: f32 = 6.5;
+const POINT_TO_MM: f32 = 0.3528;
+const ROW_HEIGHT: f32 = 4.0;
+/// Average Helvetica glyph width, as a fraction of the font size.
+const GLYPH_WIDTH: f32 = 0.5;
+
+/// Title and width (mm) of each PDF column.
+const COLUMNS: [(&str, f32); 11] = [
+    ("Produtor", 36.0),
+    ("CPF/CNPJ", 25.0),
+    ("Endereço", 38.0),
+    ("Produto", 20.0),
+    ("Variedade", 18.0),
+    ("Lote", 20.0),
+    ("Quantidade", 18.0),
+    ("Recebido em", 15.0),
+    ("Expedido em", 15.0),
+    ("Destinatário", 36.0),
+    ("CPF/CNPJ", 25.0),
+];
+
+fn optional<T: ToString>(value: &Option<T>) -> String {
+    value.as_ref().map(T::to_string).unwrap_or_default()
+}
+
+fn document(value: &Option<String>) -> String {
+    value.as_deref().map(format_cpf_cnpj).unwrap_or_default()
+}
+
+/// Renders one CSV row per movement, with the dispatch columns left empty
+/// for lots that were only received.
+pub fn render_compliance_csv(report: &ComplianceReport) -> Result<Vec<u8>, AppError> {
+    let mut writer = csv::Writer::from_writer(vec![]);
+    writer.write_record(HEADER)?;
+
+    for row in &report.rows {
+        writer.write_record([
+            optional(&row.producer_name),
+            document(&row.producer_document),
+            optional(&row.producer_address),
+            row.product.clone(),
+            optional(&row.variety),
+            row.lot.clone(),
+            row.quantity.to_string(),
+            row.quantity_unit.as_str().to_string(),
+            row.received_at.to_string(),
+            optional(&row.dispatched_at),
+            optional(&row.dispatched_quantity),
+            optional(&row.invoice_number),
+            optional(&row.recipient_name),
+            document(&row.recipient_document),
+            optional(&row.recipient_address),
+        ])?;
+    }
+
+    writer
+        .into_inner()
+        .map_err(|err| AppError::from(csv::Error::from(err.into_error())))
+}
+
+fn pdf_cells(row: &ComplianceRow) -> [String; 11] {
+    let unit = row.quantity_unit.as_str();
+    [
+        optional(&row.producer_name),
+        document(&row.producer_document),
+        optional(&row.producer_address),
+        row.product.clone(),
+        optional(&row.variety),
+        row.lot.clone(),
+        match row.dispatched_quantity {
+            Some(dispatched) => format!("{dispatched} de {} {unit}", row.quantity),
+            None => format!("{} {unit}", row.quantity),
+        },
+        row.received_at.format("%d/%m/%Y").to_string(),
+        row.dispatched_at
+            .map(|date| date.format("%d/%m/%Y").to_string())
+            .unwrap_or_default(),
+        optional(&row.recipient_name),
+        document(&row.recipient_document),
+    ]
+}
+
+/// Cuts `text` so it fits in `width` millimetres.
+fn fit(text: &str, width: f32) -> String {
+    let max = ((width - 1.0) / (FONT_SIZE * POINT_TO_MM * GLYPH_WIDTH)) as usize;
+    if text.chars().count() <= max {
+        return text.to_string();
+    }
+    let mut text: String = text.chars().take(max.saturating_sub(3)).collect();
+    text.push_str("...");
+    text
+}
+
+fn draw_row(layer: &PdfLayerReference, font: &IndirectFontRef, cells: &[String], y: f32) {
+    let mut x = MARGIN;
+    for (text, (_, width)) in cells.iter().zip(COLUMNS) {
+        layer.use_text(fit(text, width), FONT_SIZE, Mm(x), Mm(y), font);
+        x += width;
+    }
+}
+
+fn draw_rule(layer: &PdfLayerReference, y: f32) {
+    layer.add_line(Line {
+        points: vec![
+            (Point::new(Mm(MARGIN), Mm(y)), false),
+            (Point::new(Mm(PAGE_WIDTH - MARGIN), Mm(y)), false),
+        ],
+        is_closed: false,
+    });
+}
+
+/// Renders the report as an A4 landscape table, repeating the column titles
+/// on every page.
+pub fn render_compliance_pdf(report: &ComplianceReport) -> Result<Vec<u8>, AppError> {
+    let title = "Rastreabilidade de produtos vegetais frescos - INC MAPA/ANVISA nº 02/2018";
+    let period = format!(
+        "Período: {} a {}",
+        report.from.format("%d/%m/%Y"),
+        report.to.format("%d/%m/%Y")
+    );
+    let (document, page, layer) =
+        PdfDocument::new(title, Mm(PAGE_WIDTH), Mm(PAGE_HEIGHT), "Relatório");
+    let font = document.add_builtin_font(BuiltinFont::Helvetica)?;
+    let bold = document.add_builtin_font(BuiltinFont::HelveticaBold)?;
+    let titles = COLUMNS.map(|(title, _)| title.to_string());
+
+    let mut layer = document.get_page(page).get_layer(layer);
+    let mut y = PAGE_HEIGHT - MARGIN - TITLE_SIZE * POINT_TO_MM;
+    layer.use_text(title, TITLE_SIZE, Mm(MARGIN), Mm(y), &bold);
+    y -= ROW_HEIGHT * 1.5;
+    layer.use_text(&period, FONT_SIZE + 1.0, Mm(MARGIN), Mm(y), &font);
+    y -= ROW_HEIGHT * 1.5;
+    draw_row(&layer, &bold, &titles, y);
+    draw_rule(&layer, y - 1.2);
+    y -= ROW_HEIGHT;
+
+    if report.rows.is_empty() {
+        layer.use_text(
+            "Nenhuma movimentação no período.",
+            FONT_SIZE,
+            Mm(MARGIN),
+            Mm(y),
+            &font,
+        );
+    }
+
+    for row in &report.rows {
+        if y < MARGIN {
+            let (page, page_layer) =
+                document.add_page(Mm(PAGE_WIDTH), Mm(PAGE_HEIGHT), "Relatório");
+            layer = document.get_page(page).get_layer(page_layer);
+            y = PAGE_HEIGHT - MARGIN - FONT_SIZE * POINT_TO_MM;
+            draw_row(&layer, &bold, &titles, y);
+            draw_rule(&layer, y - 1.2);
+            y -= ROW_HEIGHT;
+        }
+        draw_row(&layer, &font, &pdf_cells(row), y);
+        y -= ROW_HEIGHT;
+    }
+
+    Ok(document.save_to_bytes()?)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    use super::*;
+    use crate::models::QuantityUnit;
+
+    #[test]
+    fn renders_received_and_dispatched_rows() {
+        let received_at = NaiveDate::from_ymd_opt(2024, 4, 2).unwrap();
+        let row = ComplianceRow {
+            producer_name: Some("Sítio Boa Vista".to_string()),
+            producer_document: Some("52998224725".to_string()),
+            producer_address: None,
+            product: "Tomate".to_string(),
+            variety: Some("Italiano".to_string()),
+            lot: "AbC123".to_string(),
+            quantity: 100.0,
+            quantity_unit: QuantityUnit::Kg,
+            received_at,
+            dispatched_at: None,
+            dispatched_quantity: None,
+            invoice_number: None,
+            recipient_name: None,
+            recipient_document: None,
+            recipient_address: None,
+        };
+        let report = ComplianceReport {
+            from: received_at,
+            to: received_at,
+            rows: vec![
+                row.clone(),
+                ComplianceRow {
+                    dispatched_at: Some(received_at),
+                    dispatched_quantity: Some(40.0),
+                    recipient_document: Some("11222333000181".to_string()),
+                    ..row
+                },
+            ],
+        };
+
+        let csv = String::from_utf8(render_compliance_csv(&report).unwrap()).unwrap();
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert_eq!(
+            lines[1],
+            "Sítio Boa Vista,529.982.247-25,,Tomate,Italiano,AbC123,100,kg,2024-04-02,,,,,,"
+        );
+        assert!(lines[2].ends_with(",2024-04-02,40,,,11.222.333/0001-81,"));
+        assert!(render_compliance_pdf(&report).unwrap().starts_with(b"%PDF"));
+    }
+}
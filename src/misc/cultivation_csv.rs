@@ -0,0 +1,62 @@
+use serde::Deserialize;
+
+use crate::{errors::AppError, misc::import::csv_reader, models::Cultivation};
+
+#[derive(Deserialize)]
+struct CultivationRecord {
+    name: String,
+    scientific_name: Option<String>,
+    variety: Option<String>,
+    cycle_days: Option<i64>,
+    shelf_life_days: Option<i64>,
+}
+
+/// Reads a cultivation list with the columns `name`, `scientific_name`,
+/// `variety`, `cycle_days` and `shelf_life_days`, in any order, separated by
+/// commas or semicolons.
+pub fn parse_cultivations_csv(data: &str) -> Result<Vec<Cultivation>, AppError> {
+    let mut reader = csv_reader(data);
+
+    let mut cultivations = vec![];
+    for (index, record) in reader.deserialize::<CultivationRecord>().enumerate() {
+        let line = index + 2;
+        let record = record.map_err(|err| AppError::BadRequest(format!("Linha {line}: {err}")))?;
+        let cultivation = Cultivation::new(
+            None,
+            record.name,
+            record.scientific_name,
+            record.variety,
+            record.cycle_days,
+            record.shelf_life_days,
+        )
+        .map_err(|err| AppError::BadRequest(format!("Linha {line}: {err}")))?;
+        cultivations.push(cultivation);
+    }
+
+    Ok(cultivations)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn semicolon_separated_lists_are_read() {
+        let cultivations = parse_cultivations_csv(
+            "name;variety;scientific_name;cycle_days;shelf_life_days
+Tomate;Italiano;Solanum lycopersicum;120;15
+Morango; ;Fragaria × ananassa;;7
+",
+        )
+        .unwrap();
+
+        assert_eq!(cultivations.len(), 2);
+        assert_eq!(cultivations[0].full_name(), "Tomate Italiano");
+        assert_eq!(cultivations[0].cycle_days(), Some(120));
+        assert_eq!(cultivations[1].variety(), &None);
+        assert_eq!(cultivations[1].shelf_life_days(), Some(7));
+
+        let error = parse_cultivations_csv("name,cycle_days\nTomate,0\n").unwrap_err();
+        assert!(matches!(error, AppError::BadRequest(message) if message.starts_with("Linha 2:")));
+    }
+}
@@ -8,3 +8,11 @@ pub fn past_or_present_validation(value: &NaiveDate) -> Result<(), ValidationErr
         Err(ValidationError::new("date must be in the past or present"))
     }
 }
+
+pub fn past_or_present_datetime_validation(value: &NaiveDateTime) -> Result<(), ValidationError> {
+    if value <= &Local::now().naive_local() {
+        Ok(())
+    } else {
+        Err(ValidationError::new("date must be in the past or present"))
+    }
+}
@@ -0,0 +1,99 @@
+use validator::ValidationError;
+
+const CNPJ_WEIGHTS: [u32; 13] = [6, 5, 4, 3, 2, 9, 8, 7, 6, 5, 4, 3, 2];
+
+/// Digits of a CPF or CNPJ, which are often written with `.`, `/` and `-`.
+fn digits(document: &str) -> Option<Vec<u32>> {
+    document
+        .chars()
+        .filter(|c| !matches!(c, '.' | '/' | '-' | ' '))
+        .map(|c| c.to_digit(10))
+        .collect()
+}
+
+fn cpf_check_digit(digits: &[u32]) -> u32 {
+    let weight = digits.len() as u32 + 1;
+    let sum: u32 = digits
+        .iter()
+        .enumerate()
+        .map(|(index, digit)| digit * (weight - index as u32))
+        .sum();
+    sum * 10 % 11 % 10
+}
+
+fn cnpj_check_digit(digits: &[u32]) -> u32 {
+    let weights = &CNPJ_WEIGHTS[CNPJ_WEIGHTS.len() - digits.len()..];
+    let sum: u32 = digits
+        .iter()
+        .zip(weights)
+        .map(|(digit, weight)| digit * weight)
+        .sum();
+    match sum % 11 {
+        0 | 1 => 0,
+        rest => 11 - rest,
+    }
+}
+
+fn is_valid(digits: &[u32]) -> bool {
+    let check: fn(&[u32]) -> u32 = match digits.len() {
+        11 => cpf_check_digit,
+        14 => cnpj_check_digit,
+        _ => return false,
+    };
+    let size = digits.len();
+
+    digits.iter().any(|digit| *digit != digits[0])
+        && check(&digits[..size - 2]) == digits[size - 2]
+        && check(&digits[..size - 1]) == digits[size - 1]
+}
+
+/// Accepts a CPF or a CNPJ with valid check digits, punctuated or not.
+pub fn cpf_cnpj_validation(document: &str) -> Result<(), ValidationError> {
+    match digits(document) {
+        Some(digits) if is_valid(&digits) => Ok(()),
+        _ => Err(ValidationError::new("document must be a valid CPF or CNPJ")),
+    }
+}
+
+/// Writes a CPF as `000.000.000-00` and a CNPJ as `00.000.000/0000-00`.
+/// Anything else is returned unchanged.
+pub fn format_cpf_cnpj(document: &str) -> String {
+    let Some(digits) = digits(document) else {
+        return document.to_string();
+    };
+    let digits: String = digits.iter().map(|digit| digit.to_string()).collect();
+    match digits.len() {
+        11 => format!(
+            "{}.{}.{}-{}",
+            &digits[..3],
+            &digits[3..6],
+            &digits[6..9],
+            &digits[9..]
+        ),
+        14 => format!(
+            "{}.{}.{}/{}-{}",
+            &digits[..2],
+            &digits[2..5],
+            &digits[5..8],
+            &digits[8..12],
+            &digits[12..]
+        ),
+        _ => document.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validates_and_formats_cpf_and_cnpj() {
+        assert!(cpf_cnpj_validation("529.982.247-25").is_ok());
+        assert!(cpf_cnpj_validation("52998224724").is_err());
+        assert!(cpf_cnpj_validation("11222333000181").is_ok());
+        assert!(cpf_cnpj_validation("11.222.333/0001-80").is_err());
+        assert!(cpf_cnpj_validation("111.111.111-11").is_err());
+        assert_eq!(format_cpf_cnpj("52998224725"), "529.982.247-25");
+        assert_eq!(format_cpf_cnpj("11222333000181"), "11.222.333/0001-81");
+    }
+}
@@ -0,0 +1,269 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime};
+
+use crate::models::{
+    Batch, BatchEvent, BatchEventType, EpcisAction, EpcisBizTransaction, EpcisDestination,
+    EpcisEvent, EpcisEventType, EpcisLocation, EpcisQuantity, QuantityUnit, Shipment,
+};
+
+pub const EPCIS_CONTEXT: &str = "https://ref.gs1.org/standards/epcis/2.0.0/epcis-context.jsonld";
+pub const EPCIS_SCHEMA_VERSION: &str = "2.0";
+
+/// Dates and times are recorded in Brasília time, which has had no daylight
+/// saving since 2019.
+const TIME_ZONE_OFFSET_SECONDS: i32 = -3 * 3600;
+
+pub fn time_zone() -> FixedOffset {
+    FixedOffset::east_opt(TIME_ZONE_OFFSET_SECONDS).unwrap()
+}
+
+pub fn event_time(at: NaiveDateTime) -> DateTime<FixedOffset> {
+    at.and_local_timezone(time_zone()).unwrap()
+}
+
+fn event_date(date: NaiveDate) -> DateTime<FixedOffset> {
+    event_time(date.and_time(NaiveTime::MIN))
+}
+
+/// UN/CEFACT Recommendation 20 code of `unit`.
+pub fn uom(unit: QuantityUnit) -> &'static str {
+    match unit {
+        QuantityUnit::Kg => "KGM",
+        QuantityUnit::T => "TNE",
+        QuantityUnit::Box => "BX",
+        QuantityUnit::Crate => "CR",
+        QuantityUnit::Unit => "H87",
+    }
+}
+
+/// CBV business step closest to a processing step. Washing has no CBV
+/// counterpart.
+pub fn biz_step(event_type: BatchEventType) -> &'static str {
+    match event_type {
+        BatchEventType::Washing => "other",
+        BatchEventType::Sorting | BatchEventType::Grading => "inspecting",
+        BatchEventType::Packing => "packing",
+        BatchEventType::ColdStorage => "storing",
+    }
+}
+
+/// Source data of [`build_events`]. `classes` maps each batch ID to the URI
+/// used as its EPC class, and `links` holds the `batch_lineage` edges as
+/// `(parent_id, child_id, quantity)`.
+pub struct EpcisSource<'a> {
+    pub base_url: &'a str,
+    pub batches: &'a [Batch],
+    /// Batches dated elsewhere that the events refer to.
+    pub referenced: &'a [Batch],
+    pub classes: &'a HashMap<i64, String>,
+    pub links: &'a [(i64, i64, f64)],
+    pub events: &'a [BatchEvent],
+    pub shipments: &'a [Shipment],
+}
+
+impl EpcisSource<'_> {
+    fn event_id(&self, kind: &str, id: i64) -> Option<String> {
+        Some(format!(
+            "{}/epcis/events/{kind}/{id}",
+            self.base_url.trim_end_matches('/')
+        ))
+    }
+
+    fn quantity(&self, batch_id: i64, quantity: Option<f64>) -> Option<EpcisQuantity> {
+        let batch = self
+            .batches
+            .iter()
+            .chain(self.referenced)
+            .find(|batch| *batch.id() == Some(batch_id))?;
+        Some(EpcisQuantity {
+            epc_class: self.classes.get(&batch_id)?.clone(),
+            quantity,
+            uom: quantity.map(|_| uom(batch.quantity_unit()).to_string()),
+        })
+    }
+
+    /// Quantity of the batch when it was packed, before any of it was split
+    /// off into other batches.
+    fn packed_quantity(&self, batch: &Batch) -> f64 {
+        let id = batch.id().unwrap();
+        batch.quantity()
+            + self
+                .links
+                .iter()
+                .filter(|(parent_id, _, _)| *parent_id == id)
+                .map(|(_, _, quantity)| quantity)
+                .sum::<f64>()
+    }
+}
+
+/// Batches without parents are commissioned, batches made by splitting or
+/// merging others come out of a transformation, processing steps are
+/// observations and each shipment is a shipping observation.
+pub fn build_events(source: &EpcisSource) -> Vec<EpcisEvent> {
+    let base_url = source.base_url.trim_end_matches('/');
+    let mut events = vec![];
+
+    for batch in source.batches {
+        let id = batch.id().unwrap();
+        let quantity = source.quantity(id, Some(source.packed_quantity(batch)));
+        let inputs: Vec<EpcisQuantity> = source
+            .links
+            .iter()
+            .filter(|(_, child_id, _)| *child_id == id)
+            .filter_map(|(parent_id, _, quantity)| source.quantity(*parent_id, Some(*quantity)))
+            .collect();
+
+        let mut event = EpcisEvent {
+            event_type: EpcisEventType::ObjectEvent,
+            event_id: source.event_id("commissioning", id),
+            event_time: event_date(batch.date()),
+            event_time_zone_offset: time_zone().to_string(),
+            action: Some(EpcisAction::Add),
+            biz_step: Some("commissioning".to_string()),
+            disposition: Some("active".to_string()),
+            quantity_list: quantity.clone().into_iter().collect(),
+            input_quantity_list: vec![],
+            output_quantity_list: vec![],
+            biz_location: batch.crop().plot_id().map(|plot_id| EpcisLocation {
+                id: format!("{base_url}/plots/{plot_id}"),
+            }),
+            biz_transaction_list: vec![],
+            destination_list: vec![],
+            extensions: Default::default(),
+        };
+        if !inputs.is_empty() {
+            event.event_type = EpcisEventType::TransformationEvent;
+            event.event_id = source.event_id("transformation", id);
+            event.action = None;
+            event.biz_step = Some("repackaging".to_string());
+            event.quantity_list = vec![];
+            event.input_quantity_list = inputs;
+            event.output_quantity_list = quantity.into_iter().collect();
+            event.biz_location = None;
+        }
+        events.push(event);
+    }
+
+    for event in source.events {
+        events.push(EpcisEvent {
+            event_type: EpcisEventType::ObjectEvent,
+            event_id: source.event_id("processing", event.id().unwrap()),
+            event_time: event_time(event.occurred_at()),
+            event_time_zone_offset: time_zone().to_string(),
+            action: Some(EpcisAction::Observe),
+            biz_step: Some(biz_step(event.event_type()).to_string()),
+            disposition: Some("in_progress".to_string()),
+            quantity_list: source
+                .quantity(event.batch_id(), None)
+                .into_iter()
+                .collect(),
+            input_quantity_list: vec![],
+            output_quantity_list: vec![],
+            biz_location: None,
+            biz_transaction_list: vec![],
+            destination_list: vec![],
+            extensions: Default::default(),
+        });
+    }
+
+    for shipment in source.shipments {
+        let id = shipment.id().unwrap();
+        events.push(EpcisEvent {
+            event_type: EpcisEventType::ObjectEvent,
+            event_id: source.event_id("shipping", id),
+            event_time: event_date(shipment.dispatched_at()),
+            event_time_zone_offset: time_zone().to_string(),
+            action: Some(EpcisAction::Observe),
+            biz_step: Some("shipping".to_string()),
+            disposition: Some("in_transit".to_string()),
+            quantity_list: shipment
+                .items()
+                .iter()
+                .filter_map(|item| source.quantity(item.batch_id(), Some(item.quantity())))
+                .collect(),
+            input_quantity_list: vec![],
+            output_quantity_list: vec![],
+            biz_location: None,
+            biz_transaction_list: vec![EpcisBizTransaction {
+                kind: "desadv".to_string(),
+                biz_transaction: format!("{base_url}/shipments/{id}"),
+            }],
+            destination_list: vec![EpcisDestination {
+                kind: "owning_party".to_string(),
+                destination: format!("{base_url}/customers/{}", shipment.customer_id()),
+            }],
+            extensions: Default::default(),
+        });
+    }
+
+    events
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    use super::*;
+    use crate::models::{AreaUnit, Crop, Cultivation};
+
+    fn batch(id: i64, quantity: f64) -> Batch {
+        let cultivation =
+            Cultivation::new(Some(1), "Tomate".to_string(), None, None, None, None).unwrap();
+        let crop = Crop::new(
+            Some(1),
+            "Tomate A".to_string(),
+            1.0,
+            AreaUnit::Ha,
+            cultivation,
+            NaiveDate::from_ymd_opt(2024, 1, 10).unwrap(),
+            None,
+            Some(3),
+        )
+        .unwrap();
+        Batch::new(
+            Some(id),
+            crop,
+            None,
+            None,
+            "Caixa".to_string(),
+            quantity,
+            QuantityUnit::Kg,
+            Some(format!("LOTE{id}")),
+            NaiveDate::from_ymd_opt(2024, 4, 2).unwrap(),
+            None,
+            None,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn splits_are_transformations_of_the_packed_quantity() {
+        let batches = vec![batch(1, 60.0), batch(2, 40.0)];
+        let classes = HashMap::from([(1, "lote:1".to_string()), (2, "lote:2".to_string())]);
+        let events = build_events(&EpcisSource {
+            base_url: "http://localhost:3333/",
+            batches: &batches,
+            referenced: &[],
+            classes: &classes,
+            links: &[(1, 2, 40.0)],
+            events: &[],
+            shipments: &[],
+        });
+
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].event_type, EpcisEventType::ObjectEvent);
+        assert_eq!(events[0].quantity_list[0].quantity, Some(100.0));
+        assert_eq!(
+            events[0].biz_location.as_ref().unwrap().id,
+            "http://localhost:3333/plots/3"
+        );
+        assert_eq!(events[1].event_type, EpcisEventType::TransformationEvent);
+        assert_eq!(events[1].input_quantity_list[0].epc_class, "lote:1");
+        assert_eq!(events[1].output_quantity_list[0].quantity, Some(40.0));
+        assert_eq!(
+            events[1].event_time.to_rfc3339(),
+            "2024-04-02T00:00:00-03:00"
+        );
+    }
+}
@@ -0,0 +1,87 @@
+use axum::http::{header::IF_MATCH, HeaderMap, HeaderValue};
+
+use crate::errors::AppError;
+
+/// Strong entity tag of a record at `version`, sent as its `ETag`.
+pub fn etag(version: i64) -> Result<HeaderValue, AppError> {
+    Ok(HeaderValue::from_str(&format!("\"{version}\""))?)
+}
+
+/// Versions a change was based on, taken from the `If-Match` header.
+#[derive(Debug, PartialEq)]
+pub enum IfMatch {
+    /// `*`: any current version.
+    Any,
+    Versions(Vec<i64>),
+}
+
+impl IfMatch {
+    /// Changes must name the version they were based on, so requests without
+    /// `If-Match` are refused. Weak and unknown tags never match.
+    pub fn from_headers(headers: &HeaderMap) -> Result<Self, AppError> {
+        let values = headers
+            .get_all(IF_MATCH)
+            .iter()
+            .map(|value| value.to_str().unwrap_or_default())
+            .collect::<Vec<_>>();
+        if values.is_empty() {
+            return Err(AppError::PreconditionRequired(
+                "Informe no cabeçalho If-Match o ETag recebido ao consultar o registro."
+                    .to_string(),
+            ));
+        }
+
+        let tags = values
+            .iter()
+            .flat_map(|value| value.split(','))
+            .map(str::trim);
+        let mut versions = vec![];
+        for tag in tags {
+            if tag == "*" {
+                return Ok(Self::Any);
+            }
+            if let Some(version) = tag
+                .strip_prefix('"')
+                .and_then(|tag| tag.strip_suffix('"'))
+                .and_then(|tag| tag.parse().ok())
+            {
+                versions.push(version);
+            }
+        }
+
+        Ok(Self::Versions(versions))
+    }
+
+    pub fn matches(&self, version: i64) -> bool {
+        match self {
+            Self::Any => true,
+            Self::Versions(versions) => versions.contains(&version),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_if_match() {
+        let if_match = |value: &str| {
+            let mut headers = HeaderMap::new();
+            headers.insert(IF_MATCH, HeaderValue::from_str(value).unwrap());
+            IfMatch::from_headers(&headers).unwrap()
+        };
+
+        assert!(matches!(
+            IfMatch::from_headers(&HeaderMap::new()),
+            Err(AppError::PreconditionRequired(_))
+        ));
+        assert_eq!(if_match("*"), IfMatch::Any);
+        assert_eq!(
+            if_match("\"3\", W/\"4\", \"abc\""),
+            IfMatch::Versions(vec![3])
+        );
+        assert!(if_match(etag(3).unwrap().to_str().unwrap()).matches(3));
+        assert!(!if_match("\"2\"").matches(3));
+    }
+}
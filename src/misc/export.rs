@@ -0,0 +1,267 @@
+use axum::body::Body;
+use chrono::{NaiveDate, NaiveDateTime};
+use futures::Stream;
+use rust_xlsxwriter::{Format, Workbook, Worksheet};
+
+use crate::{
+    errors::AppError,
+    models::{Batch, Crop},
+};
+
+/// Header of the crop export, with the cultivation spelled out.
+pub const CROP_COLUMNS: [&str; 12] = [
+    "id",
+    "name",
+    "area",
+    "areaUnit",
+    "cultivationId",
+    "cultivation",
+    "scientificName",
+    "variety",
+    "plantedAt",
+    "closedAt",
+    "plotId",
+    "deletedAt",
+];
+
+/// Header of the batch export. Each row repeats its crop, so the file can be
+/// read without the crop export.
+pub const BATCH_COLUMNS: [&str; 24] = [
+    "id",
+    "trackingCode",
+    "date",
+    "classification",
+    "processing",
+    "packing",
+    "quantity",
+    "quantityUnit",
+    "shippedQuantity",
+    "availableQuantity",
+    "harvestId",
+    "productId",
+    "cropId",
+    "cropName",
+    "cropArea",
+    "cropAreaUnit",
+    "cultivationId",
+    "cultivation",
+    "scientificName",
+    "variety",
+    "plantedAt",
+    "closedAt",
+    "plotId",
+    "deletedAt",
+];
+
+/// Value of an exported cell, so XLSX keeps numbers and dates typed.
+pub enum ExportCell {
+    Empty,
+    Text(String),
+    Number(f64),
+    Date(NaiveDate),
+    DateTime(NaiveDateTime),
+}
+
+impl From<&str> for ExportCell {
+    fn from(value: &str) -> Self {
+        Self::Text(value.to_string())
+    }
+}
+
+impl From<&Option<String>> for ExportCell {
+    fn from(value: &Option<String>) -> Self {
+        value.as_deref().map_or(Self::Empty, Self::from)
+    }
+}
+
+impl From<f64> for ExportCell {
+    fn from(value: f64) -> Self {
+        Self::Number(value)
+    }
+}
+
+impl From<Option<i64>> for ExportCell {
+    fn from(value: Option<i64>) -> Self {
+        value.map_or(Self::Empty, |value| Self::Number(value as f64))
+    }
+}
+
+impl From<Option<NaiveDate>> for ExportCell {
+    fn from(value: Option<NaiveDate>) -> Self {
+        value.map_or(Self::Empty, Self::Date)
+    }
+}
+
+impl From<Option<NaiveDateTime>> for ExportCell {
+    fn from(value: Option<NaiveDateTime>) -> Self {
+        value.map_or(Self::Empty, Self::DateTime)
+    }
+}
+
+/// Cells of `crop` in the order of [`CROP_COLUMNS`].
+pub fn crop_cells(crop: &Crop) -> Vec<ExportCell> {
+    let cultivation = crop.cultivation();
+    vec![
+        (*crop.id()).into(),
+        crop.name().into(),
+        crop.area().into(),
+        crop.area_unit().as_str().into(),
+        (*cultivation.id()).into(),
+        cultivation.name().into(),
+        cultivation.scientific_name().into(),
+        cultivation.variety().into(),
+        Some(crop.planted_at()).into(),
+        (*crop.closed_at()).into(),
+        crop.plot_id().into(),
+        (*crop.deleted_at()).into(),
+    ]
+}
+
+/// Cells of `batch` and the crop it came from in the order of
+/// [`BATCH_COLUMNS`].
+pub fn batch_cells(batch: &Batch) -> Vec<ExportCell> {
+    let crop = batch.crop();
+    let cultivation = crop.cultivation();
+    vec![
+        (*batch.id()).into(),
+        batch.tracking_code().into(),
+        Some(batch.date()).into(),
+        batch.classification().into(),
+        batch.processing().into(),
+        batch.packing().into(),
+        batch.quantity().into(),
+        batch.quantity_unit().as_str().into(),
+        batch.shipped_quantity().into(),
+        batch.available_quantity().into(),
+        batch.harvest_id().into(),
+        batch.product_id().into(),
+        (*crop.id()).into(),
+        crop.name().into(),
+        crop.area().into(),
+        crop.area_unit().as_str().into(),
+        (*cultivation.id()).into(),
+        cultivation.name().into(),
+        cultivation.scientific_name().into(),
+        cultivation.variety().into(),
+        Some(crop.planted_at()).into(),
+        (*crop.closed_at()).into(),
+        crop.plot_id().into(),
+        (*batch.deleted_at()).into(),
+    ]
+}
+
+/// One CSV line, so rows can be sent as soon as they are read.
+pub fn csv_line<I, T>(cells: I) -> Result<Vec<u8>, AppError>
+where
+    I: IntoIterator<Item = T>,
+    T: Into<ExportCell>,
+{
+    let mut writer = csv::Writer::from_writer(vec![]);
+    writer.write_record(cells.into_iter().map(|cell| match cell.into() {
+        ExportCell::Empty => String::new(),
+        ExportCell::Text(text) => text,
+        ExportCell::Number(number) => number.to_string(),
+        ExportCell::Date(date) => date.to_string(),
+        ExportCell::DateTime(datetime) => datetime.format("%Y-%m-%d %H:%M:%S").to_string(),
+    }))?;
+
+    writer
+        .into_inner()
+        .map_err(|err| AppError::from(csv::Error::from(err.into_error())))
+}
+
+/// Response body sending each CSV line as soon as it is produced.
+pub fn csv_body<S>(lines: S) -> Body
+where
+    S: Stream<Item = Result<Vec<u8>, AppError>> + Send + 'static,
+{
+    Body::from_stream(lines)
+}
+
+/// XLSX workbook with a single sheet whose rows are written to a temporary
+/// file as they are added, instead of being kept in memory.
+pub struct XlsxExport {
+    workbook: Workbook,
+    date_format: Format,
+    datetime_format: Format,
+    row: u32,
+}
+
+impl XlsxExport {
+    pub fn new(sheet_name: &str, columns: &[&str]) -> Result<Self, AppError> {
+        let mut workbook = Workbook::new();
+        let bold = Format::new().set_bold();
+        let worksheet = workbook.add_worksheet_with_constant_memory();
+        worksheet.set_name(sheet_name)?;
+        worksheet.set_freeze_panes(1, 0)?;
+        for (column, title) in columns.iter().enumerate() {
+            worksheet.write_string_with_format(0, column as u16, *title, &bold)?;
+        }
+
+        Ok(Self {
+            workbook,
+            date_format: Format::new().set_num_format("dd/mm/yyyy"),
+            datetime_format: Format::new().set_num_format("dd/mm/yyyy hh:mm"),
+            row: 1,
+        })
+    }
+
+    fn worksheet(&mut self) -> &mut Worksheet {
+        self.workbook.worksheet_from_index(0).unwrap()
+    }
+
+    pub fn push(&mut self, cells: Vec<ExportCell>) -> Result<(), AppError> {
+        let row = self.row;
+        let date_format = self.date_format.clone();
+        let datetime_format = self.datetime_format.clone();
+        let worksheet = self.worksheet();
+        for (column, cell) in cells.into_iter().enumerate() {
+            let column = column as u16;
+            match cell {
+                ExportCell::Empty => continue,
+                ExportCell::Text(text) => worksheet.write_string(row, column, text)?,
+                ExportCell::Number(number) => worksheet.write_number(row, column, number)?,
+                ExportCell::Date(date) => {
+                    worksheet.write_datetime_with_format(row, column, date, &date_format)?
+                }
+                ExportCell::DateTime(datetime) => {
+                    worksheet.write_datetime_with_format(row, column, datetime, &datetime_format)?
+                }
+            };
+        }
+        self.row += 1;
+
+        Ok(())
+    }
+
+    pub fn finish(mut self) -> Result<Vec<u8>, AppError> {
+        Ok(self.workbook.save_to_buffer()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn exports_typed_cells() {
+        let cells = || {
+            vec![
+                ExportCell::from(Some(1)),
+                ExportCell::from("Tomate, italiano"),
+                ExportCell::from(2.5),
+                ExportCell::from(NaiveDate::from_ymd_opt(2024, 4, 2)),
+                ExportCell::from(&None),
+            ]
+        };
+
+        assert_eq!(
+            csv_line(cells()).unwrap(),
+            b"1,\"Tomate, italiano\",2.5,2024-04-02,\n"
+        );
+
+        let mut xlsx = XlsxExport::new("Plantios", &["id", "name", "area", "date", "x"]).unwrap();
+        xlsx.push(cells()).unwrap();
+        assert!(xlsx.finish().unwrap().starts_with(b"PK\x03\x04"));
+    }
+}
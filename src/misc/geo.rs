@@ -0,0 +1,83 @@
+//! Geodesic helpers for GeoJSON boundaries, with positions given as
+//! `[longitude, latitude]` in degrees on the WGS 84 sphere.
+
+use validator::ValidationError;
+
+const EARTH_RADIUS: f64 = 6_378_137.0;
+const SQUARE_METERS_PER_HECTARE: f64 = 10_000.0;
+
+/// Area enclosed by `ring` in square metres, using the spherical excess
+/// approximation also used by most GIS tools.
+fn ring_area(ring: &[Vec<f64>]) -> f64 {
+    let sum: f64 = ring
+        .windows(2)
+        .map(|edge| {
+            let (lon1, lat1) = (edge[0][0].to_radians(), edge[0][1].to_radians());
+            let (lon2, lat2) = (edge[1][0].to_radians(), edge[1][1].to_radians());
+            (lon2 - lon1) * (2.0 + lat1.sin() + lat2.sin())
+        })
+        .sum();
+
+    (sum * EARTH_RADIUS * EARTH_RADIUS / 2.0).abs()
+}
+
+/// Area of a GeoJSON polygon in hectares. The first ring is the outer
+/// boundary and any further rings are holes.
+pub fn polygon_area_hectares(rings: &[Vec<Vec<f64>>]) -> f64 {
+    let mut rings = rings.iter().map(|ring| ring_area(ring));
+    let outer = rings.next().unwrap_or_default();
+    let holes: f64 = rings.sum();
+
+    (outer - holes).max(0.0) / SQUARE_METERS_PER_HECTARE
+}
+
+/// Checks the rings of a GeoJSON polygon: at least one ring, each closed and
+/// with four or more valid positions.
+pub fn validate_polygon(rings: &[Vec<Vec<f64>>]) -> Result<(), ValidationError> {
+    if rings.is_empty() {
+        return Err(ValidationError::new("polygon must have at least one ring"));
+    }
+
+    for ring in rings {
+        if ring.len() < 4 {
+            return Err(ValidationError::new(
+                "polygon rings must have at least four positions",
+            ));
+        }
+        if ring.first() != ring.last() {
+            return Err(ValidationError::new("polygon rings must be closed"));
+        }
+        for position in ring {
+            let valid = matches!(position.len(), 2 | 3)
+                && (-180.0..=180.0).contains(&position[0])
+                && (-90.0..=90.0).contains(&position[1]);
+            if !valid {
+                return Err(ValidationError::new(
+                    "positions must be [longitude, latitude] in degrees",
+                ));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn one_degree_square_at_equator_must_match_reference_area() {
+        let square = vec![vec![
+            vec![0.0, 0.0],
+            vec![1.0, 0.0],
+            vec![1.0, 1.0],
+            vec![0.0, 1.0],
+            vec![0.0, 0.0],
+        ]];
+
+        assert!(validate_polygon(&square).is_ok());
+        let area = polygon_area_hectares(&square);
+        assert!((area - 1_239_139.99).abs() < 1.0, "area was {area}");
+    }
+}
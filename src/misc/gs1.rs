@@ -0,0 +1,106 @@
+use chrono::NaiveDate;
+use validator::ValidationError;
+
+/// Code 128 start character for character set C, followed by FNC1, which
+/// together mark a GS1-128 barcode.
+const GS1_128_START: &str = "ĆŹ";
+const CODE128_SET_B: char = 'Ɓ';
+
+/// GS1 mod 10 check digit of `digits`, which must not include the check
+/// digit itself.
+pub fn gtin_check_digit(digits: &str) -> u32 {
+    let sum: u32 = digits
+        .chars()
+        .rev()
+        .filter_map(|digit| digit.to_digit(10))
+        .enumerate()
+        .map(|(index, digit)| if index % 2 == 0 { digit * 3 } else { digit })
+        .sum();
+    (10 - sum % 10) % 10
+}
+
+/// Accepts GTIN-8, GTIN-12, GTIN-13 and GTIN-14 with a correct check digit.
+pub fn gtin_validation(gtin: &str) -> Result<(), ValidationError> {
+    if ![8, 12, 13, 14].contains(&gtin.len()) || !gtin.chars().all(|c| c.is_ascii_digit()) {
+        return Err(ValidationError::new(
+            "GTIN must have 8, 12, 13 or 14 digits",
+        ));
+    }
+
+    let (digits, check) = gtin.split_at(gtin.len() - 1);
+    if check.parse::<u32>().ok() != Some(gtin_check_digit(digits)) {
+        return Err(ValidationError::new("GTIN check digit is invalid"));
+    }
+
+    Ok(())
+}
+
+/// The 14 digit form of `gtin`, as encoded in AI (01) and Digital Links.
+pub fn normalize_gtin(gtin: &str) -> String {
+    format!("{gtin:0>14}")
+}
+
+/// Human readable GS1 element string, with the fixed length AIs first so no
+/// separator is needed before the lot.
+pub fn element_string(gtin: &str, lot: &str, pack_date: NaiveDate) -> String {
+    format!(
+        "(01){}(13){}(10){lot}",
+        normalize_gtin(gtin),
+        pack_date.format("%y%m%d")
+    )
+}
+
+/// Data for a Code 128 encoder: the numeric AIs in character set C and the
+/// lot in character set B.
+pub fn gs1_128_data(gtin: &str, lot: &str, pack_date: NaiveDate) -> String {
+    format!(
+        "{GS1_128_START}01{}13{}10{CODE128_SET_B}{lot}",
+        normalize_gtin(gtin),
+        pack_date.format("%y%m%d")
+    )
+}
+
+/// GS1 Digital Link URI for the lot, e.g. `{base}/01/07891234567895/10/ABC`.
+pub fn digital_link(base_url: &str, gtin: &str, lot: &str) -> String {
+    let lot: String = lot
+        .bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                (byte as char).to_string()
+            }
+            _ => format!("%{byte:02X}"),
+        })
+        .collect();
+    format!(
+        "{}/01/{}/10/{lot}",
+        base_url.trim_end_matches('/'),
+        normalize_gtin(gtin)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gtins_are_checked_and_encoded() {
+        assert!(gtin_validation("7891234567895").is_ok());
+        assert!(gtin_validation("7891234567890").is_err());
+        assert!(gtin_validation("96385074").is_ok());
+        assert!(gtin_validation("78912345678A5").is_err());
+
+        let date = NaiveDate::from_ymd_opt(2024, 4, 2).unwrap();
+        assert_eq!(
+            element_string("7891234567895", "AbC123", date),
+            "(01)07891234567895(13)240402(10)AbC123"
+        );
+        assert_eq!(
+            gs1_128_data("7891234567895", "AbC123", date),
+            "ĆŹ01078912345678951324040210ƁAbC123"
+        );
+        assert_eq!(
+            digital_link("https://example.com/", "7891234567895", "AbC 1"),
+            "https://example.com/01/07891234567895/10/AbC%201"
+        );
+    }
+}
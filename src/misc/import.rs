@@ -0,0 +1,148 @@
+use std::io::Cursor;
+
+use calamine::{open_workbook_from_rs, Data, Reader, Xlsx};
+use serde::de::DeserializeOwned;
+
+use crate::errors::AppError;
+
+/// XLSX files are ZIP archives.
+const ZIP_SIGNATURE: &[u8] = b"PK\x03\x04";
+
+/// CSV reader for `data` that trims fields and accepts both commas and
+/// semicolons, as spreadsheets save them with a Brazilian locale, as
+/// separators.
+pub fn csv_reader(data: &str) -> csv::Reader<&[u8]> {
+    let header = data.lines().next().unwrap_or_default();
+    let delimiter = if header.contains(';') && !header.contains(',') {
+        b';'
+    } else {
+        b','
+    };
+    csv::ReaderBuilder::new()
+        .delimiter(delimiter)
+        .trim(csv::Trim::All)
+        .from_reader(data.as_bytes())
+}
+
+fn cell_text(cell: &Data) -> String {
+    match cell {
+        Data::Empty => String::new(),
+        Data::Float(value) if value.fract() == 0.0 => format!("{value:.0}"),
+        Data::DateTime(value) => match value.as_datetime() {
+            Some(datetime) if datetime.time() == chrono::NaiveTime::MIN => {
+                datetime.date().to_string()
+            }
+            Some(datetime) => datetime.to_string(),
+            None => value.to_string(),
+        },
+        cell => cell.to_string(),
+    }
+}
+
+/// Converts the first sheet of an XLSX workbook to CSV, so both go through
+/// the same parsing.
+fn xlsx_to_csv(data: &[u8]) -> Result<String, AppError> {
+    let invalid =
+        |err: calamine::XlsxError| AppError::BadRequest(format!("Planilha inválida: {err}"));
+    let mut workbook: Xlsx<_> = open_workbook_from_rs(Cursor::new(data)).map_err(invalid)?;
+    let range = workbook
+        .worksheet_range_at(0)
+        .ok_or_else(|| AppError::BadRequest("A planilha não possui abas".to_string()))?
+        .map_err(invalid)?;
+
+    let mut writer = csv::Writer::from_writer(vec![]);
+    for row in range.rows() {
+        writer.write_record(row.iter().map(cell_text))?;
+    }
+    let data = writer
+        .into_inner()
+        .map_err(|err| AppError::from(csv::Error::from(err.into_error())))?;
+
+    Ok(String::from_utf8(data).unwrap())
+}
+
+/// Line number of a row and either its contents or why it could not be read.
+pub type ImportRow<T> = (u64, Result<T, String>);
+
+/// Line number of the record read from `position`. The reader neither counts
+/// blank lines nor skips them before recording the position, so the line is
+/// taken from the first non-blank byte instead.
+fn line_at(data: &str, position: Option<&csv::Position>) -> u64 {
+    let bytes = data.as_bytes();
+    let mut offset = position.map_or(0, |position| position.byte() as usize);
+    while offset < bytes.len() && matches!(bytes[offset], b'\r' | b'\n') {
+        offset += 1;
+    }
+    bytes[..offset.min(bytes.len())]
+        .iter()
+        .filter(|byte| **byte == b'\n')
+        .count() as u64
+        + 1
+}
+
+/// Reads the rows of a CSV file or XLSX workbook whose header holds the
+/// field names of `T`. Each row comes with its line number, so errors can
+/// point at it, and rows that cannot be read carry the reason.
+pub fn read_rows<T: DeserializeOwned>(data: &[u8]) -> Result<Vec<ImportRow<T>>, AppError> {
+    let data = if data.starts_with(ZIP_SIGNATURE) {
+        xlsx_to_csv(data)?
+    } else {
+        String::from_utf8(data.to_vec())
+            .map_err(|_| AppError::BadRequest("O arquivo deve estar em UTF-8".to_string()))?
+    };
+
+    let mut reader = csv_reader(&data);
+    let headers = reader
+        .headers()
+        .map_err(|err| AppError::BadRequest(format!("Cabeçalho inválido: {err}")))?
+        .clone();
+    let mut rows = vec![];
+    for record in reader.records() {
+        let record = match record {
+            Ok(record) => record,
+            Err(err) => {
+                let line = line_at(&data, err.position());
+                rows.push((line, Err(err.to_string())));
+                continue;
+            }
+        };
+        let line = line_at(&data, record.position());
+        if record.iter().all(str::is_empty) {
+            continue;
+        }
+        rows.push((
+            line,
+            record
+                .deserialize(Some(&headers))
+                .map_err(|err| err.to_string()),
+        ));
+    }
+
+    Ok(rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::Deserialize;
+
+    use super::*;
+
+    #[derive(Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Row {
+        crop_id: i64,
+        quantity: f64,
+    }
+
+    #[test]
+    fn rows_keep_their_line_numbers() {
+        let rows = read_rows::<Row>(b"cropId;quantity\n1;10\n\n2;abc\n").unwrap();
+
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].0, 2);
+        let row = rows[0].1.as_ref().unwrap();
+        assert_eq!((row.crop_id, row.quantity), (1, 10.0));
+        assert_eq!(rows[1].0, 4);
+        assert!(rows[1].1.is_err());
+    }
+}
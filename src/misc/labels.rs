@@ -0,0 +1,285 @@
+use printpdf::{BuiltinFont, Mm, PdfDocument, Rect};
+use qrcode::{Color, QrCode};
+
+use crate::{
+    errors::AppError,
+    models::{Batch, LabelField, LabelFont, LabelTemplate},
+};
+
+const MARGIN: f32 = 4.0;
+const QUIET_ZONE: usize = 2;
+const POINT_TO_MM: f32 = 0.3528;
+const LINE_SPACING: f32 = 1.3;
+/// Builtin PDF fonts come without metrics here, so text is measured as if
+/// every glyph were as wide as a Courier one, which is wider than the average
+/// Helvetica or Times glyph.
+const GLYPH_WIDTH: f32 = 0.6;
+/// Zebra printers print at 203 dpi, i.e. 8 dots per millimetre.
+const DOTS_PER_MM: f64 = 8.0;
+const ZPL_BARCODE_HEIGHT: i64 = 80;
+
+/// Text printed for `field`, or `None` when the field has no text or the
+/// batch has no value for it.
+pub fn field_text(field: LabelField, batch: &Batch) -> Option<String> {
+    match field {
+        LabelField::CropName => Some(format!("Plantio: {}", batch.crop().name())),
+        LabelField::Cultivation => Some(format!(
+            "Cultura: {}",
+            batch.crop().cultivation().full_name()
+        )),
+        LabelField::Classification => batch
+            .classification()
+            .as_ref()
+            .map(|classification| format!("Classificação: {classification}")),
+        LabelField::Processing => batch
+            .processing()
+            .as_ref()
+            .map(|processing| format!("Processamento: {processing}")),
+        LabelField::Packing => Some(format!("Embalagem: {}", batch.packing())),
+        LabelField::Quantity => Some(format!("Quantidade: {}", batch.quantity())),
+        LabelField::Date => Some(format!("Data: {}", batch.date().format("%d/%m/%Y"))),
+        LabelField::TrackingCode => batch
+            .tracking_code()
+            .as_ref()
+            .map(|code| format!("Código: {code}")),
+        LabelField::QrCode => None,
+    }
+}
+
+/// Splits `text` into lines of at most `max_chars` characters, breaking
+/// between words when possible.
+pub fn wrap_text(text: &str, max_chars: usize) -> Vec<String> {
+    let max_chars = max_chars.max(1);
+    let mut lines = vec![];
+    let mut line = String::new();
+    for word in text.split_whitespace() {
+        let mut word: Vec<char> = word.chars().collect();
+        let length = line.chars().count();
+        if length > 0 && length + 1 + word.len() <= max_chars {
+            line.push(' ');
+            line.extend(word);
+            continue;
+        }
+        if length > 0 {
+            lines.push(std::mem::take(&mut line));
+        }
+        while word.len() > max_chars {
+            lines.push(word.drain(..max_chars).collect());
+        }
+        line = word.into_iter().collect();
+    }
+    if !line.is_empty() {
+        lines.push(line);
+    }
+    lines
+}
+
+pub fn render_label_pdf(
+    batch: &Batch,
+    template: &LabelTemplate,
+    qrcode_payload: &str,
+) -> Result<Vec<u8>, AppError> {
+    let width = template.page_width() as f32;
+    let height = template.page_height() as f32;
+    let font_size = template.font_size() as f32;
+    let code = batch.tracking_code().clone().unwrap_or_default();
+
+    let (document, page, layer) =
+        PdfDocument::new(format!("Lote {code}"), Mm(width), Mm(height), "Etiqueta");
+    let font = document.add_builtin_font(match template.font() {
+        LabelFont::Helvetica => BuiltinFont::Helvetica,
+        LabelFont::Times => BuiltinFont::TimesRoman,
+        LabelFont::Courier => BuiltinFont::Courier,
+    })?;
+    let layer = document.get_page(page).get_layer(layer);
+
+    let has_qrcode = template.fields().contains(&LabelField::QrCode);
+    let size = (height - 2.0 * MARGIN).min(width * 0.4);
+
+    // Text stays left of the QR code and is cut at the bottom margin.
+    let text_width = if has_qrcode {
+        width - 3.0 * MARGIN - size
+    } else {
+        width - 2.0 * MARGIN
+    };
+    let max_chars = (text_width / (font_size * POINT_TO_MM * GLYPH_WIDTH)) as usize;
+    let line_height = font_size * POINT_TO_MM * LINE_SPACING;
+    let mut y = height - MARGIN - font_size * POINT_TO_MM;
+    let lines = template
+        .fields()
+        .iter()
+        .filter_map(|field| field_text(*field, batch))
+        .flat_map(|text| wrap_text(&text, max_chars));
+    for line in lines {
+        if y < MARGIN {
+            break;
+        }
+        layer.use_text(line, font_size, Mm(MARGIN), Mm(y), &font);
+        y -= line_height;
+    }
+
+    if has_qrcode {
+        let code = QrCode::new(qrcode_payload)?;
+        let modules = code.width();
+        let colors = code.to_colors();
+        let module = size / (modules + 2 * QUIET_ZONE) as f32;
+        let left = width - MARGIN - size;
+        let top = height - MARGIN;
+
+        for (index, color) in colors.iter().enumerate() {
+            if *color != Color::Dark {
+                continue;
+            }
+            let x = left + (index % modules + QUIET_ZONE) as f32 * module;
+            let y = top - (index / modules + QUIET_ZONE + 1) as f32 * module;
+            layer.add_rect(Rect::new(Mm(x), Mm(y), Mm(x + module), Mm(y + module)));
+        }
+    }
+
+    Ok(document.save_to_bytes()?)
+}
+
+fn dots(mm: f64) -> i64 {
+    (mm * DOTS_PER_MM).round() as i64
+}
+
+/// Removes the ZPL control prefixes so field data cannot end a command early.
+fn zpl_escape(text: &str) -> String {
+    text.replace(['^', '~'], " ")
+}
+
+pub fn render_label_zpl(batch: &Batch, template: &LabelTemplate, qrcode_payload: &str) -> String {
+    let width = dots(template.page_width());
+    let height = dots(template.page_height());
+    let margin = dots(MARGIN as f64);
+    let font_height = dots(template.font_size() * POINT_TO_MM as f64);
+    let line_height = dots(template.font_size() * (POINT_TO_MM * LINE_SPACING) as f64);
+
+    let mut zpl = vec![
+        "^XA".to_string(),
+        "^CI28".to_string(),
+        format!("^PW{width}"),
+        format!("^LL{height}"),
+        format!("^CF0,{font_height}"),
+    ];
+
+    let mut y = margin;
+    for text in template
+        .fields()
+        .iter()
+        .filter_map(|field| field_text(*field, batch))
+    {
+        zpl.push(format!("^FO{margin},{y}^FD{}^FS", zpl_escape(&text)));
+        y += line_height;
+    }
+
+    if let Some(code) = batch.tracking_code() {
+        if template.fields().contains(&LabelField::TrackingCode) {
+            zpl.push(format!(
+                "^FO{margin},{y}^BY2^BCN,{ZPL_BARCODE_HEIGHT},Y,N,N^FD{}^FS",
+                zpl_escape(code)
+            ));
+        }
+    }
+
+    if template.fields().contains(&LabelField::QrCode) {
+        let size = (template.page_height() - 2.0 * MARGIN as f64).min(template.page_width() * 0.4);
+        let magnification = (dots(size) / 40).clamp(1, 10);
+        zpl.push(format!(
+            "^FO{},{margin}^BQN,2,{magnification}^FDQA,{}^FS",
+            width - margin - dots(size),
+            zpl_escape(qrcode_payload)
+        ));
+    }
+
+    zpl.push("^XZ".to_string());
+    zpl.join("\n") + "\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    use super::*;
+    use crate::models::{AreaUnit, Crop, Cultivation, QuantityUnit};
+
+    fn batch() -> Batch {
+        let crop = Crop::new(
+            Some(1),
+            "Talhão 3".to_string(),
+            2.5,
+            AreaUnit::Ha,
+            Cultivation::new(Some(1), "Tomate".to_string(), None, None, None, None).unwrap(),
+            NaiveDate::from_ymd_opt(2024, 1, 10).unwrap(),
+            Some(NaiveDate::from_ymd_opt(2024, 4, 1).unwrap()),
+            None,
+        )
+        .unwrap();
+
+        Batch::new(
+            Some(1),
+            crop,
+            Some("Extra".to_string()),
+            None,
+            "Caixa 20 kg".to_string(),
+            100.0,
+            QuantityUnit::Kg,
+            Some("AbC123xYz789".to_string()),
+            NaiveDate::from_ymd_opt(2024, 4, 2).unwrap(),
+            None,
+            None,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn zpl_label_matches_snapshot() {
+        let zpl = render_label_zpl(
+            &batch(),
+            &LabelTemplate::default_template(),
+            "https://example.com/track/AbC123xYz789",
+        );
+
+        assert_eq!(
+            zpl,
+            "^XA
+^CI28
+^PW800
+^LL560
+^CF0,28
+^FO32,32^FDPlantio: Talhão 3^FS
+^FO32,69^FDCultura: Tomate^FS
+^FO32,106^FDClassificação: Extra^FS
+^FO32,143^FDEmbalagem: Caixa 20 kg^FS
+^FO32,180^FDQuantidade: 100^FS
+^FO32,217^FDData: 02/04/2024^FS
+^FO32,254^FDCódigo: AbC123xYz789^FS
+^FO32,291^BY2^BCN,80,Y,N,N^FDAbC123xYz789^FS
+^FO448,32^BQN,2,8^FDQA,https://example.com/track/AbC123xYz789^FS
+^XZ
+"
+        );
+    }
+
+    #[test]
+    fn wraps_text_to_column_width() {
+        assert_eq!(
+            wrap_text("Embalagem: Caixa plástica 20 kg", 16),
+            vec!["Embalagem: Caixa", "plástica 20 kg"]
+        );
+        assert_eq!(
+            wrap_text("Código: AbC123xYz789", 8),
+            vec!["Código:", "AbC123xY", "z789"]
+        );
+    }
+
+    #[test]
+    fn zpl_field_data_cannot_inject_commands() {
+        let mut batch = batch();
+        batch.set_packing("Caixa^XZ~JR".to_string());
+
+        let zpl = render_label_zpl(&batch, &LabelTemplate::default_template(), "payload");
+
+        assert!(zpl.contains("^FDEmbalagem: Caixa XZ JR^FS"));
+    }
+}
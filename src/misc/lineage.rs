@@ -0,0 +1,97 @@
+use crate::models::LineageGraph;
+
+fn dot_escape(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// Renders the lineage graph as a Graphviz digraph. Edges always point from
+/// the parent batch to the child batch.
+pub fn render_dot(graph: &LineageGraph) -> String {
+    let mut lines = vec![
+        "digraph lineage {".to_string(),
+        "    rankdir=LR;".to_string(),
+    ];
+    for batch in graph.batches() {
+        lines.push(format!(
+            "    \"{}\" [label=\"{}\\n{}\\n{}\"];",
+            batch.id().unwrap(),
+            dot_escape(batch.tracking_code().as_deref().unwrap_or_default()),
+            dot_escape(batch.crop().name()),
+            batch.quantity()
+        ));
+    }
+    for (parent_id, child_id, quantity) in graph.edges() {
+        lines.push(format!(
+            "    \"{parent_id}\" -> \"{child_id}\" [label=\"{quantity}\"];"
+        ));
+    }
+    lines.push("}".to_string());
+    lines.join("\n") + "\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    use super::*;
+    use crate::models::{AreaUnit, Batch, Crop, Cultivation, LineageLink, QuantityUnit};
+
+    fn batch(id: i64, code: &str, quantity: f64) -> Batch {
+        let date = NaiveDate::from_ymd_opt(2024, 4, 2).unwrap();
+        let crop = Crop::new(
+            Some(1),
+            "Talhão 1".to_string(),
+            2.0,
+            AreaUnit::Ha,
+            Cultivation::new(Some(1), "Tomate".to_string(), None, None, None, None).unwrap(),
+            date,
+            None,
+            None,
+        )
+        .unwrap();
+        Batch::new(
+            Some(id),
+            crop,
+            None,
+            None,
+            "Caixa".to_string(),
+            quantity,
+            QuantityUnit::Kg,
+            Some(code.to_string()),
+            date,
+            None,
+            None,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn dot_lists_each_batch_once() {
+        // 1 was split into 2 and 3, which were merged back into 4.
+        let graph = LineageGraph::new(
+            batch(1, "ROOT", 0.0),
+            vec![
+                LineageLink::new(1, 2, 4.0, batch(2, "SPLIT1", 0.0)),
+                LineageLink::new(1, 3, 6.0, batch(3, "SPLIT2", 0.0)),
+                LineageLink::new(2, 4, 4.0, batch(4, "MERGED", 10.0)),
+                LineageLink::new(3, 4, 6.0, batch(4, "MERGED", 10.0)),
+            ],
+        );
+
+        assert_eq!(
+            render_dot(&graph),
+            "digraph lineage {
+    rankdir=LR;
+    \"1\" [label=\"ROOT\\nTalhão 1\\n0\"];
+    \"2\" [label=\"SPLIT1\\nTalhão 1\\n0\"];
+    \"3\" [label=\"SPLIT2\\nTalhão 1\\n0\"];
+    \"4\" [label=\"MERGED\\nTalhão 1\\n10\"];
+    \"1\" -> \"2\" [label=\"4\"];
+    \"1\" -> \"3\" [label=\"6\"];
+    \"2\" -> \"4\" [label=\"4\"];
+    \"3\" -> \"4\" [label=\"6\"];
+}
+"
+        );
+    }
+}
@@ -1,2 +1,17 @@
+pub mod audit;
+pub mod codes;
+pub mod compliance_report;
+pub mod cultivation_csv;
 pub mod date_validation;
+pub mod document;
+pub mod epcis;
+pub mod etag;
+pub mod export;
+pub mod geo;
+pub mod gs1;
+pub mod import;
+pub mod labels;
+pub mod lineage;
+pub mod recall_report;
 pub mod utils;
+pub mod yield_report;
@@ -0,0 +1,169 @@
+use crate::{errors::AppError, models::RecallReport};
+
+const HEADER: [&str; 16] = [
+    "recall_id",
+    "batch_id",
+    "tracking_code",
+    "crop",
+    "cultivation",
+    "batch_date",
+    "packing",
+    "batch_quantity",
+    "shipment_id",
+    "invoice_number",
+    "dispatched_at",
+    "shipped_quantity",
+    "customer_id",
+    "customer_name",
+    "customer_document",
+    "customer_email",
+];
+
+/// Renders the report as CSV with one row per shipped item of each affected
+/// batch. Batches that were never shipped get a single row with the
+/// shipment and customer columns left empty.
+pub fn render_recall_csv(report: &RecallReport) -> Result<Vec<u8>, AppError> {
+    let mut writer = csv::Writer::from_writer(vec![]);
+    writer.write_record(HEADER)?;
+
+    let recall_id = report.recall.id().unwrap().to_string();
+    for batch in &report.batches {
+        let batch_id = batch.id().unwrap();
+        let batch_columns = [
+            recall_id.clone(),
+            batch_id.to_string(),
+            batch.tracking_code().clone().unwrap_or_default(),
+            batch.crop().name().to_string(),
+            batch.crop().cultivation().full_name(),
+            batch.date().to_string(),
+            batch.packing().to_string(),
+            batch.quantity().to_string(),
+        ];
+
+        let mut shipped = false;
+        for shipment in &report.shipments {
+            let customer = report
+                .customers
+                .iter()
+                .find(|customer| *customer.id() == Some(shipment.customer_id()));
+            for item in shipment
+                .items()
+                .iter()
+                .filter(|item| item.batch_id() == batch_id)
+            {
+                shipped = true;
+                let shipment_columns = [
+                    shipment.id().unwrap().to_string(),
+                    shipment.invoice_number().to_string(),
+                    shipment.dispatched_at().to_string(),
+                    item.quantity().to_string(),
+                    shipment.customer_id().to_string(),
+                    customer
+                        .map(|customer| customer.name().to_string())
+                        .unwrap_or_default(),
+                    customer
+                        .and_then(|customer| customer.document().clone())
+                        .unwrap_or_default(),
+                    customer
+                        .and_then(|customer| customer.email().clone())
+                        .unwrap_or_default(),
+                ];
+                writer.write_record(batch_columns.iter().chain(shipment_columns.iter()))?;
+            }
+        }
+
+        if !shipped {
+            writer.write_record(
+                batch_columns
+                    .iter()
+                    .cloned()
+                    .chain(std::iter::repeat_n(String::new(), 8)),
+            )?;
+        }
+    }
+
+    writer
+        .into_inner()
+        .map_err(|err| AppError::from(csv::Error::from(err.into_error())))
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    use super::*;
+    use crate::models::{
+        AreaUnit, Batch, Crop, Cultivation, Customer, QuantityUnit, Recall, RecallStatus, Shipment,
+        ShipmentItem,
+    };
+
+    #[test]
+    fn unshipped_batches_must_have_empty_shipment_columns() {
+        let date = NaiveDate::from_ymd_opt(2024, 4, 2).unwrap();
+        let crop = Crop::new(
+            Some(1),
+            "Talhão 1".to_string(),
+            2.0,
+            AreaUnit::Ha,
+            Cultivation::new(Some(1), "Tomate".to_string(), None, None, None, None).unwrap(),
+            date,
+            None,
+            None,
+        )
+        .unwrap();
+        let batch = |id: i64, code: &str| {
+            Batch::new(
+                Some(id),
+                crop.clone(),
+                None,
+                None,
+                "Caixa".to_string(),
+                10.0,
+                QuantityUnit::Kg,
+                Some(code.to_string()),
+                date,
+                None,
+                None,
+            )
+            .unwrap()
+        };
+        let recall = Recall::new(
+            Some(7),
+            Some(1),
+            None,
+            None,
+            None,
+            None,
+            "Resíduo acima do limite".to_string(),
+            RecallStatus::Open,
+            date.and_hms_opt(8, 0, 0).unwrap(),
+        )
+        .unwrap();
+        let customer =
+            Customer::new(Some(3), "Mercado, Central".to_string(), None, None, None).unwrap();
+        let shipment = Shipment::new(
+            Some(5),
+            3,
+            date,
+            "NF-1".to_string(),
+            vec![ShipmentItem::new(Some(1), 1, 4.0).unwrap()],
+        )
+        .unwrap();
+
+        let report = RecallReport {
+            recall,
+            batches: vec![batch(1, "AAA"), batch(2, "BBB")],
+            shipments: vec![shipment],
+            customers: vec![customer],
+        };
+        let csv = String::from_utf8(render_recall_csv(&report).unwrap()).unwrap();
+
+        assert_eq!(
+            csv.lines().skip(1).collect::<Vec<_>>(),
+            [
+                "7,1,AAA,Talhão 1,Tomate,2024-04-02,Caixa,10,5,NF-1,2024-04-02,4,3,\"Mercado, Central\",,",
+                "7,2,BBB,Talhão 1,Tomate,2024-04-02,Caixa,10,,,,,,,,",
+            ]
+        );
+    }
+}
@@ -0,0 +1,49 @@
+use crate::{errors::AppError, models::YieldReport};
+
+const HEADER: [&str; 11] = [
+    "period",
+    "crop_id",
+    "crop",
+    "cultivation",
+    "crops",
+    "batches",
+    "quantity_kg",
+    "area_ha",
+    "productivity_kg_ha",
+    "cultivation_productivity_kg_ha",
+    "unconverted_batches",
+];
+
+fn optional<T: ToString>(value: &Option<T>) -> String {
+    value.as_ref().map(T::to_string).unwrap_or_default()
+}
+
+/// Renders one CSV row per group. Columns that do not apply to the grouping
+/// are left empty.
+pub fn render_yield_csv(report: &YieldReport) -> Result<Vec<u8>, AppError> {
+    let mut writer = csv::Writer::from_writer(vec![]);
+    writer.write_record(HEADER)?;
+
+    for row in &report.rows {
+        writer.write_record([
+            optional(&row.period),
+            optional(&row.crop_id),
+            optional(&row.crop),
+            row.cultivation.clone(),
+            row.crops.to_string(),
+            row.batches.to_string(),
+            format!("{:.2}", row.quantity),
+            format!("{:.4}", row.area),
+            optional(&row.productivity.map(|value| format!("{value:.2}"))),
+            optional(
+                &row.cultivation_productivity
+                    .map(|value| format!("{value:.2}")),
+            ),
+            row.unconverted.to_string(),
+        ])?;
+    }
+
+    writer
+        .into_inner()
+        .map_err(|err| AppError::from(csv::Error::from(err.into_error())))
+}
@@ -0,0 +1,101 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use validator::{Validate, ValidationErrors};
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, sqlx::Type)]
+#[serde(rename_all = "snake_case")]
+#[sqlx(rename_all = "snake_case")]
+pub enum AuditEntity {
+    Crop,
+    Batch,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, sqlx::Type)]
+#[serde(rename_all = "snake_case")]
+#[sqlx(rename_all = "snake_case")]
+pub enum AuditAction {
+    Create,
+    Update,
+    Delete,
+    Restore,
+}
+
+/// A change to a record. `before` and `after` hold the fields that changed,
+/// so a creation or restoration has no `before` and a deletion no `after`.
+#[derive(Debug, Clone, Validate)]
+pub struct AuditEntry {
+    id: Option<i64>,
+    entity: AuditEntity,
+    entity_id: i64,
+    action: AuditAction,
+    #[validate(length(max = 255))]
+    actor: Option<String>,
+    changed_at: NaiveDateTime,
+    before: Option<Value>,
+    after: Option<Value>,
+}
+
+#[allow(dead_code)]
+impl AuditEntry {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        id: Option<i64>,
+        entity: AuditEntity,
+        entity_id: i64,
+        action: AuditAction,
+        actor: Option<String>,
+        changed_at: NaiveDateTime,
+        before: Option<Value>,
+        after: Option<Value>,
+    ) -> Result<Self, ValidationErrors> {
+        let entry = Self {
+            id,
+            entity,
+            entity_id,
+            action,
+            actor,
+            changed_at,
+            before,
+            after,
+        };
+        entry.validate()?;
+        Ok(entry)
+    }
+
+    pub fn id(&self) -> &Option<i64> {
+        &self.id
+    }
+
+    pub fn set_id(&mut self, id: Option<i64>) {
+        self.id = id;
+    }
+
+    pub fn entity(&self) -> AuditEntity {
+        self.entity
+    }
+
+    pub fn entity_id(&self) -> i64 {
+        self.entity_id
+    }
+
+    pub fn action(&self) -> AuditAction {
+        self.action
+    }
+
+    pub fn actor(&self) -> &Option<String> {
+        &self.actor
+    }
+
+    pub fn changed_at(&self) -> NaiveDateTime {
+        self.changed_at
+    }
+
+    pub fn before(&self) -> &Option<Value> {
+        &self.before
+    }
+
+    pub fn after(&self) -> &Option<Value> {
+        &self.after
+    }
+}
@@ -1,4 +1,4 @@
-use super::crop::Crop;
+use super::{crop::Crop, unit::QuantityUnit};
 use crate::misc::date_validation::past_or_present_validation;
 use validator::{Validate, ValidationErrors};
 
@@ -14,9 +14,16 @@ pub struct Batch {
     packing: String,
     #[validate(range(min = 0.0))]
     quantity: f64,
+    quantity_unit: QuantityUnit,
     tracking_code: Option<String>,
     #[validate(custom(function = "past_or_present_validation"))]
     date: chrono::NaiveDate,
+    harvest_id: Option<i64>,
+    product_id: Option<i64>,
+    origins: Vec<Crop>,
+    shipped_quantity: f64,
+    deleted_at: Option<chrono::NaiveDateTime>,
+    version: i64,
 }
 
 #[allow(dead_code)]
@@ -29,8 +36,11 @@ impl Batch {
         processing: Option<String>,
         packing: String,
         quantity: f64,
+        quantity_unit: QuantityUnit,
         tracking_code: Option<String>,
         date: chrono::NaiveDate,
+        harvest_id: Option<i64>,
+        product_id: Option<i64>,
     ) -> Result<Self, ValidationErrors> {
         let batch = Self {
             id,
@@ -39,8 +49,15 @@ impl Batch {
             processing,
             packing,
             quantity,
+            quantity_unit,
             tracking_code,
             date,
+            harvest_id,
+            product_id,
+            origins: vec![],
+            shipped_quantity: 0.0,
+            deleted_at: None,
+            version: 1,
         };
         batch.validate()?;
         Ok(batch)
@@ -94,6 +111,14 @@ impl Batch {
         self.quantity = quantity;
     }
 
+    pub fn quantity_unit(&self) -> QuantityUnit {
+        self.quantity_unit
+    }
+
+    pub fn set_quantity_unit(&mut self, quantity_unit: QuantityUnit) {
+        self.quantity_unit = quantity_unit;
+    }
+
     pub fn tracking_code(&self) -> &Option<String> {
         &self.tracking_code
     }
@@ -109,4 +134,70 @@ impl Batch {
     pub fn set_date(&mut self, date: chrono::NaiveDate) {
         self.date = date;
     }
+
+    /// Harvest of `crop` the batch was packed from, if it was recorded.
+    pub fn harvest_id(&self) -> Option<i64> {
+        self.harvest_id
+    }
+
+    pub fn set_harvest_id(&mut self, harvest_id: Option<i64>) {
+        self.harvest_id = harvest_id;
+    }
+
+    /// Trade item the batch is a GS1 lot of, if it has a GTIN.
+    pub fn product_id(&self) -> Option<i64> {
+        self.product_id
+    }
+
+    pub fn set_product_id(&mut self, product_id: Option<i64>) {
+        self.product_id = product_id;
+    }
+
+    /// Every crop this batch was produced from. Batches made by merging others
+    /// keep their largest contributor as `crop` and list all of them here.
+    /// Falls back to `crop` when the origins were not loaded.
+    pub fn origins(&self) -> Vec<&Crop> {
+        if self.origins.is_empty() {
+            vec![&self.crop]
+        } else {
+            self.origins.iter().collect()
+        }
+    }
+
+    pub fn set_origins(&mut self, origins: Vec<Crop>) {
+        self.origins = origins;
+    }
+
+    /// Total quantity of this batch already sent to customers.
+    pub fn shipped_quantity(&self) -> f64 {
+        self.shipped_quantity
+    }
+
+    pub fn set_shipped_quantity(&mut self, shipped_quantity: f64) {
+        self.shipped_quantity = shipped_quantity;
+    }
+
+    /// Quantity still available for shipping, splitting or merging.
+    pub fn available_quantity(&self) -> f64 {
+        self.quantity - self.shipped_quantity
+    }
+
+    /// When the batch was deleted. Deleted batches are kept, along with their
+    /// tracking codes, and can be restored.
+    pub fn deleted_at(&self) -> &Option<chrono::NaiveDateTime> {
+        &self.deleted_at
+    }
+
+    pub fn set_deleted_at(&mut self, deleted_at: Option<chrono::NaiveDateTime>) {
+        self.deleted_at = deleted_at;
+    }
+
+    /// Incremented on every change, so a stale copy can't overwrite it.
+    pub fn version(&self) -> i64 {
+        self.version
+    }
+
+    pub fn set_version(&mut self, version: i64) {
+        self.version = version;
+    }
 }
@@ -0,0 +1,121 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use validator::{Validate, ValidationErrors};
+
+use crate::misc::date_validation::past_or_present_datetime_validation;
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, sqlx::Type)]
+#[serde(rename_all = "snake_case")]
+#[sqlx(rename_all = "snake_case")]
+pub enum BatchEventType {
+    Washing,
+    Sorting,
+    Grading,
+    Packing,
+    ColdStorage,
+}
+
+impl BatchEventType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Washing => "washing",
+            Self::Sorting => "sorting",
+            Self::Grading => "grading",
+            Self::Packing => "packing",
+            Self::ColdStorage => "cold_storage",
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            Self::Washing => "Lavagem",
+            Self::Sorting => "Seleção",
+            Self::Grading => "Classificação",
+            Self::Packing => "Embalagem",
+            Self::ColdStorage => "Armazenamento refrigerado",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Validate)]
+pub struct BatchEvent {
+    id: Option<i64>,
+    batch_id: i64,
+    event_type: BatchEventType,
+    #[validate(custom(function = "past_or_present_datetime_validation"))]
+    occurred_at: NaiveDateTime,
+    #[validate(length(max = 255))]
+    operator: Option<String>,
+    notes: Option<String>,
+}
+
+#[allow(dead_code)]
+impl BatchEvent {
+    pub fn new(
+        id: Option<i64>,
+        batch_id: i64,
+        event_type: BatchEventType,
+        occurred_at: NaiveDateTime,
+        operator: Option<String>,
+        notes: Option<String>,
+    ) -> Result<Self, ValidationErrors> {
+        let event = Self {
+            id,
+            batch_id,
+            event_type,
+            occurred_at,
+            operator,
+            notes,
+        };
+        event.validate()?;
+        Ok(event)
+    }
+
+    pub fn id(&self) -> &Option<i64> {
+        &self.id
+    }
+
+    pub fn set_id(&mut self, id: Option<i64>) {
+        self.id = id;
+    }
+
+    pub fn batch_id(&self) -> i64 {
+        self.batch_id
+    }
+
+    pub fn set_batch_id(&mut self, batch_id: i64) {
+        self.batch_id = batch_id;
+    }
+
+    pub fn event_type(&self) -> BatchEventType {
+        self.event_type
+    }
+
+    pub fn set_event_type(&mut self, event_type: BatchEventType) {
+        self.event_type = event_type;
+    }
+
+    pub fn occurred_at(&self) -> NaiveDateTime {
+        self.occurred_at
+    }
+
+    pub fn set_occurred_at(&mut self, occurred_at: NaiveDateTime) {
+        self.occurred_at = occurred_at;
+    }
+
+    pub fn operator(&self) -> &Option<String> {
+        &self.operator
+    }
+
+    pub fn set_operator(&mut self, operator: Option<String>) {
+        self.operator = operator;
+    }
+
+    pub fn notes(&self) -> &Option<String> {
+        &self.notes
+    }
+
+    pub fn set_notes(&mut self, notes: Option<String>) {
+        self.notes = notes;
+    }
+}
@@ -0,0 +1,93 @@
+use serde::{Deserialize, Serialize};
+use validator::{Validate, ValidationErrors};
+
+/// Batch attributes restricted to a managed list of values. Packings have a
+/// weight on top of that and live in their own table.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, sqlx::Type)]
+#[serde(rename_all = "snake_case")]
+#[sqlx(rename_all = "snake_case")]
+pub enum CatalogKind {
+    Classification,
+    Processing,
+}
+
+impl CatalogKind {
+    /// Name of the catalog used in messages.
+    pub fn catalog_name(&self) -> &'static str {
+        match self {
+            Self::Classification => "classificações",
+            Self::Processing => "processamentos",
+        }
+    }
+}
+
+/// One allowed value of a catalog. Batches store the code; inactive entries
+/// stay valid for the batches already using them but cannot be picked for
+/// new ones.
+#[derive(Debug, Clone, Validate)]
+pub struct CatalogEntry {
+    id: Option<i64>,
+    kind: CatalogKind,
+    #[validate(length(min = 1, max = 255))]
+    code: String,
+    #[validate(length(min = 1, max = 255))]
+    description: String,
+    active: bool,
+}
+
+#[allow(dead_code)]
+impl CatalogEntry {
+    pub fn new(
+        id: Option<i64>,
+        kind: CatalogKind,
+        code: String,
+        description: String,
+        active: bool,
+    ) -> Result<Self, ValidationErrors> {
+        let entry = Self {
+            id,
+            kind,
+            code,
+            description,
+            active,
+        };
+        entry.validate()?;
+        Ok(entry)
+    }
+
+    pub fn id(&self) -> &Option<i64> {
+        &self.id
+    }
+
+    pub fn set_id(&mut self, id: Option<i64>) {
+        self.id = id;
+    }
+
+    pub fn kind(&self) -> CatalogKind {
+        self.kind
+    }
+
+    pub fn code(&self) -> &str {
+        &self.code
+    }
+
+    pub fn set_code(&mut self, code: String) {
+        self.code = code;
+    }
+
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+
+    pub fn set_description(&mut self, description: String) {
+        self.description = description;
+    }
+
+    pub fn active(&self) -> bool {
+        self.active
+    }
+
+    pub fn set_active(&mut self, active: bool) {
+        self.active = active;
+    }
+}
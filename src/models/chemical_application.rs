@@ -0,0 +1,125 @@
+use chrono::{Days, NaiveDate};
+use validator::{Validate, ValidationErrors};
+
+use crate::misc::date_validation::past_or_present_validation;
+
+/// A pesticide or other agrochemical sprayed on a crop. Nothing may be
+/// harvested from the crop during the withdrawal period (pre-harvest
+/// interval) that follows the application.
+#[derive(Debug, Clone, Validate)]
+pub struct ChemicalApplication {
+    id: Option<i64>,
+    crop_id: i64,
+    #[validate(length(min = 1, max = 255))]
+    product: String,
+    #[validate(length(min = 1, max = 255))]
+    active_ingredient: String,
+    #[validate(range(exclusive_min = 0.0))]
+    dose: f64,
+    #[validate(length(min = 1, max = 32))]
+    dose_unit: String,
+    #[validate(custom(function = "past_or_present_validation"))]
+    applied_at: NaiveDate,
+    #[validate(range(min = 0, max = 365))]
+    withdrawal_days: i64,
+}
+
+#[allow(dead_code)]
+impl ChemicalApplication {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        id: Option<i64>,
+        crop_id: i64,
+        product: String,
+        active_ingredient: String,
+        dose: f64,
+        dose_unit: String,
+        applied_at: NaiveDate,
+        withdrawal_days: i64,
+    ) -> Result<Self, ValidationErrors> {
+        let application = Self {
+            id,
+            crop_id,
+            product,
+            active_ingredient,
+            dose,
+            dose_unit,
+            applied_at,
+            withdrawal_days,
+        };
+        application.validate()?;
+        Ok(application)
+    }
+
+    pub fn id(&self) -> &Option<i64> {
+        &self.id
+    }
+
+    pub fn set_id(&mut self, id: Option<i64>) {
+        self.id = id;
+    }
+
+    pub fn crop_id(&self) -> i64 {
+        self.crop_id
+    }
+
+    pub fn product(&self) -> &str {
+        &self.product
+    }
+
+    pub fn active_ingredient(&self) -> &str {
+        &self.active_ingredient
+    }
+
+    pub fn dose(&self) -> f64 {
+        self.dose
+    }
+
+    pub fn dose_unit(&self) -> &str {
+        &self.dose_unit
+    }
+
+    pub fn applied_at(&self) -> NaiveDate {
+        self.applied_at
+    }
+
+    pub fn withdrawal_days(&self) -> i64 {
+        self.withdrawal_days
+    }
+
+    /// First day the crop may be harvested again.
+    pub fn withdrawal_ends_at(&self) -> NaiveDate {
+        self.applied_at + Days::new(self.withdrawal_days as u64)
+    }
+
+    /// Whether `date` falls inside the withdrawal period.
+    pub fn is_withdrawal_active_on(&self, date: NaiveDate) -> bool {
+        self.applied_at <= date && date < self.withdrawal_ends_at()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn withdrawal_must_end_after_the_interval() {
+        let application = ChemicalApplication::new(
+            None,
+            1,
+            "Produto".to_string(),
+            "Ingrediente".to_string(),
+            1.5,
+            "L/ha".to_string(),
+            NaiveDate::from_ymd_opt(2024, 3, 1).unwrap(),
+            7,
+        )
+        .unwrap();
+
+        let date = |day| NaiveDate::from_ymd_opt(2024, 3, day).unwrap();
+        assert_eq!(application.withdrawal_ends_at(), date(8));
+        assert!(application.is_withdrawal_active_on(date(1)));
+        assert!(application.is_withdrawal_active_on(date(7)));
+        assert!(!application.is_withdrawal_active_on(date(8)));
+    }
+}
@@ -0,0 +1,33 @@
+use chrono::NaiveDate;
+
+use super::unit::QuantityUnit;
+
+/// One movement of a lot: its receipt and, when it left within the period,
+/// one of its dispatches. A lot dispatched several times has one row each.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ComplianceRow {
+    pub producer_name: Option<String>,
+    pub producer_document: Option<String>,
+    pub producer_address: Option<String>,
+    pub product: String,
+    pub variety: Option<String>,
+    pub lot: String,
+    pub quantity: f64,
+    pub quantity_unit: QuantityUnit,
+    pub received_at: NaiveDate,
+    pub dispatched_at: Option<NaiveDate>,
+    pub dispatched_quantity: Option<f64>,
+    pub invoice_number: Option<String>,
+    pub recipient_name: Option<String>,
+    pub recipient_document: Option<String>,
+    pub recipient_address: Option<String>,
+}
+
+/// Records required by the Joint Normative Instruction MAPA/ANVISA 02/2018
+/// for the lots received or dispatched within the period.
+#[derive(Debug, Clone)]
+pub struct ComplianceReport {
+    pub from: NaiveDate,
+    pub to: NaiveDate,
+    pub rows: Vec<ComplianceRow>,
+}
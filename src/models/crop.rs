@@ -1,5 +1,6 @@
+use super::{cultivation::Cultivation, unit::AreaUnit};
 use crate::misc::date_validation::past_or_present_validation;
-use chrono::NaiveDate;
+use chrono::{NaiveDate, NaiveDateTime};
 use validator::{Validate, ValidationErrors};
 
 #[derive(Debug, Clone, Validate)]
@@ -9,31 +10,41 @@ pub struct Crop {
     name: String,
     #[validate(range(min = 0.0))]
     area: f64,
-    #[validate(length(max = 255))]
-    cultivation: String,
+    area_unit: AreaUnit,
+    cultivation: Cultivation,
     #[validate(custom(function = "past_or_present_validation"))]
     planted_at: NaiveDate,
     #[validate(custom(function = "past_or_present_validation"))]
-    harvested_at: Option<NaiveDate>,
+    closed_at: Option<NaiveDate>,
+    plot_id: Option<i64>,
+    deleted_at: Option<NaiveDateTime>,
+    version: i64,
 }
 
 #[allow(dead_code)]
 impl Crop {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         id: Option<i64>,
         name: String,
         area: f64,
-        cultivation: String,
+        area_unit: AreaUnit,
+        cultivation: Cultivation,
         planted_at: NaiveDate,
-        harvested_at: Option<NaiveDate>,
+        closed_at: Option<NaiveDate>,
+        plot_id: Option<i64>,
     ) -> Result<Self, ValidationErrors> {
         let crop = Self {
             id,
             name,
             area,
+            area_unit,
             cultivation,
             planted_at,
-            harvested_at,
+            closed_at,
+            plot_id,
+            deleted_at: None,
+            version: 1,
         };
 
         crop.validate()?;
@@ -65,11 +76,25 @@ impl Crop {
         self.area = area;
     }
 
-    pub fn cultivation(&self) -> &str {
+    pub fn area_unit(&self) -> AreaUnit {
+        self.area_unit
+    }
+
+    pub fn set_area_unit(&mut self, area_unit: AreaUnit) {
+        self.area_unit = area_unit;
+    }
+
+    /// Converts the area to `unit` in place.
+    pub fn convert_area(&mut self, unit: AreaUnit) {
+        self.area = self.area_unit.convert(self.area, unit);
+        self.area_unit = unit;
+    }
+
+    pub fn cultivation(&self) -> &Cultivation {
         &self.cultivation
     }
 
-    pub fn set_cultivation(&mut self, cultivation: String) {
+    pub fn set_cultivation(&mut self, cultivation: Cultivation) {
         self.cultivation = cultivation;
     }
 
@@ -81,11 +106,38 @@ impl Crop {
         self.planted_at = planted_at;
     }
 
-    pub fn harvested_at(&self) -> &Option<NaiveDate> {
-        &self.harvested_at
+    pub fn closed_at(&self) -> &Option<NaiveDate> {
+        &self.closed_at
+    }
+
+    pub fn set_closed_at(&mut self, closed_at: Option<NaiveDate>) {
+        self.closed_at = closed_at;
+    }
+
+    /// Plot the crop was planted on.
+    pub fn plot_id(&self) -> Option<i64> {
+        self.plot_id
+    }
+
+    pub fn set_plot_id(&mut self, plot_id: Option<i64>) {
+        self.plot_id = plot_id;
+    }
+
+    /// When the crop was deleted. Deleted crops are kept and can be restored.
+    pub fn deleted_at(&self) -> &Option<NaiveDateTime> {
+        &self.deleted_at
+    }
+
+    pub fn set_deleted_at(&mut self, deleted_at: Option<NaiveDateTime>) {
+        self.deleted_at = deleted_at;
+    }
+
+    /// Incremented on every change, so a stale copy can't overwrite it.
+    pub fn version(&self) -> i64 {
+        self.version
     }
 
-    pub fn set_harvested_at(&mut self, harvested_at: Option<NaiveDate>) {
-        self.harvested_at = harvested_at;
+    pub fn set_version(&mut self, version: i64) {
+        self.version = version;
     }
 }
@@ -0,0 +1,99 @@
+use validator::{Validate, ValidationErrors};
+
+/// A species, optionally narrowed down to a variety, crops are planted with.
+/// Crops of the same species share the `name` whatever their variety.
+#[derive(Debug, Clone, PartialEq, Validate)]
+pub struct Cultivation {
+    id: Option<i64>,
+    #[validate(length(min = 1, max = 255))]
+    name: String,
+    #[validate(length(max = 255))]
+    scientific_name: Option<String>,
+    #[validate(length(max = 255))]
+    variety: Option<String>,
+    /// Typical days from planting to the end of harvest.
+    #[validate(range(min = 1))]
+    cycle_days: Option<i64>,
+    /// Days a harvested product usually keeps.
+    #[validate(range(min = 1))]
+    shelf_life_days: Option<i64>,
+}
+
+#[allow(dead_code)]
+impl Cultivation {
+    pub fn new(
+        id: Option<i64>,
+        name: String,
+        scientific_name: Option<String>,
+        variety: Option<String>,
+        cycle_days: Option<i64>,
+        shelf_life_days: Option<i64>,
+    ) -> Result<Self, ValidationErrors> {
+        let cultivation = Self {
+            id,
+            name,
+            scientific_name,
+            variety,
+            cycle_days,
+            shelf_life_days,
+        };
+        cultivation.validate()?;
+        Ok(cultivation)
+    }
+
+    pub fn id(&self) -> &Option<i64> {
+        &self.id
+    }
+
+    pub fn set_id(&mut self, id: Option<i64>) {
+        self.id = id;
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn set_name(&mut self, name: String) {
+        self.name = name;
+    }
+
+    pub fn scientific_name(&self) -> &Option<String> {
+        &self.scientific_name
+    }
+
+    pub fn set_scientific_name(&mut self, scientific_name: Option<String>) {
+        self.scientific_name = scientific_name;
+    }
+
+    pub fn variety(&self) -> &Option<String> {
+        &self.variety
+    }
+
+    pub fn set_variety(&mut self, variety: Option<String>) {
+        self.variety = variety;
+    }
+
+    pub fn cycle_days(&self) -> Option<i64> {
+        self.cycle_days
+    }
+
+    pub fn set_cycle_days(&mut self, cycle_days: Option<i64>) {
+        self.cycle_days = cycle_days;
+    }
+
+    pub fn shelf_life_days(&self) -> Option<i64> {
+        self.shelf_life_days
+    }
+
+    pub fn set_shelf_life_days(&mut self, shelf_life_days: Option<i64>) {
+        self.shelf_life_days = shelf_life_days;
+    }
+
+    /// Name followed by the variety, e.g. "Tomate Italiano".
+    pub fn full_name(&self) -> String {
+        match &self.variety {
+            Some(variety) => format!("{} {}", self.name, variety),
+            None => self.name.clone(),
+        }
+    }
+}
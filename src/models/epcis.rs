@@ -0,0 +1,101 @@
+use chrono::{DateTime, FixedOffset};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+/// Named as in the standard, where the `Event` suffix is part of the type.
+#[allow(clippy::enum_variant_names)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, sqlx::Type)]
+pub enum EpcisEventType {
+    ObjectEvent,
+    AggregationEvent,
+    TransactionEvent,
+    TransformationEvent,
+    AssociationEvent,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum EpcisAction {
+    Add,
+    Observe,
+    Delete,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EpcisQuantity {
+    pub epc_class: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quantity: Option<f64>,
+    /// UN/CEFACT Recommendation 20 unit code.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uom: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EpcisBizTransaction {
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub biz_transaction: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EpcisDestination {
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub destination: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EpcisLocation {
+    pub id: String,
+}
+
+/// An EPCIS 2.0 event in its JSON-LD form. Fields this application does not
+/// produce, such as `epcList` or `ilmd` in events captured from partners, are
+/// kept in `extensions`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EpcisEvent {
+    #[serde(rename = "type")]
+    pub event_type: EpcisEventType,
+    #[serde(rename = "eventID", skip_serializing_if = "Option::is_none")]
+    pub event_id: Option<String>,
+    pub event_time: DateTime<FixedOffset>,
+    pub event_time_zone_offset: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action: Option<EpcisAction>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub biz_step: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disposition: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub quantity_list: Vec<EpcisQuantity>,
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub input_quantity_list: Vec<EpcisQuantity>,
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub output_quantity_list: Vec<EpcisQuantity>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub biz_location: Option<EpcisLocation>,
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub biz_transaction_list: Vec<EpcisBizTransaction>,
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub destination_list: Vec<EpcisDestination>,
+    #[serde(flatten)]
+    pub extensions: Map<String, Value>,
+}
+
+impl EpcisEvent {
+    /// Every event type but `TransformationEvent` requires an action.
+    pub fn check(&self) -> Result<(), String> {
+        if self.action.is_none() && self.event_type != EpcisEventType::TransformationEvent {
+            return Err(format!(
+                "o campo action é obrigatório em {:?}",
+                self.event_type
+            ));
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,63 @@
+use validator::{Validate, ValidationErrors};
+
+#[derive(Debug, Clone, Validate)]
+pub struct Farm {
+    id: Option<i64>,
+    #[validate(length(min = 1, max = 255))]
+    name: String,
+    #[validate(length(max = 32))]
+    document: Option<String>,
+    #[validate(length(max = 255))]
+    address: Option<String>,
+}
+
+#[allow(dead_code)]
+impl Farm {
+    pub fn new(
+        id: Option<i64>,
+        name: String,
+        document: Option<String>,
+        address: Option<String>,
+    ) -> Result<Self, ValidationErrors> {
+        let farm = Self {
+            id,
+            name,
+            document,
+            address,
+        };
+        farm.validate()?;
+        Ok(farm)
+    }
+
+    pub fn id(&self) -> &Option<i64> {
+        &self.id
+    }
+
+    pub fn set_id(&mut self, id: Option<i64>) {
+        self.id = id;
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn set_name(&mut self, name: String) {
+        self.name = name;
+    }
+
+    pub fn document(&self) -> &Option<String> {
+        &self.document
+    }
+
+    pub fn set_document(&mut self, document: Option<String>) {
+        self.document = document;
+    }
+
+    pub fn address(&self) -> &Option<String> {
+        &self.address
+    }
+
+    pub fn set_address(&mut self, address: Option<String>) {
+        self.address = address;
+    }
+}
@@ -0,0 +1,71 @@
+use chrono::NaiveDate;
+use validator::{Validate, ValidationErrors};
+
+use super::unit::QuantityUnit;
+use crate::misc::date_validation::past_or_present_validation;
+
+/// One pass of harvesting a crop. Crops such as tomatoes are harvested many
+/// times before their cycle is closed.
+#[derive(Debug, Clone, Validate)]
+pub struct Harvest {
+    id: Option<i64>,
+    crop_id: i64,
+    #[validate(custom(function = "past_or_present_validation"))]
+    harvested_at: NaiveDate,
+    #[validate(range(min = 0.0))]
+    quantity: f64,
+    quantity_unit: QuantityUnit,
+    #[validate(length(max = 255))]
+    team: Option<String>,
+}
+
+#[allow(dead_code)]
+impl Harvest {
+    pub fn new(
+        id: Option<i64>,
+        crop_id: i64,
+        harvested_at: NaiveDate,
+        quantity: f64,
+        quantity_unit: QuantityUnit,
+        team: Option<String>,
+    ) -> Result<Self, ValidationErrors> {
+        let harvest = Self {
+            id,
+            crop_id,
+            harvested_at,
+            quantity,
+            quantity_unit,
+            team,
+        };
+        harvest.validate()?;
+        Ok(harvest)
+    }
+
+    pub fn id(&self) -> &Option<i64> {
+        &self.id
+    }
+
+    pub fn set_id(&mut self, id: Option<i64>) {
+        self.id = id;
+    }
+
+    pub fn crop_id(&self) -> i64 {
+        self.crop_id
+    }
+
+    pub fn harvested_at(&self) -> NaiveDate {
+        self.harvested_at
+    }
+
+    pub fn quantity(&self) -> f64 {
+        self.quantity
+    }
+
+    pub fn quantity_unit(&self) -> QuantityUnit {
+        self.quantity_unit
+    }
+
+    pub fn team(&self) -> &Option<String> {
+        &self.team
+    }
+}
@@ -0,0 +1,22 @@
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImportRowError {
+    pub line: u64,
+    pub message: String,
+}
+
+/// Outcome of a bulk import. Nothing is inserted when any row has errors.
+#[derive(Debug, Clone, Default)]
+pub struct ImportReport {
+    pub rows: usize,
+    pub inserted: usize,
+    pub errors: Vec<ImportRowError>,
+}
+
+impl ImportReport {
+    pub fn add_error(&mut self, line: u64, message: impl ToString) {
+        self.errors.push(ImportRowError {
+            line,
+            message: message.to_string(),
+        });
+    }
+}
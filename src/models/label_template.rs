@@ -0,0 +1,227 @@
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use validator::{Validate, ValidationErrors};
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LabelFont {
+    Helvetica,
+    Times,
+    Courier,
+}
+
+impl LabelFont {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Helvetica => "helvetica",
+            Self::Times => "times",
+            Self::Courier => "courier",
+        }
+    }
+}
+
+impl FromStr for LabelFont {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "helvetica" => Ok(Self::Helvetica),
+            "times" => Ok(Self::Times),
+            "courier" => Ok(Self::Courier),
+            _ => Err(format!("Fonte inválida: {value}")),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LabelField {
+    CropName,
+    Cultivation,
+    Classification,
+    Processing,
+    Packing,
+    Quantity,
+    Date,
+    TrackingCode,
+    QrCode,
+}
+
+impl LabelField {
+    pub const ALL: [LabelField; 9] = [
+        Self::CropName,
+        Self::Cultivation,
+        Self::Classification,
+        Self::Processing,
+        Self::Packing,
+        Self::Quantity,
+        Self::Date,
+        Self::TrackingCode,
+        Self::QrCode,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::CropName => "crop_name",
+            Self::Cultivation => "cultivation",
+            Self::Classification => "classification",
+            Self::Processing => "processing",
+            Self::Packing => "packing",
+            Self::Quantity => "quantity",
+            Self::Date => "date",
+            Self::TrackingCode => "tracking_code",
+            Self::QrCode => "qr_code",
+        }
+    }
+}
+
+impl FromStr for LabelField {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|field| field.as_str() == value)
+            .ok_or_else(|| format!("Campo de etiqueta inválido: {value}"))
+    }
+}
+
+#[derive(Debug, Clone, Validate)]
+pub struct LabelTemplate {
+    id: Option<i64>,
+    #[validate(length(min = 1, max = 255))]
+    organization: String,
+    #[validate(length(min = 1, max = 255))]
+    name: String,
+    #[validate(range(min = 20.0, max = 500.0))]
+    page_width: f64,
+    #[validate(range(min = 20.0, max = 500.0))]
+    page_height: f64,
+    font: LabelFont,
+    #[validate(range(min = 4.0, max = 72.0))]
+    font_size: f64,
+    #[validate(length(min = 1))]
+    fields: Vec<LabelField>,
+    is_default: bool,
+}
+
+#[allow(dead_code)]
+impl LabelTemplate {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        id: Option<i64>,
+        organization: String,
+        name: String,
+        page_width: f64,
+        page_height: f64,
+        font: LabelFont,
+        font_size: f64,
+        fields: Vec<LabelField>,
+        is_default: bool,
+    ) -> Result<Self, ValidationErrors> {
+        let template = Self {
+            id,
+            organization,
+            name,
+            page_width,
+            page_height,
+            font,
+            font_size,
+            fields,
+            is_default,
+        };
+        template.validate()?;
+        Ok(template)
+    }
+
+    /// Template used when a label is requested without choosing one and the
+    /// organization has no default: a 100 x 70 mm label with every field.
+    pub fn default_template() -> Self {
+        Self {
+            id: None,
+            organization: String::new(),
+            name: "default".to_string(),
+            page_width: 100.0,
+            page_height: 70.0,
+            font: LabelFont::Helvetica,
+            font_size: 10.0,
+            fields: LabelField::ALL.to_vec(),
+            is_default: false,
+        }
+    }
+
+    pub fn id(&self) -> &Option<i64> {
+        &self.id
+    }
+
+    pub fn set_id(&mut self, id: Option<i64>) {
+        self.id = id;
+    }
+
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+
+    pub fn set_organization(&mut self, organization: String) {
+        self.organization = organization;
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn set_name(&mut self, name: String) {
+        self.name = name;
+    }
+
+    pub fn page_width(&self) -> f64 {
+        self.page_width
+    }
+
+    pub fn set_page_width(&mut self, page_width: f64) {
+        self.page_width = page_width;
+    }
+
+    pub fn page_height(&self) -> f64 {
+        self.page_height
+    }
+
+    pub fn set_page_height(&mut self, page_height: f64) {
+        self.page_height = page_height;
+    }
+
+    pub fn font(&self) -> LabelFont {
+        self.font
+    }
+
+    pub fn set_font(&mut self, font: LabelFont) {
+        self.font = font;
+    }
+
+    pub fn font_size(&self) -> f64 {
+        self.font_size
+    }
+
+    pub fn set_font_size(&mut self, font_size: f64) {
+        self.font_size = font_size;
+    }
+
+    pub fn fields(&self) -> &[LabelField] {
+        &self.fields
+    }
+
+    pub fn set_fields(&mut self, fields: Vec<LabelField>) {
+        self.fields = fields;
+    }
+
+    /// Whether labels of the organization use this template when none is
+    /// chosen. An organization has at most one default.
+    pub fn is_default(&self) -> bool {
+        self.is_default
+    }
+
+    pub fn set_is_default(&mut self, is_default: bool) {
+        self.is_default = is_default;
+    }
+}
@@ -0,0 +1,81 @@
+use std::collections::HashSet;
+
+use serde::Deserialize;
+
+use super::Batch;
+
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LineageDirection {
+    /// Towards the batches this one was made from.
+    Up,
+    /// Towards the batches made from this one.
+    Down,
+}
+
+/// A lineage edge as stored in `batch_lineage`, along with the batch on the
+/// far side of the traversal.
+#[derive(Debug, Clone)]
+pub struct LineageLink {
+    parent_id: i64,
+    child_id: i64,
+    quantity: f64,
+    batch: Batch,
+}
+
+impl LineageLink {
+    pub fn new(parent_id: i64, child_id: i64, quantity: f64, batch: Batch) -> Self {
+        Self {
+            parent_id,
+            child_id,
+            quantity,
+            batch,
+        }
+    }
+}
+
+/// Batches reached from a root batch through `batch_lineage` and the edges
+/// between them. A batch reached along several paths, e.g. split and then
+/// merged back, appears once.
+#[derive(Debug, Clone)]
+pub struct LineageGraph {
+    root_id: i64,
+    batches: Vec<Batch>,
+    edges: Vec<(i64, i64, f64)>,
+}
+
+impl LineageGraph {
+    pub fn new(root: Batch, links: Vec<LineageLink>) -> Self {
+        let root_id = root.id().unwrap();
+        let mut seen = HashSet::from([root_id]);
+        let mut batches = vec![root];
+        let mut edges = Vec::with_capacity(links.len());
+        for link in links {
+            edges.push((link.parent_id, link.child_id, link.quantity));
+            if seen.insert(link.batch.id().unwrap()) {
+                batches.push(link.batch);
+            }
+        }
+
+        Self {
+            root_id,
+            batches,
+            edges,
+        }
+    }
+
+    pub fn root_id(&self) -> i64 {
+        self.root_id
+    }
+
+    /// Every batch of the graph, starting with the root.
+    pub fn batches(&self) -> &[Batch] {
+        &self.batches
+    }
+
+    /// Edges as `(parent_id, child_id, quantity)`, whatever the direction
+    /// the graph was walked in.
+    pub fn edges(&self) -> &[(i64, i64, f64)] {
+        &self.edges
+    }
+}
@@ -1,4 +1,50 @@
+mod audit;
 mod batch;
+mod batch_event;
+mod catalog;
+mod chemical_application;
+mod compliance_report;
 mod crop;
+mod cultivation;
+mod customer;
+mod epcis;
+mod farm;
+mod harvest;
+mod import_report;
+mod label_template;
+mod lineage;
+mod packing;
+mod plot;
+mod product;
+mod recall;
+mod shipment;
+mod unit;
+mod yield_report;
 
-pub use self::{batch::Batch, crop::Crop};
+pub use self::{
+    audit::{AuditAction, AuditEntity, AuditEntry},
+    batch::Batch,
+    batch_event::{BatchEvent, BatchEventType},
+    catalog::{CatalogEntry, CatalogKind},
+    chemical_application::ChemicalApplication,
+    compliance_report::{ComplianceReport, ComplianceRow},
+    crop::Crop,
+    cultivation::Cultivation,
+    customer::Customer,
+    epcis::{
+        EpcisAction, EpcisBizTransaction, EpcisDestination, EpcisEvent, EpcisEventType,
+        EpcisLocation, EpcisQuantity,
+    },
+    farm::Farm,
+    harvest::Harvest,
+    import_report::{ImportReport, ImportRowError},
+    label_template::{LabelField, LabelFont, LabelTemplate},
+    lineage::{LineageDirection, LineageGraph, LineageLink},
+    packing::Packing,
+    plot::{Plot, PlotBoundary},
+    product::Product,
+    recall::{Recall, RecallReport, RecallStatus},
+    shipment::{Shipment, ShipmentItem},
+    unit::{AreaUnit, QuantityUnit},
+    yield_report::{YieldGroupBy, YieldReport, YieldRow},
+};
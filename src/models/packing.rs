@@ -0,0 +1,76 @@
+use validator::{Validate, ValidationErrors};
+
+/// A kind of package batches are packed in, e.g. "CX20" for "Caixa 20 kg".
+/// Its weight, when known, converts quantities counted in packages to mass.
+#[derive(Debug, Clone, Validate)]
+pub struct Packing {
+    id: Option<i64>,
+    #[validate(length(min = 1, max = 255))]
+    code: String,
+    #[validate(length(min = 1, max = 255))]
+    description: String,
+    #[validate(range(exclusive_min = 0.0))]
+    kg_per_unit: Option<f64>,
+    active: bool,
+}
+
+#[allow(dead_code)]
+impl Packing {
+    pub fn new(
+        id: Option<i64>,
+        code: String,
+        description: String,
+        kg_per_unit: Option<f64>,
+        active: bool,
+    ) -> Result<Self, ValidationErrors> {
+        let packing = Self {
+            id,
+            code,
+            description,
+            kg_per_unit,
+            active,
+        };
+        packing.validate()?;
+        Ok(packing)
+    }
+
+    pub fn id(&self) -> &Option<i64> {
+        &self.id
+    }
+
+    pub fn set_id(&mut self, id: Option<i64>) {
+        self.id = id;
+    }
+
+    pub fn code(&self) -> &str {
+        &self.code
+    }
+
+    pub fn set_code(&mut self, code: String) {
+        self.code = code;
+    }
+
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+
+    pub fn set_description(&mut self, description: String) {
+        self.description = description;
+    }
+
+    pub fn kg_per_unit(&self) -> Option<f64> {
+        self.kg_per_unit
+    }
+
+    pub fn set_kg_per_unit(&mut self, kg_per_unit: Option<f64>) {
+        self.kg_per_unit = kg_per_unit;
+    }
+
+    pub fn active(&self) -> bool {
+        self.active
+    }
+
+    pub fn set_active(&mut self, active: bool) {
+        self.active = active;
+    }
+}
@@ -0,0 +1,89 @@
+use serde::{Deserialize, Serialize};
+use validator::{Validate, ValidationError, ValidationErrors};
+
+use crate::misc::geo::{polygon_area_hectares, validate_polygon};
+
+/// GeoJSON geometry of a plot. Only polygons are accepted.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum PlotBoundary {
+    Polygon { coordinates: Vec<Vec<Vec<f64>>> },
+}
+
+impl PlotBoundary {
+    fn rings(&self) -> &[Vec<Vec<f64>>] {
+        match self {
+            Self::Polygon { coordinates } => coordinates,
+        }
+    }
+}
+
+fn boundary_validation(boundary: &PlotBoundary) -> Result<(), ValidationError> {
+    validate_polygon(boundary.rings())
+}
+
+/// A field of a farm. `area` is computed from the boundary, in hectares.
+#[derive(Debug, Clone, Validate)]
+pub struct Plot {
+    id: Option<i64>,
+    farm_id: i64,
+    #[validate(length(min = 1, max = 255))]
+    name: String,
+    #[validate(custom(function = "boundary_validation"))]
+    boundary: PlotBoundary,
+    area: f64,
+}
+
+#[allow(dead_code)]
+impl Plot {
+    pub fn new(
+        id: Option<i64>,
+        farm_id: i64,
+        name: String,
+        boundary: PlotBoundary,
+    ) -> Result<Self, ValidationErrors> {
+        let mut plot = Self {
+            id,
+            farm_id,
+            name,
+            boundary,
+            area: 0.0,
+        };
+        plot.validate()?;
+        plot.area = polygon_area_hectares(plot.boundary.rings());
+        Ok(plot)
+    }
+
+    pub fn id(&self) -> &Option<i64> {
+        &self.id
+    }
+
+    pub fn set_id(&mut self, id: Option<i64>) {
+        self.id = id;
+    }
+
+    pub fn farm_id(&self) -> i64 {
+        self.farm_id
+    }
+
+    pub fn set_farm_id(&mut self, farm_id: i64) {
+        self.farm_id = farm_id;
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn set_name(&mut self, name: String) {
+        self.name = name;
+    }
+
+    pub fn boundary(&self) -> &PlotBoundary {
+        &self.boundary
+    }
+
+    /// Area enclosed by the boundary, in hectares.
+    pub fn area(&self) -> f64 {
+        self.area
+    }
+}
@@ -0,0 +1,64 @@
+use validator::{Validate, ValidationErrors};
+
+use crate::misc::gs1::{gtin_validation, normalize_gtin};
+
+/// A trade item identified by a GTIN. Batches of it are the GS1 lots.
+#[derive(Debug, Clone, Validate)]
+pub struct Product {
+    id: Option<i64>,
+    #[validate(custom(function = "gtin_validation"))]
+    gtin: String,
+    #[validate(length(min = 1, max = 255))]
+    name: String,
+    #[validate(length(max = 255))]
+    description: Option<String>,
+}
+
+#[allow(dead_code)]
+impl Product {
+    /// Stores the GTIN in its 14 digit form.
+    pub fn new(
+        id: Option<i64>,
+        gtin: String,
+        name: String,
+        description: Option<String>,
+    ) -> Result<Self, ValidationErrors> {
+        let mut product = Self {
+            id,
+            gtin,
+            name,
+            description,
+        };
+        product.validate()?;
+        product.gtin = normalize_gtin(&product.gtin);
+        Ok(product)
+    }
+
+    pub fn id(&self) -> &Option<i64> {
+        &self.id
+    }
+
+    pub fn set_id(&mut self, id: Option<i64>) {
+        self.id = id;
+    }
+
+    pub fn gtin(&self) -> &str {
+        &self.gtin
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn set_name(&mut self, name: String) {
+        self.name = name;
+    }
+
+    pub fn description(&self) -> &Option<String> {
+        &self.description
+    }
+
+    pub fn set_description(&mut self, description: Option<String>) {
+        self.description = description;
+    }
+}
@@ -0,0 +1,126 @@
+use chrono::{NaiveDate, NaiveDateTime};
+use serde::{Deserialize, Serialize};
+use validator::{Validate, ValidationErrors};
+
+use super::{Batch, Customer, Shipment};
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, sqlx::Type)]
+#[serde(rename_all = "snake_case")]
+#[sqlx(rename_all = "snake_case")]
+pub enum RecallStatus {
+    Open,
+    InProgress,
+    Closed,
+}
+
+/// A recall started from a crop, a batch or a cultivation packed within a
+/// period. `batch_ids` holds every batch found affected when it was opened,
+/// derived batches included.
+#[derive(Debug, Clone, Validate)]
+pub struct Recall {
+    id: Option<i64>,
+    crop_id: Option<i64>,
+    batch_id: Option<i64>,
+    #[validate(length(min = 1, max = 255))]
+    cultivation: Option<String>,
+    start_date: Option<NaiveDate>,
+    end_date: Option<NaiveDate>,
+    #[validate(length(min = 1))]
+    reason: String,
+    status: RecallStatus,
+    created_at: NaiveDateTime,
+    batch_ids: Vec<i64>,
+}
+
+#[allow(dead_code)]
+impl Recall {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        id: Option<i64>,
+        crop_id: Option<i64>,
+        batch_id: Option<i64>,
+        cultivation: Option<String>,
+        start_date: Option<NaiveDate>,
+        end_date: Option<NaiveDate>,
+        reason: String,
+        status: RecallStatus,
+        created_at: NaiveDateTime,
+    ) -> Result<Self, ValidationErrors> {
+        let recall = Self {
+            id,
+            crop_id,
+            batch_id,
+            cultivation,
+            start_date,
+            end_date,
+            reason,
+            status,
+            created_at,
+            batch_ids: vec![],
+        };
+        recall.validate()?;
+        Ok(recall)
+    }
+
+    pub fn id(&self) -> &Option<i64> {
+        &self.id
+    }
+
+    pub fn set_id(&mut self, id: Option<i64>) {
+        self.id = id;
+    }
+
+    pub fn crop_id(&self) -> Option<i64> {
+        self.crop_id
+    }
+
+    pub fn batch_id(&self) -> Option<i64> {
+        self.batch_id
+    }
+
+    pub fn cultivation(&self) -> &Option<String> {
+        &self.cultivation
+    }
+
+    pub fn start_date(&self) -> Option<NaiveDate> {
+        self.start_date
+    }
+
+    pub fn end_date(&self) -> Option<NaiveDate> {
+        self.end_date
+    }
+
+    pub fn reason(&self) -> &str {
+        &self.reason
+    }
+
+    pub fn status(&self) -> RecallStatus {
+        self.status
+    }
+
+    pub fn set_status(&mut self, status: RecallStatus) {
+        self.status = status;
+    }
+
+    pub fn created_at(&self) -> NaiveDateTime {
+        self.created_at
+    }
+
+    pub fn batch_ids(&self) -> &[i64] {
+        &self.batch_ids
+    }
+
+    pub fn set_batch_ids(&mut self, batch_ids: Vec<i64>) {
+        self.batch_ids = batch_ids;
+    }
+}
+
+/// Everything reached by a recall: the affected batches, the shipments that
+/// took any of them and the customers that received those shipments.
+#[derive(Debug, Clone)]
+pub struct RecallReport {
+    pub recall: Recall,
+    pub batches: Vec<Batch>,
+    pub shipments: Vec<Shipment>,
+    pub customers: Vec<Customer>,
+}
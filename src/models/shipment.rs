@@ -0,0 +1,123 @@
+use chrono::NaiveDate;
+use serde::Serialize;
+use validator::{Validate, ValidationErrors};
+
+use crate::misc::date_validation::past_or_present_validation;
+
+#[derive(Debug, Clone, Serialize, Validate)]
+pub struct ShipmentItem {
+    id: Option<i64>,
+    batch_id: i64,
+    #[validate(range(exclusive_min = 0.0))]
+    quantity: f64,
+}
+
+#[allow(dead_code)]
+impl ShipmentItem {
+    pub fn new(id: Option<i64>, batch_id: i64, quantity: f64) -> Result<Self, ValidationErrors> {
+        let item = Self {
+            id,
+            batch_id,
+            quantity,
+        };
+        item.validate()?;
+        Ok(item)
+    }
+
+    pub fn id(&self) -> &Option<i64> {
+        &self.id
+    }
+
+    pub fn set_id(&mut self, id: Option<i64>) {
+        self.id = id;
+    }
+
+    pub fn batch_id(&self) -> i64 {
+        self.batch_id
+    }
+
+    pub fn set_batch_id(&mut self, batch_id: i64) {
+        self.batch_id = batch_id;
+    }
+
+    pub fn quantity(&self) -> f64 {
+        self.quantity
+    }
+
+    pub fn set_quantity(&mut self, quantity: f64) {
+        self.quantity = quantity;
+    }
+}
+
+#[derive(Debug, Clone, Validate)]
+pub struct Shipment {
+    id: Option<i64>,
+    customer_id: i64,
+    #[validate(custom(function = "past_or_present_validation"))]
+    dispatched_at: NaiveDate,
+    #[validate(length(min = 1, max = 64))]
+    invoice_number: String,
+    #[validate(length(min = 1), nested)]
+    items: Vec<ShipmentItem>,
+}
+
+#[allow(dead_code)]
+impl Shipment {
+    pub fn new(
+        id: Option<i64>,
+        customer_id: i64,
+        dispatched_at: NaiveDate,
+        invoice_number: String,
+        items: Vec<ShipmentItem>,
+    ) -> Result<Self, ValidationErrors> {
+        let shipment = Self {
+            id,
+            customer_id,
+            dispatched_at,
+            invoice_number,
+            items,
+        };
+        shipment.validate()?;
+        Ok(shipment)
+    }
+
+    pub fn id(&self) -> &Option<i64> {
+        &self.id
+    }
+
+    pub fn set_id(&mut self, id: Option<i64>) {
+        self.id = id;
+    }
+
+    pub fn customer_id(&self) -> i64 {
+        self.customer_id
+    }
+
+    pub fn set_customer_id(&mut self, customer_id: i64) {
+        self.customer_id = customer_id;
+    }
+
+    pub fn dispatched_at(&self) -> NaiveDate {
+        self.dispatched_at
+    }
+
+    pub fn set_dispatched_at(&mut self, dispatched_at: NaiveDate) {
+        self.dispatched_at = dispatched_at;
+    }
+
+    pub fn invoice_number(&self) -> &str {
+        &self.invoice_number
+    }
+
+    pub fn set_invoice_number(&mut self, invoice_number: String) {
+        self.invoice_number = invoice_number;
+    }
+
+    pub fn items(&self) -> &[ShipmentItem] {
+        &self.items
+    }
+
+    pub fn set_items(&mut self, items: Vec<ShipmentItem>) {
+        self.items = items;
+    }
+}
@@ -0,0 +1,129 @@
+use serde::{Deserialize, Serialize};
+
+/// Unit of a batch quantity. `Kg` and `T` are masses; the others count
+/// packages and are only converted to mass through the packing's weight.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize, sqlx::Type)]
+#[serde(rename_all = "snake_case")]
+#[sqlx(rename_all = "snake_case")]
+pub enum QuantityUnit {
+    #[default]
+    Kg,
+    T,
+    Box,
+    Crate,
+    Unit,
+}
+
+impl QuantityUnit {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Kg => "kg",
+            Self::T => "t",
+            Self::Box => "box",
+            Self::Crate => "crate",
+            Self::Unit => "unit",
+        }
+    }
+
+    /// Whether the unit counts packages rather than mass.
+    pub fn is_package(&self) -> bool {
+        self.kg_factor().is_none()
+    }
+
+    /// Kilograms in one of this unit, when it is a mass unit.
+    fn kg_factor(&self) -> Option<f64> {
+        match self {
+            Self::Kg => Some(1.0),
+            Self::T => Some(1000.0),
+            Self::Box | Self::Crate | Self::Unit => None,
+        }
+    }
+
+    /// Converts `value` from this unit to `to`. Package units go through
+    /// `kg_per_package`, so converting between two different package units
+    /// or without a packing weight yields `None`.
+    pub fn convert(
+        &self,
+        value: f64,
+        to: QuantityUnit,
+        kg_per_package: Option<f64>,
+    ) -> Option<f64> {
+        if *self == to {
+            return Some(value);
+        }
+
+        let kg = match self.kg_factor() {
+            Some(factor) => value * factor,
+            None if to.kg_factor().is_some() => value * kg_per_package?,
+            None => return None,
+        };
+
+        match to.kg_factor() {
+            Some(factor) => Some(kg / factor),
+            None => Some(kg / kg_per_package?),
+        }
+    }
+}
+
+/// Unit of a crop area.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize, sqlx::Type)]
+#[serde(rename_all = "snake_case")]
+#[sqlx(rename_all = "snake_case")]
+pub enum AreaUnit {
+    #[default]
+    Ha,
+    M2,
+    /// Alqueire paulista, 2.42 ha.
+    Alqueire,
+}
+
+impl AreaUnit {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Ha => "ha",
+            Self::M2 => "m2",
+            Self::Alqueire => "alqueire",
+        }
+    }
+
+    fn hectare_factor(&self) -> f64 {
+        match self {
+            Self::Ha => 1.0,
+            Self::M2 => 0.0001,
+            Self::Alqueire => 2.42,
+        }
+    }
+
+    pub fn convert(&self, value: f64, to: AreaUnit) -> f64 {
+        if *self == to {
+            return value;
+        }
+        value * self.hectare_factor() / to.hectare_factor()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn package_units_must_convert_through_packing_weight() {
+        assert_eq!(
+            QuantityUnit::Box.convert(10.0, QuantityUnit::Kg, Some(20.0)),
+            Some(200.0)
+        );
+        assert_eq!(
+            QuantityUnit::T.convert(0.2, QuantityUnit::Box, Some(20.0)),
+            Some(10.0)
+        );
+        assert_eq!(
+            QuantityUnit::Box.convert(10.0, QuantityUnit::Kg, None),
+            None
+        );
+        assert_eq!(
+            QuantityUnit::Box.convert(10.0, QuantityUnit::Crate, Some(20.0)),
+            None
+        );
+        assert_eq!(AreaUnit::Alqueire.convert(2.0, AreaUnit::Ha), 4.84);
+    }
+}
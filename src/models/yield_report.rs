@@ -0,0 +1,249 @@
+use std::collections::BTreeMap;
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+use super::batch::Batch;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum YieldGroupBy {
+    #[default]
+    Crop,
+    Cultivation,
+    Month,
+}
+
+/// Produced quantity (kg) and productivity (kg/ha) of one group. Months are
+/// split per cultivation, since adding up yields of different cultivations
+/// says nothing.
+#[derive(Debug, Clone, PartialEq)]
+pub struct YieldRow {
+    pub period: Option<String>,
+    pub crop_id: Option<i64>,
+    pub crop: Option<String>,
+    pub cultivation: String,
+    pub crops: usize,
+    pub batches: usize,
+    pub quantity: f64,
+    pub area: f64,
+    pub productivity: Option<f64>,
+    /// Productivity of the crop's whole cultivation in the period, to compare
+    /// crops against. Only filled when grouping by crop.
+    pub cultivation_productivity: Option<f64>,
+    /// Batches left out of the totals because their quantity could not be
+    /// converted to kilograms.
+    pub unconverted: usize,
+}
+
+#[derive(Debug, Clone)]
+pub struct YieldReport {
+    pub from: Option<NaiveDate>,
+    pub to: Option<NaiveDate>,
+    pub group_by: YieldGroupBy,
+    pub rows: Vec<YieldRow>,
+}
+
+#[derive(Default)]
+struct Totals<'a> {
+    first: Option<&'a Batch>,
+    crops: BTreeMap<i64, f64>,
+    batches: usize,
+    quantity: f64,
+    unconverted: usize,
+}
+
+impl Totals<'_> {
+    fn area(&self) -> f64 {
+        self.crops.values().sum()
+    }
+
+    fn productivity(&self) -> Option<f64> {
+        let area = self.area();
+        (area > 0.0).then(|| self.quantity / area)
+    }
+}
+
+fn totals<'a, K: Ord>(
+    batches: &'a [Batch],
+    unconverted: &'a [Batch],
+    key: impl Fn(&Batch) -> K,
+) -> BTreeMap<K, Totals<'a>> {
+    let mut groups: BTreeMap<K, Totals> = BTreeMap::new();
+    for batch in batches {
+        let totals = groups.entry(key(batch)).or_default();
+        totals.first.get_or_insert(batch);
+        totals
+            .crops
+            .insert(batch.crop().id().unwrap(), batch.crop().area());
+        totals.batches += 1;
+        totals.quantity += batch.quantity();
+    }
+    for batch in unconverted {
+        let totals = groups.entry(key(batch)).or_default();
+        totals.first.get_or_insert(batch);
+        totals.unconverted += 1;
+    }
+    groups
+}
+
+impl YieldReport {
+    /// Aggregates `batches`, whose quantities must already be in kilograms
+    /// and crop areas in hectares. A crop's area counts once per group no
+    /// matter how many of its batches fall in it. The `unconverted` batches
+    /// are only counted, so their crops' areas don't lower productivity.
+    pub fn new(
+        from: Option<NaiveDate>,
+        to: Option<NaiveDate>,
+        group_by: YieldGroupBy,
+        batches: &[Batch],
+        unconverted: &[Batch],
+    ) -> Self {
+        let cultivations = totals(batches, unconverted, |batch| {
+            batch.crop().cultivation().name().to_string()
+        });
+
+        let rows = match group_by {
+            YieldGroupBy::Crop => {
+                let mut rows: Vec<YieldRow> =
+                    totals(batches, unconverted, |batch| batch.crop().id().unwrap())
+                        .into_iter()
+                        .map(|(crop_id, totals)| {
+                            let crop = totals.first.unwrap().crop();
+                            YieldRow {
+                                period: None,
+                                crop_id: Some(crop_id),
+                                crop: Some(crop.name().to_string()),
+                                cultivation: crop.cultivation().name().to_string(),
+                                crops: 1,
+                                batches: totals.batches,
+                                quantity: totals.quantity,
+                                area: totals.area(),
+                                productivity: totals.productivity(),
+                                cultivation_productivity: cultivations[crop.cultivation().name()]
+                                    .productivity(),
+                                unconverted: totals.unconverted,
+                            }
+                        })
+                        .collect();
+                rows.sort_by(|a, b| {
+                    a.cultivation.cmp(&b.cultivation).then(
+                        b.productivity
+                            .unwrap_or_default()
+                            .total_cmp(&a.productivity.unwrap_or_default()),
+                    )
+                });
+                rows
+            }
+            YieldGroupBy::Cultivation => cultivations
+                .into_iter()
+                .map(|(cultivation, totals)| YieldRow {
+                    period: None,
+                    crop_id: None,
+                    crop: None,
+                    cultivation,
+                    crops: totals.crops.len(),
+                    batches: totals.batches,
+                    quantity: totals.quantity,
+                    area: totals.area(),
+                    productivity: totals.productivity(),
+                    cultivation_productivity: None,
+                    unconverted: totals.unconverted,
+                })
+                .collect(),
+            YieldGroupBy::Month => totals(batches, unconverted, |batch| {
+                (
+                    batch.date().format("%Y-%m").to_string(),
+                    batch.crop().cultivation().name().to_string(),
+                )
+            })
+            .into_iter()
+            .map(|((period, cultivation), totals)| YieldRow {
+                period: Some(period),
+                crop_id: None,
+                crop: None,
+                cultivation,
+                crops: totals.crops.len(),
+                batches: totals.batches,
+                quantity: totals.quantity,
+                area: totals.area(),
+                productivity: totals.productivity(),
+                cultivation_productivity: None,
+                unconverted: totals.unconverted,
+            })
+            .collect(),
+        };
+
+        Self {
+            from,
+            to,
+            group_by,
+            rows,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{AreaUnit, Crop, Cultivation, QuantityUnit};
+
+    fn batch(crop: &Crop, quantity: f64, date: NaiveDate) -> Batch {
+        Batch::new(
+            Some(1),
+            crop.clone(),
+            None,
+            None,
+            "Caixa".to_string(),
+            quantity,
+            QuantityUnit::Kg,
+            None,
+            date,
+            None,
+            None,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn crops_are_compared_with_their_cultivation() {
+        let planted_at = NaiveDate::from_ymd_opt(2024, 1, 10).unwrap();
+        let crop = |id: i64, area: f64| {
+            Crop::new(
+                Some(id),
+                format!("Talhão {id}"),
+                area,
+                AreaUnit::Ha,
+                Cultivation::new(Some(1), "Tomate".to_string(), None, None, None, None).unwrap(),
+                planted_at,
+                None,
+                None,
+            )
+            .unwrap()
+        };
+        let (first, second) = (crop(1, 2.0), crop(2, 1.0));
+        let april = NaiveDate::from_ymd_opt(2024, 4, 2).unwrap();
+        let may = NaiveDate::from_ymd_opt(2024, 5, 2).unwrap();
+        let batches = vec![
+            batch(&first, 1000.0, april),
+            batch(&first, 1000.0, may),
+            batch(&second, 3000.0, april),
+        ];
+
+        let report = YieldReport::new(None, None, YieldGroupBy::Crop, &batches, &[]);
+
+        assert_eq!(report.rows.len(), 2);
+        assert_eq!(report.rows[0].crop_id, Some(2));
+        assert_eq!(report.rows[0].productivity, Some(3000.0));
+        assert_eq!(report.rows[1].batches, 2);
+        assert_eq!(report.rows[1].area, 2.0);
+        assert_eq!(report.rows[1].productivity, Some(1000.0));
+        assert_eq!(report.rows[1].cultivation_productivity, Some(5000.0 / 3.0));
+
+        let report = YieldReport::new(None, None, YieldGroupBy::Month, &batches, &[]);
+
+        assert_eq!(report.rows[0].period.as_deref(), Some("2024-04"));
+        assert_eq!(report.rows[0].quantity, 4000.0);
+        assert_eq!(report.rows[0].area, 3.0);
+    }
+}
@@ -0,0 +1,106 @@
+use sqlx::{query, query_as, SqliteConnection, SqlitePool};
+
+use crate::{
+    errors::AppError,
+    models::{AuditAction, AuditEntity, AuditEntry},
+};
+
+#[derive(Debug)]
+pub struct AuditEntryDb {
+    id: i64,
+    entity: AuditEntity,
+    entity_id: i64,
+    action: AuditAction,
+    actor: Option<String>,
+    changed_at: chrono::NaiveDateTime,
+    before: Option<String>,
+    after: Option<String>,
+}
+
+impl From<AuditEntryDb> for AuditEntry {
+    fn from(entry: AuditEntryDb) -> Self {
+        let parse =
+            |value: Option<String>| value.and_then(|value| serde_json::from_str(&value).ok());
+        AuditEntry::new(
+            Some(entry.id),
+            entry.entity,
+            entry.entity_id,
+            entry.action,
+            entry.actor,
+            entry.changed_at,
+            parse(entry.before),
+            parse(entry.after),
+        )
+        .unwrap()
+    }
+}
+
+pub struct AuditRepository {
+    pool: Box<SqlitePool>,
+}
+
+impl AuditRepository {
+    pub fn new(pool: Box<SqlitePool>) -> Self {
+        Self { pool }
+    }
+
+    /// Entries of `entity` (and of record `entity_id` of it, when given),
+    /// oldest first.
+    pub async fn list(
+        &self,
+        entity: Option<AuditEntity>,
+        entity_id: Option<i64>,
+    ) -> Result<Vec<AuditEntry>, AppError> {
+        let entries = query_as!(
+            AuditEntryDb,
+            r#"
+            SELECT id, entity as "entity: AuditEntity", entity_id, action as "action: AuditAction", actor, changed_at, before, after
+            FROM audit_log
+            WHERE (?1 IS NULL OR entity = ?1) AND (?2 IS NULL OR entity_id = ?2)
+            ORDER BY changed_at, id
+            "#,
+            entity,
+            entity_id
+        )
+        .fetch_all(&*self.pool)
+        .await?;
+
+        Ok(entries.into_iter().map(AuditEntry::from).collect())
+    }
+
+    /// Runs on `connection`, the transaction of the change being recorded.
+    pub async fn insert(
+        &self,
+        connection: &mut SqliteConnection,
+        mut entry: AuditEntry,
+    ) -> Result<AuditEntry, AppError> {
+        let entity = entry.entity();
+        let entity_id = entry.entity_id();
+        let action = entry.action();
+        let actor = entry.actor().clone();
+        let changed_at = entry.changed_at();
+        let before = entry.before().as_ref().map(|value| value.to_string());
+        let after = entry.after().as_ref().map(|value| value.to_string());
+
+        let entry_id = query!(
+            r#"
+            INSERT INTO audit_log (entity, entity_id, action, actor, changed_at, before, after)
+            VALUES (?, ?, ?, ?, ?, ?, ?)
+            RETURNING id
+            "#,
+            entity,
+            entity_id,
+            action,
+            actor,
+            changed_at,
+            before,
+            after
+        )
+        .fetch_one(connection)
+        .await?;
+
+        entry.set_id(Some(entry_id.id));
+
+        Ok(entry)
+    }
+}
@@ -0,0 +1,107 @@
+use sqlx::{query, query_as, SqlitePool};
+
+use crate::{
+    errors::AppError,
+    models::{BatchEvent, BatchEventType},
+};
+
+#[derive(Debug)]
+pub struct BatchEventDb {
+    id: i64,
+    batch_id: i64,
+    event_type: BatchEventType,
+    occurred_at: chrono::NaiveDateTime,
+    operator: Option<String>,
+    notes: Option<String>,
+}
+
+impl From<BatchEventDb> for BatchEvent {
+    fn from(event: BatchEventDb) -> Self {
+        BatchEvent::new(
+            Some(event.id),
+            event.batch_id,
+            event.event_type,
+            event.occurred_at,
+            event.operator,
+            event.notes,
+        )
+        .unwrap()
+    }
+}
+
+pub struct BatchEventRepository {
+    pool: Box<SqlitePool>,
+}
+
+impl BatchEventRepository {
+    pub fn new(pool: Box<SqlitePool>) -> Self {
+        Self { pool }
+    }
+
+    /// Events that occurred between `from` and `to`, both inclusive.
+    pub async fn list(
+        &self,
+        from: Option<chrono::NaiveDate>,
+        to: Option<chrono::NaiveDate>,
+    ) -> Result<Vec<BatchEvent>, AppError> {
+        let events = query_as!(
+            BatchEventDb,
+            r#"
+            SELECT id, batch_id, event_type as "event_type: BatchEventType", occurred_at, operator, notes
+            FROM batch_events
+            WHERE (?1 IS NULL OR DATE(occurred_at) >= ?1) AND (?2 IS NULL OR DATE(occurred_at) <= ?2)
+            ORDER BY occurred_at, id
+            "#,
+            from,
+            to
+        )
+        .fetch_all(&*self.pool)
+        .await?;
+
+        Ok(events.into_iter().map(BatchEvent::from).collect())
+    }
+
+    pub async fn list_by_batch_id(&self, batch_id: i64) -> Result<Vec<BatchEvent>, AppError> {
+        let events = query_as!(
+            BatchEventDb,
+            r#"
+            SELECT id, batch_id, event_type as "event_type: BatchEventType", occurred_at, operator, notes
+            FROM batch_events
+            WHERE batch_id = ?
+            ORDER BY occurred_at, id
+            "#,
+            batch_id
+        )
+        .fetch_all(&*self.pool)
+        .await?;
+
+        Ok(events.into_iter().map(BatchEvent::from).collect())
+    }
+
+    pub async fn insert(&self, mut event: BatchEvent) -> Result<BatchEvent, AppError> {
+        let batch_id = event.batch_id();
+        let event_type = event.event_type();
+        let occurred_at = event.occurred_at();
+        let operator = event.operator().clone();
+        let notes = event.notes().clone();
+
+        let event_id = query!(
+            r#"
+            INSERT INTO batch_events (batch_id, event_type, occurred_at, operator, notes)
+            VALUES (?, ?, ?, ?, ?)
+            RETURNING id
+            "#,
+            batch_id,
+            event_type,
+            occurred_at,
+            operator,
+            notes,
+        )
+        .fetch_one(&*self.pool)
+        .await?;
+
+        event.set_id(Some(event_id.id));
+
+        Ok(event)
+    }
+}
@@ -1,10 +1,86 @@
-use sqlx::{query, SqlitePool};
+use async_stream::try_stream;
+use futures::{stream::BoxStream, TryStreamExt};
+use sqlx::{query, query_as, Sqlite, SqliteConnection, SqlitePool, Transaction};
 
 use crate::{
     errors::AppError,
-    models::{Batch, Crop},
+    models::{AreaUnit, Batch, Crop, Cultivation, LineageDirection, LineageLink, QuantityUnit},
 };
 
+#[derive(Debug)]
+pub struct BatchDb {
+    id: i64,
+    crop_id: i64,
+    classification: Option<String>,
+    processing: Option<String>,
+    packing: String,
+    quantity: f64,
+    quantity_unit: QuantityUnit,
+    tracking_code: String,
+    date: chrono::NaiveDate,
+    harvest_id: Option<i64>,
+    product_id: Option<i64>,
+    crop_name: String,
+    crop_area: f64,
+    crop_area_unit: AreaUnit,
+    crop_cultivation_id: i64,
+    crop_cultivation: String,
+    crop_scientific_name: Option<String>,
+    crop_variety: Option<String>,
+    crop_cycle_days: Option<i64>,
+    crop_shelf_life_days: Option<i64>,
+    crop_planted_at: chrono::NaiveDate,
+    crop_closed_at: Option<chrono::NaiveDate>,
+    crop_plot_id: Option<i64>,
+    shipped_quantity: f64,
+    deleted_at: Option<chrono::NaiveDateTime>,
+    version: i64,
+}
+
+impl From<BatchDb> for Batch {
+    fn from(batch: BatchDb) -> Self {
+        let cultivation = Cultivation::new(
+            Some(batch.crop_cultivation_id),
+            batch.crop_cultivation,
+            batch.crop_scientific_name,
+            batch.crop_variety,
+            batch.crop_cycle_days,
+            batch.crop_shelf_life_days,
+        )
+        .unwrap();
+        let crop = Crop::new(
+            Some(batch.crop_id),
+            batch.crop_name,
+            batch.crop_area,
+            batch.crop_area_unit,
+            cultivation,
+            batch.crop_planted_at,
+            batch.crop_closed_at,
+            batch.crop_plot_id,
+        )
+        .unwrap();
+
+        let mut result = Batch::new(
+            Some(batch.id),
+            crop,
+            batch.classification,
+            batch.processing,
+            batch.packing,
+            batch.quantity,
+            batch.quantity_unit,
+            Some(batch.tracking_code),
+            batch.date,
+            batch.harvest_id,
+            batch.product_id,
+        )
+        .unwrap();
+        result.set_shipped_quantity(batch.shipped_quantity);
+        result.set_deleted_at(batch.deleted_at);
+        result.set_version(batch.version);
+        result
+    }
+}
+
 pub struct BatchRepository {
     pool: Box<SqlitePool>,
 }
@@ -14,51 +90,145 @@ impl BatchRepository {
         Self { pool }
     }
 
-    pub async fn list(&self) -> Result<Vec<Batch>, AppError> {
-        let batches = query!(
+    /// Error for a change based on a version of the batch other than the
+    /// current one.
+    pub fn stale(id: i64) -> AppError {
+        AppError::PreconditionFailed(format!(
+            "O lote de ID {id} foi alterado por outra pessoa. Recarregue-o e tente novamente."
+        ))
+    }
+
+    /// Error for a batch quantity below what was already shipped from it.
+    pub fn over_shipped(quantity: f64, shipped_quantity: f64) -> AppError {
+        AppError::BadRequest(format!(
+            "A quantidade do lote ({quantity}) não pode ser menor que a quantidade já expedida ({shipped_quantity})"
+        ))
+    }
+
+    /// Error for deleting a batch something was shipped from.
+    pub fn shipped(id: i64) -> AppError {
+        AppError::BadRequest(format!(
+            "O lote de ID {id} possui remessas, e portanto não pode ser excluído."
+        ))
+    }
+
+    /// Error for restoring a batch that isn't deleted.
+    pub fn not_deleted(id: i64) -> AppError {
+        AppError::BadRequest(format!("O lote de ID {id} não está excluído."))
+    }
+
+    /// Starts a transaction for the changes below, which take the connection
+    /// to run on so their audit entries are written along with them.
+    pub async fn begin(&self) -> Result<Transaction<'static, Sqlite>, AppError> {
+        Ok(self.pool.begin().await?)
+    }
+
+    #[cfg(test)]
+    pub fn pool(&self) -> Box<SqlitePool> {
+        self.pool.clone()
+    }
+
+    /// Batches not deleted, or every batch when `include_deleted` is set.
+    pub async fn list(&self, include_deleted: bool) -> Result<Vec<Batch>, AppError> {
+        let batches = query_as!(
+            BatchDb,
             r#"
-            SELECT b.id, b.crop_id, b.classification, b.processing, b.packing, b.quantity, b.tracking_code, b.date, c.name as crop_name, c.area as crop_area, c.cultivation as crop_cultivation, c.planted_at as crop_planted_at, c.harvested_at as crop_harvested_at
+            SELECT b.id, b.crop_id, b.classification, b.processing, b.packing, b.quantity, b.quantity_unit as "quantity_unit: QuantityUnit", b.tracking_code, b.date, b.harvest_id, b.product_id, b.deleted_at, b.version, c.name as crop_name, c.area as crop_area, c.area_unit as "crop_area_unit: AreaUnit", v.id as crop_cultivation_id, v.name as crop_cultivation, v.scientific_name as crop_scientific_name, v.variety as crop_variety, v.cycle_days as crop_cycle_days, v.shelf_life_days as crop_shelf_life_days, c.planted_at as crop_planted_at, c.closed_at as crop_closed_at, c.plot_id as crop_plot_id,
+                (SELECT COALESCE(SUM(s.quantity), 0) FROM shipment_items s WHERE s.batch_id = b.id) as "shipped_quantity!: f64"
             FROM batches b
-            INNER JOIN crops c ON b.crop_id = c.id;
+            INNER JOIN crops c ON b.crop_id = c.id
+            INNER JOIN cultivations v ON v.id = c.cultivation_id
+            WHERE ? OR b.deleted_at IS NULL;
             "#,
+            include_deleted
         )
         .fetch_all(&*self.pool)
         .await?;
 
-        let batches = batches
-            .iter()
-            .map(|batch| {
-                Batch::new(
-                    Some(batch.id),
-                    Crop::new(
-                        Some(batch.crop_id),
-                        batch.crop_name.clone(),
-                        batch.crop_area,
-                        batch.crop_cultivation.clone(),
-                        batch.crop_planted_at,
-                        batch.crop_harvested_at,
-                    )
-                    .unwrap(),
-                    batch.classification.clone(),
-                    batch.processing.clone(),
-                    batch.packing.clone(),
-                    batch.quantity,
-                    Some(batch.tracking_code.clone()),
-                    batch.date,
-                )
-                .unwrap()
-            })
-            .collect();
+        Ok(batches.into_iter().map(Batch::from).collect())
+    }
 
-        Ok(batches)
+    /// Every batch, deleted ones included, dated between `from` and `to`, both
+    /// inclusive.
+    pub async fn list_dated(
+        &self,
+        from: Option<chrono::NaiveDate>,
+        to: Option<chrono::NaiveDate>,
+    ) -> Result<Vec<Batch>, AppError> {
+        let batches = query_as!(
+            BatchDb,
+            r#"
+            SELECT b.id, b.crop_id, b.classification, b.processing, b.packing, b.quantity, b.quantity_unit as "quantity_unit: QuantityUnit", b.tracking_code, b.date, b.harvest_id, b.product_id, b.deleted_at, b.version, c.name as crop_name, c.area as crop_area, c.area_unit as "crop_area_unit: AreaUnit", v.id as crop_cultivation_id, v.name as crop_cultivation, v.scientific_name as crop_scientific_name, v.variety as crop_variety, v.cycle_days as crop_cycle_days, v.shelf_life_days as crop_shelf_life_days, c.planted_at as crop_planted_at, c.closed_at as crop_closed_at, c.plot_id as crop_plot_id,
+                (SELECT COALESCE(SUM(s.quantity), 0) FROM shipment_items s WHERE s.batch_id = b.id) as "shipped_quantity!: f64"
+            FROM batches b
+            INNER JOIN crops c ON b.crop_id = c.id
+            INNER JOIN cultivations v ON v.id = c.cultivation_id
+            WHERE (?1 IS NULL OR b.date >= ?1) AND (?2 IS NULL OR b.date <= ?2)
+            ORDER BY b.id;
+            "#,
+            from,
+            to
+        )
+        .fetch_all(&*self.pool)
+        .await?;
+
+        Ok(batches.into_iter().map(Batch::from).collect())
+    }
+
+    /// Batches with the given ids, deleted ones included.
+    pub async fn find_by_ids(&self, ids: &[i64]) -> Result<Vec<Batch>, AppError> {
+        let ids = serde_json::to_string(ids).unwrap();
+        let batches = query_as!(
+            BatchDb,
+            r#"
+            SELECT b.id, b.crop_id, b.classification, b.processing, b.packing, b.quantity, b.quantity_unit as "quantity_unit: QuantityUnit", b.tracking_code, b.date, b.harvest_id, b.product_id, b.deleted_at, b.version, c.name as crop_name, c.area as crop_area, c.area_unit as "crop_area_unit: AreaUnit", v.id as crop_cultivation_id, v.name as crop_cultivation, v.scientific_name as crop_scientific_name, v.variety as crop_variety, v.cycle_days as crop_cycle_days, v.shelf_life_days as crop_shelf_life_days, c.planted_at as crop_planted_at, c.closed_at as crop_closed_at, c.plot_id as crop_plot_id,
+                (SELECT COALESCE(SUM(s.quantity), 0) FROM shipment_items s WHERE s.batch_id = b.id) as "shipped_quantity!: f64"
+            FROM batches b
+            INNER JOIN crops c ON b.crop_id = c.id
+            INNER JOIN cultivations v ON v.id = c.cultivation_id
+            WHERE b.id IN (SELECT value FROM json_each(?))
+            ORDER BY b.id;
+            "#,
+            ids
+        )
+        .fetch_all(&*self.pool)
+        .await?;
+
+        Ok(batches.into_iter().map(Batch::from).collect())
+    }
+
+    /// Same rows as [`BatchRepository::list`], fetched as they are read.
+    pub fn stream(&self, include_deleted: bool) -> BoxStream<'_, Result<Batch, AppError>> {
+        Box::pin(try_stream! {
+            let mut rows = query_as!(
+                BatchDb,
+                r#"
+                SELECT b.id, b.crop_id, b.classification, b.processing, b.packing, b.quantity, b.quantity_unit as "quantity_unit: QuantityUnit", b.tracking_code, b.date, b.harvest_id, b.product_id, b.deleted_at, b.version, c.name as crop_name, c.area as crop_area, c.area_unit as "crop_area_unit: AreaUnit", v.id as crop_cultivation_id, v.name as crop_cultivation, v.scientific_name as crop_scientific_name, v.variety as crop_variety, v.cycle_days as crop_cycle_days, v.shelf_life_days as crop_shelf_life_days, c.planted_at as crop_planted_at, c.closed_at as crop_closed_at, c.plot_id as crop_plot_id,
+                    (SELECT COALESCE(SUM(s.quantity), 0) FROM shipment_items s WHERE s.batch_id = b.id) as "shipped_quantity!: f64"
+                FROM batches b
+                INNER JOIN crops c ON b.crop_id = c.id
+                INNER JOIN cultivations v ON v.id = c.cultivation_id
+                WHERE ? OR b.deleted_at IS NULL
+                ORDER BY b.id;
+                "#,
+                include_deleted
+            )
+            .fetch(&*self.pool);
+            while let Some(row) = rows.try_next().await? {
+                yield Batch::from(row);
+            }
+        })
     }
 
     pub async fn find_by_id(&self, id: i64) -> Result<Batch, AppError> {
-        let batch = query!(
+        let batch = query_as!(
+            BatchDb,
             r#"
-            SELECT b.id, b.crop_id, b.classification, b.processing, b.packing, b.quantity, b.tracking_code, b.date, c.name as crop_name, c.area as crop_area, c.cultivation as crop_cultivation, c.planted_at as crop_planted_at, c.harvested_at as crop_harvested_at
+            SELECT b.id, b.crop_id, b.classification, b.processing, b.packing, b.quantity, b.quantity_unit as "quantity_unit: QuantityUnit", b.tracking_code, b.date, b.harvest_id, b.product_id, b.deleted_at, b.version, c.name as crop_name, c.area as crop_area, c.area_unit as "crop_area_unit: AreaUnit", v.id as crop_cultivation_id, v.name as crop_cultivation, v.scientific_name as crop_scientific_name, v.variety as crop_variety, v.cycle_days as crop_cycle_days, v.shelf_life_days as crop_shelf_life_days, c.planted_at as crop_planted_at, c.closed_at as crop_closed_at, c.plot_id as crop_plot_id,
+                (SELECT COALESCE(SUM(s.quantity), 0) FROM shipment_items s WHERE s.batch_id = b.id) as "shipped_quantity!: f64"
             FROM batches b
             INNER JOIN crops c ON b.crop_id = c.id
+            INNER JOIN cultivations v ON v.id = c.cultivation_id
             WHERE b.id = ?;
             "#,
             id
@@ -66,25 +236,7 @@ impl BatchRepository {
         .fetch_one(&*self.pool)
         .await?;
 
-        let crop = Crop::new(
-            Some(batch.crop_id),
-            batch.crop_name,
-            batch.crop_area,
-            batch.crop_cultivation,
-            batch.crop_planted_at,
-            batch.crop_harvested_at,
-        )?;
-
-        Ok(Batch::new(
-            Some(batch.id),
-            crop,
-            batch.classification,
-            batch.processing,
-            batch.packing,
-            batch.quantity,
-            Some(batch.tracking_code),
-            batch.date,
-        )?)
+        Ok(batch.into())
     }
 
     pub async fn find_by_tracking_code<S: ToString>(
@@ -92,11 +244,14 @@ impl BatchRepository {
         code: S,
     ) -> Result<Vec<Batch>, AppError> {
         let code = code.to_string();
-        let batches = query!(
+        let batches = query_as!(
+            BatchDb,
             r#"
-            SELECT b.id, b.crop_id, b.classification, b.processing, b.packing, b.quantity, b.tracking_code, b.date, c.name as crop_name, c.area as crop_area, c.cultivation as crop_cultivation, c.planted_at as crop_planted_at, c.harvested_at as crop_harvested_at
+            SELECT b.id, b.crop_id, b.classification, b.processing, b.packing, b.quantity, b.quantity_unit as "quantity_unit: QuantityUnit", b.tracking_code, b.date, b.harvest_id, b.product_id, b.deleted_at, b.version, c.name as crop_name, c.area as crop_area, c.area_unit as "crop_area_unit: AreaUnit", v.id as crop_cultivation_id, v.name as crop_cultivation, v.scientific_name as crop_scientific_name, v.variety as crop_variety, v.cycle_days as crop_cycle_days, v.shelf_life_days as crop_shelf_life_days, c.planted_at as crop_planted_at, c.closed_at as crop_closed_at, c.plot_id as crop_plot_id,
+                (SELECT COALESCE(SUM(s.quantity), 0) FROM shipment_items s WHERE s.batch_id = b.id) as "shipped_quantity!: f64"
             FROM batches b
             INNER JOIN crops c ON b.crop_id = c.id
+            INNER JOIN cultivations v ON v.id = c.cultivation_id
             WHERE b.tracking_code = ?;
             "#,
             code,
@@ -104,40 +259,18 @@ impl BatchRepository {
         .fetch_all(&*self.pool)
         .await?;
 
-        let batches = batches
-            .iter()
-            .map(|batch| {
-                Batch::new(
-                    Some(batch.id),
-                    Crop::new(
-                        Some(batch.crop_id),
-                        batch.crop_name.clone(),
-                        batch.crop_area,
-                        batch.crop_cultivation.clone(),
-                        batch.crop_planted_at,
-                        batch.crop_harvested_at,
-                    )
-                    .unwrap(),
-                    batch.classification.clone(),
-                    batch.processing.clone(),
-                    batch.packing.clone(),
-                    batch.quantity,
-                    Some(batch.tracking_code.clone()),
-                    batch.date,
-                )
-                .unwrap()
-            })
-            .collect();
-
-        Ok(batches)
+        Ok(batches.into_iter().map(Batch::from).collect())
     }
 
     pub async fn find_by_crop_id(&self, crop_id: i64) -> Result<Vec<Batch>, AppError> {
-        let batches = query!(
+        let batches = query_as!(
+            BatchDb,
             r#"
-            SELECT b.id, b.crop_id, b.classification, b.processing, b.packing, b.quantity, b.tracking_code, b.date, c.name as crop_name, c.area as crop_area, c.cultivation as crop_cultivation, c.planted_at as crop_planted_at, c.harvested_at as crop_harvested_at
+            SELECT b.id, b.crop_id, b.classification, b.processing, b.packing, b.quantity, b.quantity_unit as "quantity_unit: QuantityUnit", b.tracking_code, b.date, b.harvest_id, b.product_id, b.deleted_at, b.version, c.name as crop_name, c.area as crop_area, c.area_unit as "crop_area_unit: AreaUnit", v.id as crop_cultivation_id, v.name as crop_cultivation, v.scientific_name as crop_scientific_name, v.variety as crop_variety, v.cycle_days as crop_cycle_days, v.shelf_life_days as crop_shelf_life_days, c.planted_at as crop_planted_at, c.closed_at as crop_closed_at, c.plot_id as crop_plot_id,
+                (SELECT COALESCE(SUM(s.quantity), 0) FROM shipment_items s WHERE s.batch_id = b.id) as "shipped_quantity!: f64"
             FROM batches b
             INNER JOIN crops c ON b.crop_id = c.id
+            INNER JOIN cultivations v ON v.id = c.cultivation_id
             WHERE b.crop_id = ?;
             "#,
             crop_id
@@ -145,105 +278,554 @@ impl BatchRepository {
         .fetch_all(&*self.pool)
         .await?;
 
-        let batches = batches
-            .iter()
-            .map(|batch| {
-                Batch::new(
-                    Some(batch.id),
-                    Crop::new(
-                        Some(batch.crop_id),
-                        batch.crop_name.clone(),
-                        batch.crop_area,
-                        batch.crop_cultivation.clone(),
-                        batch.crop_planted_at,
-                        batch.crop_harvested_at,
-                    )
-                    .unwrap(),
-                    batch.classification.clone(),
-                    batch.processing.clone(),
-                    batch.packing.clone(),
-                    batch.quantity,
-                    Some(batch.tracking_code.clone()),
-                    batch.date,
-                )
-                .unwrap()
-            })
-            .collect();
+        Ok(batches.into_iter().map(Batch::from).collect())
+    }
 
-        Ok(batches)
+    /// Batches packed straight from a crop, i.e. not produced by splitting
+    /// or merging others, dated within the period and not deleted. Quantities
+    /// are the packed ones: whatever was later moved to other batches is
+    /// added back.
+    pub async fn find_packed(
+        &self,
+        from: Option<chrono::NaiveDate>,
+        to: Option<chrono::NaiveDate>,
+    ) -> Result<Vec<Batch>, AppError> {
+        let batches = query_as!(
+            BatchDb,
+            r#"
+            SELECT b.id, b.crop_id, b.classification, b.processing, b.packing,
+                b.quantity + (SELECT COALESCE(SUM(l.quantity), 0) FROM batch_lineage l WHERE l.parent_id = b.id) as "quantity!: f64",
+                b.quantity_unit as "quantity_unit: QuantityUnit", b.tracking_code, b.date, b.harvest_id, b.product_id, b.deleted_at, b.version, c.name as crop_name, c.area as crop_area, c.area_unit as "crop_area_unit: AreaUnit", v.id as crop_cultivation_id, v.name as crop_cultivation, v.scientific_name as crop_scientific_name, v.variety as crop_variety, v.cycle_days as crop_cycle_days, v.shelf_life_days as crop_shelf_life_days, c.planted_at as crop_planted_at, c.closed_at as crop_closed_at, c.plot_id as crop_plot_id,
+                (SELECT COALESCE(SUM(s.quantity), 0) FROM shipment_items s WHERE s.batch_id = b.id) as "shipped_quantity!: f64"
+            FROM batches b
+            INNER JOIN crops c ON b.crop_id = c.id
+            INNER JOIN cultivations v ON v.id = c.cultivation_id
+            WHERE NOT EXISTS (SELECT 1 FROM batch_lineage l WHERE l.child_id = b.id)
+                AND b.deleted_at IS NULL
+                AND (?1 IS NULL OR b.date >= ?1)
+                AND (?2 IS NULL OR b.date <= ?2)
+            ORDER BY b.date, b.id;
+            "#,
+            from,
+            to
+        )
+        .fetch_all(&*self.pool)
+        .await?;
+
+        Ok(batches.into_iter().map(Batch::from).collect())
     }
 
-    pub async fn insert(&self, mut batch: Batch) -> Result<Batch, AppError> {
+    async fn insert_with(
+        connection: &mut SqliteConnection,
+        batch: &Batch,
+    ) -> Result<i64, AppError> {
         let crop_id = batch.crop().id().unwrap();
         let classification = batch.classification().clone();
         let processing = batch.processing().clone();
         let packing = batch.packing();
         let quantity = batch.quantity();
+        let quantity_unit = batch.quantity_unit();
         let tracking_code = batch.tracking_code().clone().unwrap();
         let date = batch.date();
+        let harvest_id = batch.harvest_id();
+        let product_id = batch.product_id();
 
         let id = query!(
             r#"
-            INSERT INTO batches (crop_id, classification, processing, packing, quantity, tracking_code, date)
-            VALUES (?, ?, ?, ?, ?, ?, ?);
+            INSERT INTO batches (crop_id, classification, processing, packing, quantity, quantity_unit, tracking_code, date, harvest_id, product_id)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?);
             "#,
             crop_id,
             classification,
             processing,
             packing,
             quantity,
+            quantity_unit,
             tracking_code,
-            date
+            date,
+            harvest_id,
+            product_id
         )
-        .execute(&*self.pool)
+        .execute(connection)
         .await?
         .last_insert_rowid();
 
+        Ok(id)
+    }
+
+    pub async fn insert(
+        &self,
+        connection: &mut SqliteConnection,
+        mut batch: Batch,
+    ) -> Result<Batch, AppError> {
+        let id = Self::insert_with(connection, &batch).await?;
+
         batch.set_id(Some(id));
 
         Ok(batch)
     }
 
-    pub async fn update(&self, id: i64, batch: Batch) -> Result<Batch, AppError> {
+    pub async fn insert_all(
+        &self,
+        connection: &mut SqliteConnection,
+        mut batches: Vec<Batch>,
+    ) -> Result<Vec<Batch>, AppError> {
+        for batch in batches.iter_mut() {
+            let id = Self::insert_with(connection, batch).await?;
+            batch.set_id(Some(id));
+        }
+
+        Ok(batches)
+    }
+
+    /// Lowers `batch` by `quantity` if it is still at the version it was read
+    /// at and still has that much available, so concurrent changes can't
+    /// take the same quantity twice.
+    async fn take(
+        connection: &mut SqliteConnection,
+        batch: &Batch,
+        quantity: f64,
+    ) -> Result<(), AppError> {
+        let id = batch.id().unwrap();
+        let version = batch.version();
+
+        let updated = query!(
+            r#"
+            UPDATE batches
+            SET quantity = quantity - ?1, version = version + 1
+            WHERE id = ?2 AND version = ?3
+                AND quantity - (SELECT COALESCE(SUM(s.quantity), 0) FROM shipment_items s WHERE s.batch_id = ?2) >= ?1;
+            "#,
+            quantity,
+            id,
+            version
+        )
+        .execute(&mut *connection)
+        .await?
+        .rows_affected();
+        if updated > 0 {
+            return Ok(());
+        }
+
+        // Shipments don't change the version, so the batch may be current and
+        // still have lost the quantity to one.
+        let current = query!("SELECT version FROM batches WHERE id = ?", id)
+            .fetch_one(&mut *connection)
+            .await?;
+        if current.version != version {
+            return Err(Self::stale(id));
+        }
+
+        Err(AppError::BadRequest(format!(
+            "O lote {} não tem mais {} disponível, pois foi expedido por outra pessoa. Recarregue-o e tente novamente.",
+            batch.tracking_code().as_ref().unwrap(),
+            quantity
+        )))
+    }
+
+    /// Moves part of `parent` into `children`, each paired with the quantity
+    /// it takes from the parent in the parent's unit, recording each child in
+    /// the lineage table. Fails if the parent changed since it was read or no
+    /// longer has that quantity available.
+    pub async fn split(
+        &self,
+        connection: &mut SqliteConnection,
+        parent: &Batch,
+        children: Vec<(Batch, f64)>,
+    ) -> Result<Vec<Batch>, AppError> {
+        let parent_id = parent.id().unwrap();
+        let total: f64 = children.iter().map(|(_, quantity)| quantity).sum();
+
+        Self::take(connection, parent, total).await?;
+
+        let mut inserted = Vec::with_capacity(children.len());
+        for (mut child, quantity) in children {
+            let child_id = Self::insert_with(connection, &child).await?;
+
+            query!(
+                r#"
+                INSERT INTO batch_lineage (parent_id, child_id, quantity)
+                VALUES (?, ?, ?);
+                "#,
+                parent_id,
+                child_id,
+                quantity
+            )
+            .execute(&mut *connection)
+            .await?;
+
+            child.set_id(Some(child_id));
+            inserted.push(child);
+        }
+
+        Ok(inserted)
+    }
+
+    /// Inserts `batch` made from `sources`, each paired with the quantity it
+    /// contributed, and lowers the sources' quantities. Fails if a source
+    /// changed since it was read or no longer has its quantity available.
+    pub async fn merge(
+        &self,
+        connection: &mut SqliteConnection,
+        sources: &[(Batch, f64)],
+        mut batch: Batch,
+    ) -> Result<Batch, AppError> {
+        let child_id = Self::insert_with(connection, &batch).await?;
+
+        for (source, quantity) in sources {
+            let parent_id = source.id().unwrap();
+
+            Self::take(connection, source, *quantity).await?;
+
+            query!(
+                r#"
+                INSERT INTO batch_lineage (parent_id, child_id, quantity)
+                VALUES (?, ?, ?);
+                "#,
+                parent_id,
+                child_id,
+                quantity
+            )
+            .execute(&mut *connection)
+            .await?;
+        }
+
+        batch.set_id(Some(child_id));
+
+        Ok(batch)
+    }
+
+    /// Every `batch_lineage` edge as `(parent_id, child_id, quantity)`.
+    pub async fn list_links(&self) -> Result<Vec<(i64, i64, f64)>, AppError> {
+        let links = query!(
+            r#"
+            SELECT parent_id, child_id, quantity
+            FROM batch_lineage
+            ORDER BY child_id, parent_id
+            "#,
+        )
+        .fetch_all(&*self.pool)
+        .await?;
+
+        Ok(links
+            .into_iter()
+            .map(|link| (link.parent_id, link.child_id, link.quantity))
+            .collect())
+    }
+
+    /// Links into or out of the batches dated between `from` and `to`, both
+    /// inclusive.
+    pub async fn list_links_dated(
+        &self,
+        from: Option<chrono::NaiveDate>,
+        to: Option<chrono::NaiveDate>,
+    ) -> Result<Vec<(i64, i64, f64)>, AppError> {
+        let links = query!(
+            r#"
+            SELECT l.parent_id, l.child_id, l.quantity
+            FROM batch_lineage l
+            WHERE EXISTS (
+                SELECT 1 FROM batches b
+                WHERE b.id IN (l.parent_id, l.child_id)
+                    AND (?1 IS NULL OR b.date >= ?1) AND (?2 IS NULL OR b.date <= ?2)
+            )
+            ORDER BY l.child_id, l.parent_id
+            "#,
+            from,
+            to
+        )
+        .fetch_all(&*self.pool)
+        .await?;
+
+        Ok(links
+            .into_iter()
+            .map(|link| (link.parent_id, link.child_id, link.quantity))
+            .collect())
+    }
+
+    /// Crops of the batch and of every batch it descends from.
+    pub async fn find_origins(&self, id: i64) -> Result<Vec<Crop>, AppError> {
+        let crops = query!(
+            r#"
+            WITH RECURSIVE ancestors(id) AS (
+                SELECT ?
+                UNION
+                SELECT l.parent_id
+                FROM batch_lineage l
+                INNER JOIN ancestors a ON l.child_id = a.id
+            )
+            SELECT DISTINCT c.id as "id!", c.name, c.area, c.area_unit as "area_unit: AreaUnit", v.id as cultivation_id, v.name as cultivation_name, v.scientific_name, v.variety, v.cycle_days, v.shelf_life_days, c.planted_at, c.closed_at, c.plot_id
+            FROM ancestors a
+            INNER JOIN batches b ON b.id = a.id
+            INNER JOIN crops c ON c.id = b.crop_id
+            INNER JOIN cultivations v ON v.id = c.cultivation_id
+            ORDER BY c.id;
+            "#,
+            id
+        )
+        .fetch_all(&*self.pool)
+        .await?;
+
+        let crops = crops
+            .into_iter()
+            .map(|crop| {
+                let cultivation = Cultivation::new(
+                    Some(crop.cultivation_id),
+                    crop.cultivation_name,
+                    crop.scientific_name,
+                    crop.variety,
+                    crop.cycle_days,
+                    crop.shelf_life_days,
+                )?;
+                Crop::new(
+                    Some(crop.id),
+                    crop.name,
+                    crop.area,
+                    crop.area_unit,
+                    cultivation,
+                    crop.planted_at,
+                    crop.closed_at,
+                    crop.plot_id,
+                )
+            })
+            .collect::<Result<Vec<Crop>, _>>()?;
+
+        Ok(crops)
+    }
+
+    /// Batches of crops of species `cultivation` (ignoring case) packed between
+    /// `start` and `end`, both inclusive.
+    pub async fn find_by_cultivation(
+        &self,
+        cultivation: &str,
+        start: chrono::NaiveDate,
+        end: chrono::NaiveDate,
+    ) -> Result<Vec<Batch>, AppError> {
+        let batches = query_as!(
+            BatchDb,
+            r#"
+            SELECT b.id, b.crop_id, b.classification, b.processing, b.packing, b.quantity, b.quantity_unit as "quantity_unit: QuantityUnit", b.tracking_code, b.date, b.harvest_id, b.product_id, b.deleted_at, b.version, c.name as crop_name, c.area as crop_area, c.area_unit as "crop_area_unit: AreaUnit", v.id as crop_cultivation_id, v.name as crop_cultivation, v.scientific_name as crop_scientific_name, v.variety as crop_variety, v.cycle_days as crop_cycle_days, v.shelf_life_days as crop_shelf_life_days, c.planted_at as crop_planted_at, c.closed_at as crop_closed_at, c.plot_id as crop_plot_id,
+                (SELECT COALESCE(SUM(s.quantity), 0) FROM shipment_items s WHERE s.batch_id = b.id) as "shipped_quantity!: f64"
+            FROM batches b
+            INNER JOIN crops c ON b.crop_id = c.id
+            INNER JOIN cultivations v ON v.id = c.cultivation_id
+            WHERE v.name = ? AND b.date BETWEEN ? AND ?;
+            "#,
+            cultivation,
+            start,
+            end
+        )
+        .fetch_all(&*self.pool)
+        .await?;
+
+        Ok(batches.into_iter().map(Batch::from).collect())
+    }
+
+    /// Walks `batch_lineage` from batch `id` up to `depth` levels in the given
+    /// direction. Each link carries the batch reached through it.
+    pub async fn find_lineage(
+        &self,
+        id: i64,
+        direction: LineageDirection,
+        depth: i64,
+    ) -> Result<Vec<LineageLink>, AppError> {
+        let up = direction == LineageDirection::Up;
+        let links = query!(
+            r#"
+            WITH RECURSIVE lineage(parent_id, child_id, quantity, depth) AS (
+                SELECT parent_id, child_id, quantity, 1
+                FROM batch_lineage
+                WHERE CASE WHEN ?2 THEN child_id ELSE parent_id END = ?1
+                UNION
+                SELECT l.parent_id, l.child_id, l.quantity, g.depth + 1
+                FROM batch_lineage l
+                INNER JOIN lineage g
+                    ON CASE WHEN ?2 THEN l.child_id = g.parent_id ELSE l.parent_id = g.child_id END
+                WHERE g.depth < ?3
+            )
+            SELECT DISTINCT g.parent_id as "parent_id!: i64", g.child_id as "child_id!: i64", g.quantity as "link_quantity!: f64", b.id, b.crop_id, b.classification, b.processing, b.packing, b.quantity, b.quantity_unit as "quantity_unit: QuantityUnit", b.tracking_code, b.date, b.harvest_id, b.product_id, b.deleted_at, b.version, c.name as crop_name, c.area as crop_area, c.area_unit as "crop_area_unit: AreaUnit", v.id as crop_cultivation_id, v.name as crop_cultivation, v.scientific_name as crop_scientific_name, v.variety as crop_variety, v.cycle_days as crop_cycle_days, v.shelf_life_days as crop_shelf_life_days, c.planted_at as crop_planted_at, c.closed_at as crop_closed_at, c.plot_id as crop_plot_id,
+                (SELECT COALESCE(SUM(s.quantity), 0) FROM shipment_items s WHERE s.batch_id = b.id) as "shipped_quantity!: f64"
+            FROM lineage g
+            INNER JOIN batches b ON b.id = CASE WHEN ?2 THEN g.parent_id ELSE g.child_id END
+            INNER JOIN crops c ON b.crop_id = c.id
+            INNER JOIN cultivations v ON v.id = c.cultivation_id
+            ORDER BY b.id;
+            "#,
+            id,
+            up,
+            depth
+        )
+        .fetch_all(&*self.pool)
+        .await?;
+
+        let links = links
+            .into_iter()
+            .map(|link| {
+                let batch = BatchDb {
+                    id: link.id,
+                    crop_id: link.crop_id,
+                    classification: link.classification,
+                    processing: link.processing,
+                    packing: link.packing,
+                    quantity: link.quantity,
+                    quantity_unit: link.quantity_unit,
+                    tracking_code: link.tracking_code,
+                    date: link.date,
+                    harvest_id: link.harvest_id,
+                    product_id: link.product_id,
+                    crop_name: link.crop_name,
+                    crop_area: link.crop_area,
+                    crop_area_unit: link.crop_area_unit,
+                    crop_cultivation_id: link.crop_cultivation_id,
+                    crop_cultivation: link.crop_cultivation,
+                    crop_scientific_name: link.crop_scientific_name,
+                    crop_variety: link.crop_variety,
+                    crop_cycle_days: link.crop_cycle_days,
+                    crop_shelf_life_days: link.crop_shelf_life_days,
+                    crop_planted_at: link.crop_planted_at,
+                    crop_closed_at: link.crop_closed_at,
+                    crop_plot_id: link.crop_plot_id,
+                    shipped_quantity: link.shipped_quantity,
+                    deleted_at: link.deleted_at,
+                    version: link.version,
+                };
+                LineageLink::new(
+                    link.parent_id,
+                    link.child_id,
+                    link.link_quantity,
+                    batch.into(),
+                )
+            })
+            .collect();
+
+        Ok(links)
+    }
+
+    /// Updates the batch if it is still at `version`, so a change made since
+    /// it was read isn't overwritten, and its new quantity still covers what
+    /// was shipped from it, which doesn't change the version.
+    pub async fn update(
+        &self,
+        connection: &mut SqliteConnection,
+        id: i64,
+        version: i64,
+        mut batch: Batch,
+    ) -> Result<Batch, AppError> {
         let crop_id = batch.crop().id().unwrap();
         let classification = batch.classification().clone();
         let processing = batch.processing().clone();
         let packing = batch.packing();
         let quantity = batch.quantity();
+        let quantity_unit = batch.quantity_unit();
         let tracking_code = batch.tracking_code().clone().unwrap();
         let date = batch.date();
+        let harvest_id = batch.harvest_id();
+        let product_id = batch.product_id();
 
-        query!(
+        let updated = query!(
             r#"
             UPDATE batches
-            SET crop_id = ?, classification = ?, processing = ?, packing = ?, quantity = ?, tracking_code = ?, date = ?
-            WHERE id = ?;
+            SET crop_id = ?, classification = ?, processing = ?, packing = ?, quantity = ?, quantity_unit = ?, tracking_code = ?, date = ?, harvest_id = ?, product_id = ?, version = version + 1
+            WHERE id = ? AND version = ?
+                AND ? >= (SELECT COALESCE(SUM(quantity), 0) FROM shipment_items WHERE batch_id = batches.id);
             "#,
             crop_id,
             classification,
             processing,
             packing,
             quantity,
+            quantity_unit,
             tracking_code,
             date,
-            id
+            harvest_id,
+            product_id,
+            id,
+            version,
+            quantity
         )
-        .execute(&*self.pool)
-        .await?;
+        .execute(&mut *connection)
+        .await?
+        .rows_affected();
+        if updated == 0 {
+            let current = query!(
+                r#"
+                SELECT version, (SELECT COALESCE(SUM(quantity), 0) FROM shipment_items WHERE batch_id = ?1) AS "shipped_quantity!: f64"
+                FROM batches
+                WHERE id = ?1
+                "#,
+                id
+            )
+            .fetch_one(&mut *connection)
+            .await?;
+            if current.version != version {
+                return Err(Self::stale(id));
+            }
+            return Err(Self::over_shipped(quantity, current.shipped_quantity));
+        }
+
+        batch.set_version(version + 1);
 
         Ok(batch)
     }
 
-    pub async fn delete(&self, id: i64) -> Result<(), AppError> {
-        query!(
+    /// Marks the batch as deleted, if it is still at `version` and nothing
+    /// was shipped from it, keeping its row so its tracking code is never
+    /// issued again.
+    pub async fn delete(
+        &self,
+        connection: &mut SqliteConnection,
+        id: i64,
+        version: i64,
+        deleted_at: chrono::NaiveDateTime,
+    ) -> Result<(), AppError> {
+        let updated = query!(
+            r#"
+            UPDATE batches
+            SET deleted_at = ?1, version = version + 1
+            WHERE id = ?2 AND version = ?3
+                AND NOT EXISTS (SELECT 1 FROM shipment_items WHERE batch_id = ?2);
+            "#,
+            deleted_at,
+            id,
+            version
+        )
+        .execute(&mut *connection)
+        .await?
+        .rows_affected();
+        if updated == 0 {
+            let current = query!("SELECT version FROM batches WHERE id = ?", id)
+                .fetch_one(&mut *connection)
+                .await?;
+            if current.version != version {
+                return Err(Self::stale(id));
+            }
+            return Err(Self::shipped(id));
+        }
+
+        Ok(())
+    }
+
+    /// Undoes the deletion of the batch, failing if it isn't deleted, so
+    /// concurrent restores don't both go through.
+    pub async fn restore(
+        &self,
+        connection: &mut SqliteConnection,
+        id: i64,
+    ) -> Result<(), AppError> {
+        let updated = query!(
             r#"
-            DELETE FROM batches
-            WHERE id = ?;
+            UPDATE batches
+            SET deleted_at = NULL, version = version + 1
+            WHERE id = ? AND deleted_at IS NOT NULL;
             "#,
             id
         )
-        .execute(&*self.pool)
-        .await?;
+        .execute(&mut *connection)
+        .await?
+        .rows_affected();
+        if updated == 0 {
+            return Err(Self::not_deleted(id));
+        }
 
         Ok(())
     }
@@ -0,0 +1,201 @@
+use sqlx::{query, query_as, SqlitePool};
+
+use crate::{
+    errors::AppError,
+    models::{CatalogEntry, CatalogKind},
+};
+
+#[derive(Debug)]
+pub struct CatalogEntryDb {
+    id: i64,
+    kind: CatalogKind,
+    code: String,
+    description: String,
+    active: bool,
+}
+
+impl From<CatalogEntryDb> for CatalogEntry {
+    fn from(entry: CatalogEntryDb) -> Self {
+        CatalogEntry::new(
+            Some(entry.id),
+            entry.kind,
+            entry.code,
+            entry.description,
+            entry.active,
+        )
+        .unwrap()
+    }
+}
+
+pub struct CatalogRepository {
+    pool: Box<SqlitePool>,
+}
+
+impl CatalogRepository {
+    pub fn new(pool: Box<SqlitePool>) -> Self {
+        Self { pool }
+    }
+
+    pub async fn list(
+        &self,
+        kind: CatalogKind,
+        active: Option<bool>,
+    ) -> Result<Vec<CatalogEntry>, AppError> {
+        let entries = query_as!(
+            CatalogEntryDb,
+            r#"
+            SELECT id, kind as "kind: CatalogKind", code, description, active
+            FROM catalog_entries
+            WHERE kind = ?1 AND (?2 IS NULL OR active = ?2)
+            ORDER BY code
+            "#,
+            kind,
+            active
+        )
+        .fetch_all(&*self.pool)
+        .await?;
+
+        Ok(entries.into_iter().map(CatalogEntry::from).collect())
+    }
+
+    pub async fn find_by_id(&self, kind: CatalogKind, id: i64) -> Result<CatalogEntry, AppError> {
+        let entry = query_as!(
+            CatalogEntryDb,
+            r#"
+            SELECT id, kind as "kind: CatalogKind", code, description, active
+            FROM catalog_entries
+            WHERE kind = ? AND id = ?
+            "#,
+            kind,
+            id
+        )
+        .fetch_optional(&*self.pool)
+        .await?;
+
+        match entry {
+            Some(entry) => Ok(entry.into()),
+            None => Err(AppError::NotFound(format!(
+                "Item de catálogo de ID {} não encontrado",
+                id
+            ))),
+        }
+    }
+
+    /// Looks the code up ignoring case.
+    pub async fn find_by_code(
+        &self,
+        kind: CatalogKind,
+        code: &str,
+    ) -> Result<Option<CatalogEntry>, AppError> {
+        let entry = query_as!(
+            CatalogEntryDb,
+            r#"
+            SELECT id, kind as "kind: CatalogKind", code, description, active
+            FROM catalog_entries
+            WHERE kind = ? AND code = ?
+            "#,
+            kind,
+            code
+        )
+        .fetch_optional(&*self.pool)
+        .await?;
+
+        Ok(entry.map(CatalogEntry::from))
+    }
+
+    pub async fn count_batches(&self, kind: CatalogKind, code: &str) -> Result<i64, AppError> {
+        let count = match kind {
+            CatalogKind::Classification => {
+                query!(
+                    r#"
+                    SELECT COUNT(*) as count
+                    FROM batches
+                    WHERE classification = ?
+                    "#,
+                    code
+                )
+                .fetch_one(&*self.pool)
+                .await?
+                .count
+            }
+            CatalogKind::Processing => {
+                query!(
+                    r#"
+                    SELECT COUNT(*) as count
+                    FROM batches
+                    WHERE processing = ?
+                    "#,
+                    code
+                )
+                .fetch_one(&*self.pool)
+                .await?
+                .count
+            }
+        };
+
+        Ok(count)
+    }
+
+    pub async fn insert(&self, mut entry: CatalogEntry) -> Result<CatalogEntry, AppError> {
+        let kind = entry.kind();
+        let code = entry.code().to_string();
+        let description = entry.description().to_string();
+        let active = entry.active();
+
+        let entry_id = query!(
+            r#"
+            INSERT INTO catalog_entries (kind, code, description, active)
+            VALUES (?, ?, ?, ?)
+            RETURNING id
+            "#,
+            kind,
+            code,
+            description,
+            active,
+        )
+        .fetch_one(&*self.pool)
+        .await?;
+
+        entry.set_id(Some(entry_id.id));
+
+        Ok(entry)
+    }
+
+    pub async fn update(&self, id: i64, mut entry: CatalogEntry) -> Result<CatalogEntry, AppError> {
+        let code = entry.code().to_string();
+        let description = entry.description().to_string();
+        let active = entry.active();
+
+        query!(
+            r#"
+            UPDATE catalog_entries
+            SET code = ?, description = ?, active = ?
+            WHERE id = ?
+            "#,
+            code,
+            description,
+            active,
+            id,
+        )
+        .execute(&*self.pool)
+        .await?;
+
+        entry.set_id(Some(id));
+
+        Ok(entry)
+    }
+
+    pub async fn delete(&self, id: i64) -> Result<(), AppError> {
+        query!(
+            r#"
+            DELETE FROM catalog_entries
+            WHERE id = ?
+            "#,
+            id,
+        )
+        .execute(&*self.pool)
+        .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,158 @@
+use sqlx::{query, query_as, SqlitePool};
+
+use crate::{errors::AppError, models::ChemicalApplication};
+
+#[derive(Debug)]
+pub struct ChemicalApplicationDb {
+    id: i64,
+    crop_id: i64,
+    product: String,
+    active_ingredient: String,
+    dose: f64,
+    dose_unit: String,
+    applied_at: chrono::NaiveDate,
+    withdrawal_days: i64,
+}
+
+impl From<ChemicalApplicationDb> for ChemicalApplication {
+    fn from(application: ChemicalApplicationDb) -> Self {
+        ChemicalApplication::new(
+            Some(application.id),
+            application.crop_id,
+            application.product,
+            application.active_ingredient,
+            application.dose,
+            application.dose_unit,
+            application.applied_at,
+            application.withdrawal_days,
+        )
+        .unwrap()
+    }
+}
+
+pub struct ChemicalApplicationRepository {
+    pool: Box<SqlitePool>,
+}
+
+impl ChemicalApplicationRepository {
+    pub fn new(pool: Box<SqlitePool>) -> Self {
+        Self { pool }
+    }
+
+    pub async fn list_by_crop_id(
+        &self,
+        crop_id: i64,
+    ) -> Result<Vec<ChemicalApplication>, AppError> {
+        let applications = query_as!(
+            ChemicalApplicationDb,
+            r#"
+            SELECT id, crop_id, product, active_ingredient, dose, dose_unit, applied_at, withdrawal_days
+            FROM chemical_applications
+            WHERE crop_id = ?
+            ORDER BY applied_at, id
+            "#,
+            crop_id
+        )
+        .fetch_all(&*self.pool)
+        .await?;
+
+        Ok(applications
+            .into_iter()
+            .map(ChemicalApplication::from)
+            .collect())
+    }
+
+    /// Applications whose withdrawal period includes `date`, of any crop.
+    pub async fn list_active_on(
+        &self,
+        date: chrono::NaiveDate,
+    ) -> Result<Vec<ChemicalApplication>, AppError> {
+        let applications = query_as!(
+            ChemicalApplicationDb,
+            r#"
+            SELECT id, crop_id, product, active_ingredient, dose, dose_unit, applied_at, withdrawal_days
+            FROM chemical_applications
+            WHERE applied_at <= ?1 AND date(applied_at, '+' || withdrawal_days || ' days') > ?1
+            ORDER BY crop_id, applied_at, id
+            "#,
+            date
+        )
+        .fetch_all(&*self.pool)
+        .await?;
+
+        Ok(applications
+            .into_iter()
+            .map(ChemicalApplication::from)
+            .collect())
+    }
+
+    pub async fn find_by_id(&self, id: i64) -> Result<ChemicalApplication, AppError> {
+        let application = query_as!(
+            ChemicalApplicationDb,
+            r#"
+            SELECT id, crop_id, product, active_ingredient, dose, dose_unit, applied_at, withdrawal_days
+            FROM chemical_applications
+            WHERE id = ?
+            "#,
+            id
+        )
+        .fetch_optional(&*self.pool)
+        .await?;
+
+        match application {
+            Some(application) => Ok(application.into()),
+            None => Err(AppError::NotFound(format!(
+                "Aplicação de ID {} não encontrada",
+                id
+            ))),
+        }
+    }
+
+    pub async fn insert(
+        &self,
+        mut application: ChemicalApplication,
+    ) -> Result<ChemicalApplication, AppError> {
+        let crop_id = application.crop_id();
+        let product = application.product().to_string();
+        let active_ingredient = application.active_ingredient().to_string();
+        let dose = application.dose();
+        let dose_unit = application.dose_unit().to_string();
+        let applied_at = application.applied_at();
+        let withdrawal_days = application.withdrawal_days();
+
+        let application_id = query!(
+            r#"
+            INSERT INTO chemical_applications (crop_id, product, active_ingredient, dose, dose_unit, applied_at, withdrawal_days)
+            VALUES (?, ?, ?, ?, ?, ?, ?)
+            RETURNING id
+            "#,
+            crop_id,
+            product,
+            active_ingredient,
+            dose,
+            dose_unit,
+            applied_at,
+            withdrawal_days,
+        )
+        .fetch_one(&*self.pool)
+        .await?;
+
+        application.set_id(Some(application_id.id));
+
+        Ok(application)
+    }
+
+    pub async fn delete(&self, id: i64) -> Result<(), AppError> {
+        query!(
+            r#"
+            DELETE FROM chemical_applications
+            WHERE id = ?
+            "#,
+            id,
+        )
+        .execute(&*self.pool)
+        .await?;
+
+        Ok(())
+    }
+}
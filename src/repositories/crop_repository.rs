@@ -1,42 +1,57 @@
-use sqlx::{query, query_as, SqlitePool};
+use async_stream::try_stream;
+use futures::{stream::BoxStream, TryStreamExt};
+use sqlx::{query, query_as, Sqlite, SqliteConnection, SqlitePool, Transaction};
 
-use crate::{errors::AppError, models::Crop};
+use crate::{
+    errors::AppError,
+    models::{AreaUnit, Crop, Cultivation},
+};
 
 #[derive(Debug)]
 pub struct CropDb {
     id: i64,
     name: String,
     area: f64,
-    cultivation: String,
+    area_unit: AreaUnit,
+    cultivation_id: i64,
+    cultivation_name: String,
+    cultivation_scientific_name: Option<String>,
+    cultivation_variety: Option<String>,
+    cultivation_cycle_days: Option<i64>,
+    cultivation_shelf_life_days: Option<i64>,
     planted_at: chrono::NaiveDate,
-    harvested_at: Option<chrono::NaiveDate>,
+    closed_at: Option<chrono::NaiveDate>,
+    plot_id: Option<i64>,
+    deleted_at: Option<chrono::NaiveDateTime>,
+    version: i64,
 }
 
 impl From<CropDb> for Crop {
     fn from(crop: CropDb) -> Self {
-        Crop::new(
-            Some(crop.id),
-            crop.name,
-            crop.area,
-            crop.cultivation,
-            crop.planted_at,
-            crop.harvested_at,
+        let cultivation = Cultivation::new(
+            Some(crop.cultivation_id),
+            crop.cultivation_name,
+            crop.cultivation_scientific_name,
+            crop.cultivation_variety,
+            crop.cultivation_cycle_days,
+            crop.cultivation_shelf_life_days,
         )
-        .unwrap()
-    }
-}
+        .unwrap();
 
-impl From<&CropDb> for Crop {
-    fn from(crop: &CropDb) -> Self {
-        Crop::new(
+        let mut result = Crop::new(
             Some(crop.id),
-            crop.name.clone(),
+            crop.name,
             crop.area,
-            crop.cultivation.clone(),
+            crop.area_unit,
+            cultivation,
             crop.planted_at,
-            crop.harvested_at,
+            crop.closed_at,
+            crop.plot_id,
         )
-        .unwrap()
+        .unwrap();
+        result.set_deleted_at(crop.deleted_at);
+        result.set_version(crop.version);
+        result
     }
 }
 
@@ -49,29 +64,91 @@ impl CropRepository {
         Self { pool }
     }
 
-    pub async fn list(&self) -> Result<Vec<Crop>, AppError> {
+    /// Error for a change based on a version of the crop other than the
+    /// current one.
+    pub fn stale(id: i64) -> AppError {
+        AppError::PreconditionFailed(format!(
+            "O plantio de ID {id} foi alterado por outra pessoa. Recarregue-o e tente novamente."
+        ))
+    }
+
+    /// Error for closing a crop already closed on `closed_at`.
+    pub fn closed(id: i64, closed_at: chrono::NaiveDate) -> AppError {
+        AppError::BadRequest(format!(
+            "O plantio de ID {id} já foi encerrado em {}.",
+            closed_at.format("%d/%m/%Y")
+        ))
+    }
+
+    /// Error for closing a crop on `closed_at`, before its `last` harvest.
+    pub fn harvested_after(closed_at: chrono::NaiveDate, last: chrono::NaiveDate) -> AppError {
+        AppError::BadRequest(format!(
+            "A data de encerramento ({}) não pode ser anterior a última colheita ({})",
+            closed_at.format("%d/%m/%Y"),
+            last.format("%d/%m/%Y")
+        ))
+    }
+
+    /// Error for restoring a crop that isn't deleted.
+    pub fn not_deleted(id: i64) -> AppError {
+        AppError::BadRequest(format!("O plantio de ID {id} não está excluído."))
+    }
+
+    /// Starts a transaction for the changes below, which take the connection
+    /// to run on so their audit entries are written along with them.
+    pub async fn begin(&self) -> Result<Transaction<'static, Sqlite>, AppError> {
+        Ok(self.pool.begin().await?)
+    }
+
+    /// Crops not deleted, or every crop when `include_deleted` is set.
+    pub async fn list(&self, include_deleted: bool) -> Result<Vec<Crop>, AppError> {
         let crops = query_as!(
             CropDb,
             r#"
-            SELECT id, name, area, cultivation, planted_at, harvested_at
-            FROM crops
+            SELECT c.id, c.name, c.area, c.area_unit as "area_unit: AreaUnit", v.id as cultivation_id, v.name as cultivation_name, v.scientific_name as cultivation_scientific_name, v.variety as cultivation_variety, v.cycle_days as cultivation_cycle_days, v.shelf_life_days as cultivation_shelf_life_days, c.planted_at, c.closed_at, c.plot_id, c.deleted_at, c.version
+            FROM crops c
+            INNER JOIN cultivations v ON v.id = c.cultivation_id
+            WHERE ? OR c.deleted_at IS NULL
             "#,
+            include_deleted
         )
         .fetch_all(&*self.pool)
         .await?;
 
-        let crops = crops.iter().map(|crop| crop.into()).collect();
+        let crops = crops.into_iter().map(Crop::from).collect();
 
         Ok(crops)
     }
 
+    /// Same rows as [`CropRepository::list`], fetched as they are read.
+    pub fn stream(&self, include_deleted: bool) -> BoxStream<'_, Result<Crop, AppError>> {
+        Box::pin(try_stream! {
+            let mut rows = query_as!(
+                CropDb,
+                r#"
+                SELECT c.id, c.name, c.area, c.area_unit as "area_unit: AreaUnit", v.id as cultivation_id, v.name as cultivation_name, v.scientific_name as cultivation_scientific_name, v.variety as cultivation_variety, v.cycle_days as cultivation_cycle_days, v.shelf_life_days as cultivation_shelf_life_days, c.planted_at, c.closed_at, c.plot_id, c.deleted_at, c.version
+                FROM crops c
+                INNER JOIN cultivations v ON v.id = c.cultivation_id
+                WHERE ? OR c.deleted_at IS NULL
+                ORDER BY c.id
+                "#,
+                include_deleted
+            )
+            .fetch(&*self.pool);
+            while let Some(row) = rows.try_next().await? {
+                yield Crop::from(row);
+            }
+        })
+    }
+
     pub async fn find_by_id(&self, id: i64) -> Result<Crop, AppError> {
         let crop = query_as!(
             CropDb,
             r#"
-            SELECT id, name, area, cultivation, planted_at, harvested_at
-            FROM crops
-            WHERE id = ?
+            SELECT c.id, c.name, c.area, c.area_unit as "area_unit: AreaUnit", v.id as cultivation_id, v.name as cultivation_name, v.scientific_name as cultivation_scientific_name, v.variety as cultivation_variety, v.cycle_days as cultivation_cycle_days, v.shelf_life_days as cultivation_shelf_life_days, c.planted_at, c.closed_at, c.plot_id, c.deleted_at, c.version
+            FROM crops c
+            INNER JOIN cultivations v ON v.id = c.cultivation_id
+            WHERE c.id = ?
             "#,
             id
         )
@@ -87,69 +164,216 @@ impl CropRepository {
         }
     }
 
-    pub async fn insert(&self, mut crop: Crop) -> Result<Crop, AppError> {
+    pub async fn count_by_plot_id(&self, plot_id: i64) -> Result<i64, AppError> {
+        let count = query!(
+            r#"
+            SELECT COUNT(*) as count
+            FROM crops
+            WHERE plot_id = ?
+            "#,
+            plot_id
+        )
+        .fetch_one(&*self.pool)
+        .await?;
+
+        Ok(count.count)
+    }
+
+    async fn insert_with(connection: &mut SqliteConnection, crop: &Crop) -> Result<i64, AppError> {
         let crop_name = crop.name().to_string();
         let crop_area = crop.area();
-        let crop_cultivation = crop.cultivation().to_string();
+        let crop_area_unit = crop.area_unit();
+        let crop_cultivation_id = crop.cultivation().id().unwrap();
         let crop_planted_at = crop.planted_at();
-        let crop_harvested_at = crop.harvested_at();
+        let crop_closed_at = crop.closed_at();
+        let crop_plot_id = crop.plot_id();
 
         let crop_id = query!(
             r#"
-            INSERT INTO crops (name, area, cultivation, planted_at, harvested_at)
-            VALUES (?, ?, ?, ?, ?)
+            INSERT INTO crops (name, area, area_unit, cultivation_id, planted_at, closed_at, plot_id)
+            VALUES (?, ?, ?, ?, ?, ?, ?)
             RETURNING id
             "#,
             crop_name,
             crop_area,
-            crop_cultivation,
+            crop_area_unit,
+            crop_cultivation_id,
             crop_planted_at,
-            crop_harvested_at,
+            crop_closed_at,
+            crop_plot_id,
         )
-        .fetch_one(&*self.pool)
+        .fetch_one(connection)
         .await?;
 
-        crop.set_id(Some(crop_id.id));
+        Ok(crop_id.id)
+    }
+
+    pub async fn insert(
+        &self,
+        connection: &mut SqliteConnection,
+        mut crop: Crop,
+    ) -> Result<Crop, AppError> {
+        let id = Self::insert_with(connection, &crop).await?;
+
+        crop.set_id(Some(id));
 
         Ok(crop)
     }
 
-    pub async fn update(&self, id: i64, crop: Crop) -> Result<Crop, AppError> {
+    pub async fn insert_all(
+        &self,
+        connection: &mut SqliteConnection,
+        mut crops: Vec<Crop>,
+    ) -> Result<Vec<Crop>, AppError> {
+        for crop in crops.iter_mut() {
+            let id = Self::insert_with(connection, crop).await?;
+            crop.set_id(Some(id));
+        }
+
+        Ok(crops)
+    }
+
+    /// Updates the crop if it is still at `version`, so a change made since
+    /// it was read isn't overwritten.
+    pub async fn update(
+        &self,
+        connection: &mut SqliteConnection,
+        id: i64,
+        version: i64,
+        mut crop: Crop,
+    ) -> Result<Crop, AppError> {
         let crop_name = crop.name().to_string();
         let crop_area = crop.area();
-        let crop_cultivation = crop.cultivation().to_string();
+        let crop_area_unit = crop.area_unit();
+        let crop_cultivation_id = crop.cultivation().id().unwrap();
         let crop_planted_at = crop.planted_at();
-        let crop_harvested_at = crop.harvested_at();
+        let crop_closed_at = crop.closed_at();
+        let crop_plot_id = crop.plot_id();
 
-        query!(
+        let updated = query!(
             r#"
             UPDATE crops
-            SET name = ?, area = ?, cultivation = ?, planted_at = ?, harvested_at = ?
-            WHERE id = ?
+            SET name = ?, area = ?, area_unit = ?, cultivation_id = ?, planted_at = ?, closed_at = ?, plot_id = ?, version = version + 1
+            WHERE id = ? AND version = ?
             "#,
             crop_name,
             crop_area,
-            crop_cultivation,
+            crop_area_unit,
+            crop_cultivation_id,
             crop_planted_at,
-            crop_harvested_at,
+            crop_closed_at,
+            crop_plot_id,
             id,
+            version,
         )
-        .execute(&*self.pool)
-        .await?;
+        .execute(&mut *connection)
+        .await?
+        .rows_affected();
+        if updated == 0 {
+            return Err(Self::stale(id));
+        }
+
+        crop.set_id(Some(id));
+        crop.set_version(version + 1);
 
         Ok(crop)
     }
 
-    pub async fn delete(&self, id: i64) -> Result<(), AppError> {
-        query!(
+    /// Closes the crop if it is still open and has no harvest after
+    /// `closed_at`, so a close or harvest made since it was read can't be
+    /// contradicted.
+    pub async fn close(
+        &self,
+        connection: &mut SqliteConnection,
+        id: i64,
+        closed_at: chrono::NaiveDate,
+    ) -> Result<(), AppError> {
+        let updated = query!(
             r#"
-            DELETE FROM crops
-            WHERE id = ?
+            UPDATE crops
+            SET closed_at = ?1, version = version + 1
+            WHERE id = ?2 AND closed_at IS NULL
+                AND NOT EXISTS (SELECT 1 FROM harvests WHERE crop_id = ?2 AND harvested_at > ?1)
             "#,
+            closed_at,
             id,
         )
-        .execute(&*self.pool)
-        .await?;
+        .execute(&mut *connection)
+        .await?
+        .rows_affected();
+        if updated == 0 {
+            let current = query!(
+                r#"
+                SELECT closed_at, (SELECT MAX(harvested_at) FROM harvests WHERE crop_id = ?1) AS "last_harvest: chrono::NaiveDate"
+                FROM crops
+                WHERE id = ?1
+                "#,
+                id,
+            )
+            .fetch_one(&mut *connection)
+            .await?;
+            if let Some(current) = current.closed_at {
+                return Err(Self::closed(id, current));
+            }
+            return Err(Self::harvested_after(
+                closed_at,
+                current.last_harvest.unwrap_or(closed_at),
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Marks the crop as deleted, keeping its row, if it is still at
+    /// `version`.
+    pub async fn delete(
+        &self,
+        connection: &mut SqliteConnection,
+        id: i64,
+        version: i64,
+        deleted_at: chrono::NaiveDateTime,
+    ) -> Result<(), AppError> {
+        let updated = query!(
+            r#"
+            UPDATE crops
+            SET deleted_at = ?, version = version + 1
+            WHERE id = ? AND version = ?
+            "#,
+            deleted_at,
+            id,
+            version,
+        )
+        .execute(&mut *connection)
+        .await?
+        .rows_affected();
+        if updated == 0 {
+            return Err(Self::stale(id));
+        }
+
+        Ok(())
+    }
+
+    /// Undoes the deletion of the crop, failing if it isn't deleted, so
+    /// concurrent restores don't both go through.
+    pub async fn restore(
+        &self,
+        connection: &mut SqliteConnection,
+        id: i64,
+    ) -> Result<(), AppError> {
+        let updated = query!(
+            r#"
+            UPDATE crops
+            SET deleted_at = NULL, version = version + 1
+            WHERE id = ? AND deleted_at IS NOT NULL
+            "#,
+            id,
+        )
+        .execute(&mut *connection)
+        .await?
+        .rows_affected();
+        if updated == 0 {
+            return Err(Self::not_deleted(id));
+        }
 
         Ok(())
     }
@@ -0,0 +1,239 @@
+use sqlx::{query, query_as, SqlitePool};
+
+use crate::{errors::AppError, models::Cultivation};
+
+#[derive(Debug)]
+pub struct CultivationDb {
+    id: i64,
+    name: String,
+    scientific_name: Option<String>,
+    variety: Option<String>,
+    cycle_days: Option<i64>,
+    shelf_life_days: Option<i64>,
+}
+
+impl From<CultivationDb> for Cultivation {
+    fn from(cultivation: CultivationDb) -> Self {
+        Cultivation::new(
+            Some(cultivation.id),
+            cultivation.name,
+            cultivation.scientific_name,
+            cultivation.variety,
+            cultivation.cycle_days,
+            cultivation.shelf_life_days,
+        )
+        .unwrap()
+    }
+}
+
+pub struct CultivationRepository {
+    pool: Box<SqlitePool>,
+}
+
+impl CultivationRepository {
+    pub fn new(pool: Box<SqlitePool>) -> Self {
+        Self { pool }
+    }
+
+    pub async fn list(&self) -> Result<Vec<Cultivation>, AppError> {
+        let cultivations = query_as!(
+            CultivationDb,
+            r#"
+            SELECT id, name, scientific_name, variety, cycle_days, shelf_life_days
+            FROM cultivations
+            ORDER BY name, variety
+            "#,
+        )
+        .fetch_all(&*self.pool)
+        .await?;
+
+        Ok(cultivations.into_iter().map(Cultivation::from).collect())
+    }
+
+    pub async fn find_by_id(&self, id: i64) -> Result<Cultivation, AppError> {
+        let cultivation = query_as!(
+            CultivationDb,
+            r#"
+            SELECT id, name, scientific_name, variety, cycle_days, shelf_life_days
+            FROM cultivations
+            WHERE id = ?
+            "#,
+            id
+        )
+        .fetch_optional(&*self.pool)
+        .await?;
+
+        match cultivation {
+            Some(cultivation) => Ok(cultivation.into()),
+            None => Err(AppError::NotFound(format!(
+                "Cultura de ID {} não encontrada",
+                id
+            ))),
+        }
+    }
+
+    /// Looks the name and variety up ignoring case.
+    pub async fn find_by_name(
+        &self,
+        name: &str,
+        variety: Option<&str>,
+    ) -> Result<Option<Cultivation>, AppError> {
+        let cultivation = query_as!(
+            CultivationDb,
+            r#"
+            SELECT id, name, scientific_name, variety, cycle_days, shelf_life_days
+            FROM cultivations
+            WHERE name = ?1 AND COALESCE(variety, '') = COALESCE(?2, '')
+            "#,
+            name,
+            variety
+        )
+        .fetch_optional(&*self.pool)
+        .await?;
+
+        Ok(cultivation.map(Cultivation::from))
+    }
+
+    pub async fn count_crops(&self, id: i64) -> Result<i64, AppError> {
+        let count = query!(
+            r#"
+            SELECT COUNT(*) as count
+            FROM crops
+            WHERE cultivation_id = ?
+            "#,
+            id
+        )
+        .fetch_one(&*self.pool)
+        .await?;
+
+        Ok(count.count)
+    }
+
+    pub async fn insert(&self, mut cultivation: Cultivation) -> Result<Cultivation, AppError> {
+        let name = cultivation.name().to_string();
+        let scientific_name = cultivation.scientific_name().clone();
+        let variety = cultivation.variety().clone();
+        let cycle_days = cultivation.cycle_days();
+        let shelf_life_days = cultivation.shelf_life_days();
+
+        let cultivation_id = query!(
+            r#"
+            INSERT INTO cultivations (name, scientific_name, variety, cycle_days, shelf_life_days)
+            VALUES (?, ?, ?, ?, ?)
+            RETURNING id
+            "#,
+            name,
+            scientific_name,
+            variety,
+            cycle_days,
+            shelf_life_days,
+        )
+        .fetch_one(&*self.pool)
+        .await?;
+
+        cultivation.set_id(Some(cultivation_id.id));
+
+        Ok(cultivation)
+    }
+
+    pub async fn update(
+        &self,
+        id: i64,
+        mut cultivation: Cultivation,
+    ) -> Result<Cultivation, AppError> {
+        let name = cultivation.name().to_string();
+        let scientific_name = cultivation.scientific_name().clone();
+        let variety = cultivation.variety().clone();
+        let cycle_days = cultivation.cycle_days();
+        let shelf_life_days = cultivation.shelf_life_days();
+
+        query!(
+            r#"
+            UPDATE cultivations
+            SET name = ?, scientific_name = ?, variety = ?, cycle_days = ?, shelf_life_days = ?
+            WHERE id = ?
+            "#,
+            name,
+            scientific_name,
+            variety,
+            cycle_days,
+            shelf_life_days,
+            id,
+        )
+        .execute(&*self.pool)
+        .await?;
+
+        cultivation.set_id(Some(id));
+
+        Ok(cultivation)
+    }
+
+    /// Inserts the new cultivations and updates the ones already registered
+    /// with the same name and variety, all or nothing. Returns how many were
+    /// inserted and updated.
+    pub async fn import(&self, cultivations: &[Cultivation]) -> Result<(usize, usize), AppError> {
+        let mut transaction = self.pool.begin().await?;
+        let (mut inserted, mut updated) = (0, 0);
+
+        for cultivation in cultivations {
+            let name = cultivation.name();
+            let scientific_name = cultivation.scientific_name().clone();
+            let variety = cultivation.variety().clone();
+            let cycle_days = cultivation.cycle_days();
+            let shelf_life_days = cultivation.shelf_life_days();
+
+            let result = query!(
+                r#"
+                UPDATE cultivations
+                SET scientific_name = ?3, cycle_days = ?4, shelf_life_days = ?5
+                WHERE name = ?1 AND COALESCE(variety, '') = COALESCE(?2, '')
+                "#,
+                name,
+                variety,
+                scientific_name,
+                cycle_days,
+                shelf_life_days,
+            )
+            .execute(&mut *transaction)
+            .await?;
+
+            if result.rows_affected() > 0 {
+                updated += 1;
+                continue;
+            }
+
+            query!(
+                r#"
+                INSERT INTO cultivations (name, scientific_name, variety, cycle_days, shelf_life_days)
+                VALUES (?, ?, ?, ?, ?)
+                "#,
+                name,
+                scientific_name,
+                variety,
+                cycle_days,
+                shelf_life_days,
+            )
+            .execute(&mut *transaction)
+            .await?;
+            inserted += 1;
+        }
+
+        transaction.commit().await?;
+
+        Ok((inserted, updated))
+    }
+
+    pub async fn delete(&self, id: i64) -> Result<(), AppError> {
+        query!(
+            r#"
+            DELETE FROM cultivations
+            WHERE id = ?
+            "#,
+            id,
+        )
+        .execute(&*self.pool)
+        .await?;
+
+        Ok(())
+    }
+}
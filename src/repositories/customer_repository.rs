@@ -0,0 +1,137 @@
+use sqlx::{query, query_as, SqlitePool};
+
+use crate::{errors::AppError, models::Customer};
+
+#[derive(Debug)]
+pub struct CustomerDb {
+    id: i64,
+    name: String,
+    document: Option<String>,
+    address: Option<String>,
+    email: Option<String>,
+}
+
+impl From<CustomerDb> for Customer {
+    fn from(customer: CustomerDb) -> Self {
+        Customer::new(
+            Some(customer.id),
+            customer.name,
+            customer.document,
+            customer.address,
+            customer.email,
+        )
+        .unwrap()
+    }
+}
+
+pub struct CustomerRepository {
+    pool: Box<SqlitePool>,
+}
+
+impl CustomerRepository {
+    pub fn new(pool: Box<SqlitePool>) -> Self {
+        Self { pool }
+    }
+
+    pub async fn list(&self) -> Result<Vec<Customer>, AppError> {
+        let customers = query_as!(
+            CustomerDb,
+            r#"
+            SELECT id, name, document, address, email
+            FROM customers
+            ORDER BY name
+            "#,
+        )
+        .fetch_all(&*self.pool)
+        .await?;
+
+        Ok(customers.into_iter().map(Customer::from).collect())
+    }
+
+    pub async fn find_by_id(&self, id: i64) -> Result<Customer, AppError> {
+        let customer = query_as!(
+            CustomerDb,
+            r#"
+            SELECT id, name, document, address, email
+            FROM customers
+            WHERE id = ?
+            "#,
+            id
+        )
+        .fetch_optional(&*self.pool)
+        .await?;
+
+        match customer {
+            Some(customer) => Ok(customer.into()),
+            None => Err(AppError::NotFound(format!(
+                "Cliente de ID {} não encontrado",
+                id
+            ))),
+        }
+    }
+
+    pub async fn insert(&self, mut customer: Customer) -> Result<Customer, AppError> {
+        let name = customer.name().to_string();
+        let document = customer.document().clone();
+        let address = customer.address().clone();
+        let email = customer.email().clone();
+
+        let customer_id = query!(
+            r#"
+            INSERT INTO customers (name, document, address, email)
+            VALUES (?, ?, ?, ?)
+            RETURNING id
+            "#,
+            name,
+            document,
+            address,
+            email,
+        )
+        .fetch_one(&*self.pool)
+        .await?;
+
+        customer.set_id(Some(customer_id.id));
+
+        Ok(customer)
+    }
+
+    pub async fn update(&self, id: i64, mut customer: Customer) -> Result<Customer, AppError> {
+        let name = customer.name().to_string();
+        let document = customer.document().clone();
+        let address = customer.address().clone();
+        let email = customer.email().clone();
+
+        query!(
+            r#"
+            UPDATE customers
+            SET name = ?, document = ?, address = ?, email = ?
+            WHERE id = ?
+            "#,
+            name,
+            document,
+            address,
+            email,
+            id,
+        )
+        .execute(&*self.pool)
+        .await?;
+
+        customer.set_id(Some(id));
+
+        Ok(customer)
+    }
+
+    pub async fn delete(&self, id: i64) -> Result<(), AppError> {
+        query!(
+            r#"
+            DELETE FROM customers
+            WHERE id = ?
+            "#,
+            id,
+        )
+        .execute(&*self.pool)
+        .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,77 @@
+use sqlx::{query, SqlitePool};
+
+use crate::{errors::AppError, models::EpcisEvent};
+
+pub struct EpcisRepository {
+    pool: Box<SqlitePool>,
+}
+
+impl EpcisRepository {
+    pub fn new(pool: Box<SqlitePool>) -> Self {
+        Self { pool }
+    }
+
+    /// Captured events dated between `from` and `to`, both inclusive, in the
+    /// time zone they were recorded in.
+    pub async fn list(
+        &self,
+        from: Option<chrono::NaiveDate>,
+        to: Option<chrono::NaiveDate>,
+    ) -> Result<Vec<EpcisEvent>, AppError> {
+        let events = query!(
+            r#"
+            SELECT document
+            FROM epcis_events
+            WHERE (?1 IS NULL OR event_date >= ?1) AND (?2 IS NULL OR event_date <= ?2)
+            ORDER BY event_date, id
+            "#,
+            from,
+            to
+        )
+        .fetch_all(&*self.pool)
+        .await?;
+
+        Ok(events
+            .into_iter()
+            .map(|event| serde_json::from_str(&event.document).unwrap())
+            .collect())
+    }
+
+    /// Stores `events` in a single transaction. Events whose `eventID` was
+    /// already captured are skipped; returns how many were stored and skipped.
+    pub async fn capture(&self, events: &[EpcisEvent]) -> Result<(usize, usize), AppError> {
+        let mut transaction = self.pool.begin().await?;
+        let (mut captured, mut duplicates) = (0, 0);
+
+        for event in events {
+            let event_id = event.event_id.clone();
+            let event_type = event.event_type;
+            let event_date = event.event_time.date_naive();
+            let document = serde_json::to_string(event).unwrap();
+
+            let result = query!(
+                r#"
+                INSERT INTO epcis_events (event_id, event_type, event_date, document)
+                VALUES (?, ?, ?, ?)
+                ON CONFLICT (event_id) DO NOTHING
+                "#,
+                event_id,
+                event_type,
+                event_date,
+                document,
+            )
+            .execute(&mut *transaction)
+            .await?;
+
+            if result.rows_affected() > 0 {
+                captured += 1;
+            } else {
+                duplicates += 1;
+            }
+        }
+
+        transaction.commit().await?;
+
+        Ok((captured, duplicates))
+    }
+}
@@ -0,0 +1,125 @@
+use sqlx::{query, query_as, SqlitePool};
+
+use crate::{errors::AppError, models::Farm};
+
+#[derive(Debug)]
+pub struct FarmDb {
+    id: i64,
+    name: String,
+    document: Option<String>,
+    address: Option<String>,
+}
+
+impl From<FarmDb> for Farm {
+    fn from(farm: FarmDb) -> Self {
+        Farm::new(Some(farm.id), farm.name, farm.document, farm.address).unwrap()
+    }
+}
+
+pub struct FarmRepository {
+    pool: Box<SqlitePool>,
+}
+
+impl FarmRepository {
+    pub fn new(pool: Box<SqlitePool>) -> Self {
+        Self { pool }
+    }
+
+    pub async fn list(&self) -> Result<Vec<Farm>, AppError> {
+        let farms = query_as!(
+            FarmDb,
+            r#"
+            SELECT id, name, document, address
+            FROM farms
+            ORDER BY name
+            "#,
+        )
+        .fetch_all(&*self.pool)
+        .await?;
+
+        Ok(farms.into_iter().map(Farm::from).collect())
+    }
+
+    pub async fn find_by_id(&self, id: i64) -> Result<Farm, AppError> {
+        let farm = query_as!(
+            FarmDb,
+            r#"
+            SELECT id, name, document, address
+            FROM farms
+            WHERE id = ?
+            "#,
+            id
+        )
+        .fetch_optional(&*self.pool)
+        .await?;
+
+        match farm {
+            Some(farm) => Ok(farm.into()),
+            None => Err(AppError::NotFound(format!(
+                "Fazenda de ID {} não encontrada",
+                id
+            ))),
+        }
+    }
+
+    pub async fn insert(&self, mut farm: Farm) -> Result<Farm, AppError> {
+        let name = farm.name().to_string();
+        let document = farm.document().clone();
+        let address = farm.address().clone();
+
+        let farm_id = query!(
+            r#"
+            INSERT INTO farms (name, document, address)
+            VALUES (?, ?, ?)
+            RETURNING id
+            "#,
+            name,
+            document,
+            address,
+        )
+        .fetch_one(&*self.pool)
+        .await?;
+
+        farm.set_id(Some(farm_id.id));
+
+        Ok(farm)
+    }
+
+    pub async fn update(&self, id: i64, mut farm: Farm) -> Result<Farm, AppError> {
+        let name = farm.name().to_string();
+        let document = farm.document().clone();
+        let address = farm.address().clone();
+
+        query!(
+            r#"
+            UPDATE farms
+            SET name = ?, document = ?, address = ?
+            WHERE id = ?
+            "#,
+            name,
+            document,
+            address,
+            id,
+        )
+        .execute(&*self.pool)
+        .await?;
+
+        farm.set_id(Some(id));
+
+        Ok(farm)
+    }
+
+    pub async fn delete(&self, id: i64) -> Result<(), AppError> {
+        query!(
+            r#"
+            DELETE FROM farms
+            WHERE id = ?
+            "#,
+            id,
+        )
+        .execute(&*self.pool)
+        .await?;
+
+        Ok(())
+    }
+}
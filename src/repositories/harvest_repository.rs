@@ -0,0 +1,176 @@
+use sqlx::{query, query_as, SqlitePool};
+
+use crate::{
+    errors::AppError,
+    models::{Harvest, QuantityUnit},
+};
+
+#[derive(Debug)]
+pub struct HarvestDb {
+    id: i64,
+    crop_id: i64,
+    harvested_at: chrono::NaiveDate,
+    quantity: f64,
+    quantity_unit: QuantityUnit,
+    team: Option<String>,
+}
+
+impl From<HarvestDb> for Harvest {
+    fn from(harvest: HarvestDb) -> Self {
+        Harvest::new(
+            Some(harvest.id),
+            harvest.crop_id,
+            harvest.harvested_at,
+            harvest.quantity,
+            harvest.quantity_unit,
+            harvest.team,
+        )
+        .unwrap()
+    }
+}
+
+pub struct HarvestRepository {
+    pool: Box<SqlitePool>,
+}
+
+impl HarvestRepository {
+    pub fn new(pool: Box<SqlitePool>) -> Self {
+        Self { pool }
+    }
+
+    pub async fn list(&self) -> Result<Vec<Harvest>, AppError> {
+        let harvests = query_as!(
+            HarvestDb,
+            r#"
+            SELECT id, crop_id, harvested_at, quantity, quantity_unit as "quantity_unit: QuantityUnit", team
+            FROM harvests
+            ORDER BY harvested_at, id
+            "#,
+        )
+        .fetch_all(&*self.pool)
+        .await?;
+
+        Ok(harvests.into_iter().map(Harvest::from).collect())
+    }
+
+    pub async fn list_by_crop_id(&self, crop_id: i64) -> Result<Vec<Harvest>, AppError> {
+        let harvests = query_as!(
+            HarvestDb,
+            r#"
+            SELECT id, crop_id, harvested_at, quantity, quantity_unit as "quantity_unit: QuantityUnit", team
+            FROM harvests
+            WHERE crop_id = ?
+            ORDER BY harvested_at, id
+            "#,
+            crop_id
+        )
+        .fetch_all(&*self.pool)
+        .await?;
+
+        Ok(harvests.into_iter().map(Harvest::from).collect())
+    }
+
+    /// Harvests the batch or any batch it descends from came from.
+    pub async fn find_by_batch_lineage(&self, batch_id: i64) -> Result<Vec<Harvest>, AppError> {
+        let harvests = query_as!(
+            HarvestDb,
+            r#"
+            WITH RECURSIVE ancestors(id) AS (
+                SELECT ?
+                UNION
+                SELECT l.parent_id
+                FROM batch_lineage l
+                INNER JOIN ancestors a ON l.child_id = a.id
+            )
+            SELECT DISTINCT h.id, h.crop_id, h.harvested_at, h.quantity, h.quantity_unit as "quantity_unit: QuantityUnit", h.team
+            FROM ancestors a
+            INNER JOIN batches b ON b.id = a.id
+            INNER JOIN harvests h ON h.id = b.harvest_id
+            ORDER BY h.harvested_at, h.id
+            "#,
+            batch_id
+        )
+        .fetch_all(&*self.pool)
+        .await?;
+
+        Ok(harvests.into_iter().map(Harvest::from).collect())
+    }
+
+    pub async fn find_by_id(&self, id: i64) -> Result<Harvest, AppError> {
+        let harvest = query_as!(
+            HarvestDb,
+            r#"
+            SELECT id, crop_id, harvested_at, quantity, quantity_unit as "quantity_unit: QuantityUnit", team
+            FROM harvests
+            WHERE id = ?
+            "#,
+            id
+        )
+        .fetch_optional(&*self.pool)
+        .await?;
+
+        match harvest {
+            Some(harvest) => Ok(harvest.into()),
+            None => Err(AppError::NotFound(format!(
+                "Colheita de ID {} não encontrada",
+                id
+            ))),
+        }
+    }
+
+    pub async fn count_batches(&self, id: i64) -> Result<i64, AppError> {
+        let count = query!(
+            r#"
+            SELECT COUNT(*) as count
+            FROM batches
+            WHERE harvest_id = ?
+            "#,
+            id
+        )
+        .fetch_one(&*self.pool)
+        .await?;
+
+        Ok(count.count)
+    }
+
+    pub async fn insert(&self, mut harvest: Harvest) -> Result<Harvest, AppError> {
+        let crop_id = harvest.crop_id();
+        let harvested_at = harvest.harvested_at();
+        let quantity = harvest.quantity();
+        let quantity_unit = harvest.quantity_unit();
+        let team = harvest.team().clone();
+
+        let harvest_id = query!(
+            r#"
+            INSERT INTO harvests (crop_id, harvested_at, quantity, quantity_unit, team)
+            VALUES (?, ?, ?, ?, ?)
+            RETURNING id
+            "#,
+            crop_id,
+            harvested_at,
+            quantity,
+            quantity_unit,
+            team,
+        )
+        .fetch_one(&*self.pool)
+        .await?;
+
+        harvest.set_id(Some(harvest_id.id));
+
+        Ok(harvest)
+    }
+
+    pub async fn delete(&self, id: i64) -> Result<(), AppError> {
+        query!(
+            r#"
+            DELETE FROM harvests
+            WHERE id = ?
+            "#,
+            id,
+        )
+        .execute(&*self.pool)
+        .await?;
+
+        Ok(())
+    }
+}
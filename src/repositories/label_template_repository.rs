@@ -0,0 +1,239 @@
+use sqlx::{query, query_as, SqliteConnection, SqlitePool};
+
+use crate::{
+    errors::AppError,
+    models::{LabelField, LabelTemplate},
+};
+
+#[derive(Debug)]
+pub struct LabelTemplateDb {
+    id: i64,
+    organization: String,
+    name: String,
+    page_width: f64,
+    page_height: f64,
+    font: String,
+    font_size: f64,
+    fields: String,
+    is_default: bool,
+}
+
+impl From<&LabelTemplateDb> for LabelTemplate {
+    fn from(template: &LabelTemplateDb) -> Self {
+        LabelTemplate::new(
+            Some(template.id),
+            template.organization.clone(),
+            template.name.clone(),
+            template.page_width,
+            template.page_height,
+            template.font.parse().unwrap(),
+            template.font_size,
+            template
+                .fields
+                .split(',')
+                .filter_map(|field| field.parse().ok())
+                .collect(),
+            template.is_default,
+        )
+        .unwrap()
+    }
+}
+
+fn join_fields(fields: &[LabelField]) -> String {
+    fields
+        .iter()
+        .map(LabelField::as_str)
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+pub struct LabelTemplateRepository {
+    pool: Box<SqlitePool>,
+}
+
+impl LabelTemplateRepository {
+    pub fn new(pool: Box<SqlitePool>) -> Self {
+        Self { pool }
+    }
+
+    pub async fn list(&self, organization: Option<String>) -> Result<Vec<LabelTemplate>, AppError> {
+        let templates = query_as!(
+            LabelTemplateDb,
+            r#"
+            SELECT id, organization, name, page_width, page_height, font, font_size, fields, is_default
+            FROM label_templates
+            WHERE ?1 IS NULL OR organization = ?1
+            ORDER BY organization, name
+            "#,
+            organization,
+        )
+        .fetch_all(&*self.pool)
+        .await?;
+
+        Ok(templates.iter().map(|template| template.into()).collect())
+    }
+
+    pub async fn find_by_id(&self, id: i64) -> Result<LabelTemplate, AppError> {
+        let template = query_as!(
+            LabelTemplateDb,
+            r#"
+            SELECT id, organization, name, page_width, page_height, font, font_size, fields, is_default
+            FROM label_templates
+            WHERE id = ?
+            "#,
+            id
+        )
+        .fetch_optional(&*self.pool)
+        .await?;
+
+        match template {
+            Some(template) => Ok((&template).into()),
+            None => Err(AppError::NotFound(format!(
+                "Modelo de etiqueta de ID {} não encontrado",
+                id
+            ))),
+        }
+    }
+
+    /// Default template of `organization`, if it has one.
+    pub async fn find_default(
+        &self,
+        organization: &str,
+    ) -> Result<Option<LabelTemplate>, AppError> {
+        let template = query_as!(
+            LabelTemplateDb,
+            r#"
+            SELECT id, organization, name, page_width, page_height, font, font_size, fields, is_default
+            FROM label_templates
+            WHERE organization = ? AND is_default
+            "#,
+            organization
+        )
+        .fetch_optional(&*self.pool)
+        .await?;
+
+        Ok(template.as_ref().map(LabelTemplate::from))
+    }
+
+    /// Makes the other templates of `organization` no longer its default.
+    async fn clear_default(
+        connection: &mut SqliteConnection,
+        organization: &str,
+        id: Option<i64>,
+    ) -> Result<(), AppError> {
+        query!(
+            r#"
+            UPDATE label_templates
+            SET is_default = FALSE
+            WHERE organization = ? AND id IS NOT ? AND is_default
+            "#,
+            organization,
+            id,
+        )
+        .execute(connection)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn insert(&self, mut template: LabelTemplate) -> Result<LabelTemplate, AppError> {
+        let organization = template.organization().to_string();
+        let name = template.name().to_string();
+        let page_width = template.page_width();
+        let page_height = template.page_height();
+        let font = template.font().as_str();
+        let font_size = template.font_size();
+        let fields = join_fields(template.fields());
+        let is_default = template.is_default();
+
+        let mut transaction = self.pool.begin().await?;
+
+        if is_default {
+            Self::clear_default(&mut transaction, &organization, None).await?;
+        }
+
+        let template_id = query!(
+            r#"
+            INSERT INTO label_templates (organization, name, page_width, page_height, font, font_size, fields, is_default)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+            RETURNING id
+            "#,
+            organization,
+            name,
+            page_width,
+            page_height,
+            font,
+            font_size,
+            fields,
+            is_default,
+        )
+        .fetch_one(&mut *transaction)
+        .await?;
+
+        transaction.commit().await?;
+
+        template.set_id(Some(template_id.id));
+
+        Ok(template)
+    }
+
+    pub async fn update(
+        &self,
+        id: i64,
+        mut template: LabelTemplate,
+    ) -> Result<LabelTemplate, AppError> {
+        let organization = template.organization().to_string();
+        let name = template.name().to_string();
+        let page_width = template.page_width();
+        let page_height = template.page_height();
+        let font = template.font().as_str();
+        let font_size = template.font_size();
+        let fields = join_fields(template.fields());
+        let is_default = template.is_default();
+
+        let mut transaction = self.pool.begin().await?;
+
+        if is_default {
+            Self::clear_default(&mut transaction, &organization, Some(id)).await?;
+        }
+
+        query!(
+            r#"
+            UPDATE label_templates
+            SET organization = ?, name = ?, page_width = ?, page_height = ?, font = ?, font_size = ?, fields = ?, is_default = ?
+            WHERE id = ?
+            "#,
+            organization,
+            name,
+            page_width,
+            page_height,
+            font,
+            font_size,
+            fields,
+            is_default,
+            id,
+        )
+        .execute(&mut *transaction)
+        .await?;
+
+        transaction.commit().await?;
+
+        template.set_id(Some(id));
+
+        Ok(template)
+    }
+
+    pub async fn delete(&self, id: i64) -> Result<(), AppError> {
+        query!(
+            r#"
+            DELETE FROM label_templates
+            WHERE id = ?
+            "#,
+            id,
+        )
+        .execute(&*self.pool)
+        .await?;
+
+        Ok(())
+    }
+}
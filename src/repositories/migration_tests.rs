@@ -0,0 +1,210 @@
+//! Migrations that move existing rows around, run against data inserted in
+//! the schema they start from.
+
+use sqlx::{query, query_scalar, SqlitePool};
+
+use super::CropRepository;
+
+async fn get_database_pool() -> Result<SqlitePool, String> {
+    SqlitePool::connect("sqlite::memory:")
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Applies the migrations older than `version`.
+async fn migrate_before(pool: &SqlitePool, version: i64) -> Result<(), String> {
+    let mut migrator = sqlx::migrate!("./migrations");
+    migrator.migrations = migrator
+        .migrations
+        .iter()
+        .filter(|migration| migration.version < version)
+        .cloned()
+        .collect::<Vec<_>>()
+        .into();
+    migrator.run(pool).await.map_err(|e| e.to_string())
+}
+
+async fn migrate(pool: &SqlitePool) -> Result<(), String> {
+    sqlx::migrate!("./migrations")
+        .run(pool)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tokio::test]
+async fn harvest_dates_become_harvests() -> Result<(), String> {
+    let pool = get_database_pool().await?;
+    migrate_before(&pool, 20240804120000).await?;
+    query(
+        r#"
+        INSERT INTO crops (id, name, area, cultivation, planted_at, harvested_at)
+        VALUES (1, 'Talhão 1', 2, 'Tomate', '2024-01-10', '2024-04-02'),
+            (2, 'Talhão 2', 2, 'Tomate', '2024-01-10', NULL);
+        INSERT INTO batches (crop_id, packing, quantity, tracking_code, date)
+        VALUES (1, 'Caixa', 10, 'A', '2024-04-02'),
+            (1, 'Caixa', 10, 'B', '2024-03-30'),
+            (2, 'Caixa', 10, 'C', '2024-04-02');
+        "#,
+    )
+    .execute(&pool)
+    .await
+    .map_err(|e| e.to_string())?;
+    migrate(&pool).await?;
+
+    let harvests: Vec<(i64, i64, String, f64)> =
+        sqlx::query_as("SELECT id, crop_id, harvested_at, quantity FROM harvests")
+            .fetch_all(&pool)
+            .await
+            .map_err(|e| e.to_string())?;
+    assert_eq!(harvests.len(), 1);
+    let (harvest_id, crop_id, harvested_at, quantity) = harvests[0].clone();
+    assert_eq!(
+        (crop_id, harvested_at.as_str(), quantity),
+        (1, "2024-04-02", 0.0)
+    );
+
+    let batches: Vec<(String, Option<i64>)> =
+        sqlx::query_as("SELECT tracking_code, harvest_id FROM batches ORDER BY tracking_code")
+            .fetch_all(&pool)
+            .await
+            .map_err(|e| e.to_string())?;
+    assert_eq!(
+        batches,
+        vec![
+            ("A".to_string(), Some(harvest_id)),
+            ("B".to_string(), None),
+            ("C".to_string(), None),
+        ]
+    );
+
+    let closed: i64 = query_scalar("SELECT COUNT(*) FROM crops WHERE closed_at IS NOT NULL")
+        .fetch_one(&pool)
+        .await
+        .map_err(|e| e.to_string())?;
+    assert_eq!(closed, 0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn batch_codes_differing_in_case_or_spacing_become_one_catalog_entry() -> Result<(), String> {
+    let pool = get_database_pool().await?;
+    migrate_before(&pool, 20240805120000).await?;
+    query(
+        r#"
+        INSERT INTO crops (id, name, area, cultivation, planted_at)
+        VALUES (1, 'Talhão 1', 2, 'Tomate', '2024-01-10');
+        INSERT INTO packings (name, kg_per_unit) VALUES ('Caixa 20 kg', 20);
+        INSERT INTO batches (crop_id, classification, processing, packing, quantity, tracking_code, date)
+        VALUES (1, 'Extra', 'Lavado', 'caixa 20kg', 10, 'A', '2024-04-02'),
+            (1, ' ex tra ', '  ', 'CAIXA 20 KG', 10, 'B', '2024-04-02'),
+            (1, 'Especial', NULL, ' Saco ', 10, 'C', '2024-04-02');
+        "#,
+    )
+    .execute(&pool)
+    .await
+    .map_err(|e| e.to_string())?;
+    migrate(&pool).await?;
+
+    let entries: Vec<(String, String)> =
+        sqlx::query_as("SELECT kind, code FROM catalog_entries ORDER BY kind, code")
+            .fetch_all(&pool)
+            .await
+            .map_err(|e| e.to_string())?;
+    assert_eq!(
+        entries,
+        vec![
+            ("classification".to_string(), "Especial".to_string()),
+            ("classification".to_string(), "Extra".to_string()),
+            ("processing".to_string(), "Lavado".to_string()),
+        ]
+    );
+
+    let packings: Vec<(String, Option<f64>)> =
+        sqlx::query_as("SELECT code, kg_per_unit FROM packings ORDER BY code")
+            .fetch_all(&pool)
+            .await
+            .map_err(|e| e.to_string())?;
+    assert_eq!(
+        packings,
+        vec![
+            ("Caixa 20 kg".to_string(), Some(20.0)),
+            ("Saco".to_string(), None),
+        ]
+    );
+
+    let batches: Vec<(Option<String>, Option<String>, String)> = sqlx::query_as(
+        "SELECT classification, processing, packing FROM batches ORDER BY tracking_code",
+    )
+    .fetch_all(&pool)
+    .await
+    .map_err(|e| e.to_string())?;
+    assert_eq!(
+        batches,
+        vec![
+            (
+                Some("Extra".to_string()),
+                Some("Lavado".to_string()),
+                "Caixa 20 kg".to_string()
+            ),
+            (Some("Extra".to_string()), None, "Caixa 20 kg".to_string()),
+            (Some("Especial".to_string()), None, "Saco".to_string()),
+        ]
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn blank_cultivations_become_a_placeholder_cultivation() -> Result<(), String> {
+    let pool = get_database_pool().await?;
+    migrate_before(&pool, 20240806120000).await?;
+    query(
+        r#"
+        INSERT INTO crops (name, area, cultivation, planted_at)
+        VALUES ('Talhão 1', 2, 'Tomate', '2024-01-10'),
+            ('Talhão 2', 2, ' tomate ', '2024-01-10'),
+            ('Talhão 3', 2, '', '2024-01-10'),
+            ('Talhão 4', 2, '   ', '2024-01-10');
+        "#,
+    )
+    .execute(&pool)
+    .await
+    .map_err(|e| e.to_string())?;
+    migrate(&pool).await?;
+
+    let crops: Vec<(String, String)> = sqlx::query_as(
+        r#"
+        SELECT c.name, v.name
+        FROM crops c
+        INNER JOIN cultivations v ON v.id = c.cultivation_id
+        ORDER BY c.name
+        "#,
+    )
+    .fetch_all(&pool)
+    .await
+    .map_err(|e| e.to_string())?;
+    assert_eq!(
+        crops,
+        vec![
+            ("Talhão 1".to_string(), "Tomate".to_string()),
+            ("Talhão 2".to_string(), "Tomate".to_string()),
+            ("Talhão 3".to_string(), "Não informada".to_string()),
+            ("Talhão 4".to_string(), "Não informada".to_string()),
+        ]
+    );
+
+    let cultivations: i64 = query_scalar("SELECT COUNT(*) FROM cultivations")
+        .fetch_one(&pool)
+        .await
+        .map_err(|e| e.to_string())?;
+    assert_eq!(cultivations, 2);
+
+    let crops = CropRepository::new(Box::new(pool))
+        .list(false)
+        .await
+        .map_err(|e| e.to_string())?;
+    assert_eq!(crops.len(), 4);
+
+    Ok(())
+}
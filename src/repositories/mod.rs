@@ -1,4 +1,31 @@
+mod audit_repository;
+mod batch_event_repository;
 mod batch_repository;
+mod catalog_repository;
+mod chemical_application_repository;
 mod crop_repository;
+mod cultivation_repository;
+mod customer_repository;
+mod epcis_repository;
+mod farm_repository;
+mod harvest_repository;
+mod label_template_repository;
+#[cfg(test)]
+mod migration_tests;
+mod packing_repository;
+mod plot_repository;
+mod product_repository;
+mod recall_repository;
+mod shipment_repository;
 
-pub use self::{batch_repository::BatchRepository, crop_repository::CropRepository};
+pub use self::{
+    audit_repository::AuditRepository, batch_event_repository::BatchEventRepository,
+    batch_repository::BatchRepository, catalog_repository::CatalogRepository,
+    chemical_application_repository::ChemicalApplicationRepository,
+    crop_repository::CropRepository, cultivation_repository::CultivationRepository,
+    customer_repository::CustomerRepository, epcis_repository::EpcisRepository,
+    farm_repository::FarmRepository, harvest_repository::HarvestRepository,
+    label_template_repository::LabelTemplateRepository, packing_repository::PackingRepository,
+    plot_repository::PlotRepository, product_repository::ProductRepository,
+    recall_repository::RecallRepository, shipment_repository::ShipmentRepository,
+};
@@ -0,0 +1,171 @@
+use sqlx::{query, query_as, SqlitePool};
+
+use crate::{errors::AppError, models::Packing};
+
+#[derive(Debug)]
+pub struct PackingDb {
+    id: i64,
+    code: String,
+    description: String,
+    kg_per_unit: Option<f64>,
+    active: bool,
+}
+
+impl From<PackingDb> for Packing {
+    fn from(packing: PackingDb) -> Self {
+        Packing::new(
+            Some(packing.id),
+            packing.code,
+            packing.description,
+            packing.kg_per_unit,
+            packing.active,
+        )
+        .unwrap()
+    }
+}
+
+pub struct PackingRepository {
+    pool: Box<SqlitePool>,
+}
+
+impl PackingRepository {
+    pub fn new(pool: Box<SqlitePool>) -> Self {
+        Self { pool }
+    }
+
+    pub async fn list(&self, active: Option<bool>) -> Result<Vec<Packing>, AppError> {
+        let packings = query_as!(
+            PackingDb,
+            r#"
+            SELECT id, code, description, kg_per_unit, active
+            FROM packings
+            WHERE ?1 IS NULL OR active = ?1
+            ORDER BY code
+            "#,
+            active
+        )
+        .fetch_all(&*self.pool)
+        .await?;
+
+        Ok(packings.into_iter().map(Packing::from).collect())
+    }
+
+    pub async fn find_by_id(&self, id: i64) -> Result<Packing, AppError> {
+        let packing = query_as!(
+            PackingDb,
+            r#"
+            SELECT id, code, description, kg_per_unit, active
+            FROM packings
+            WHERE id = ?
+            "#,
+            id
+        )
+        .fetch_optional(&*self.pool)
+        .await?;
+
+        match packing {
+            Some(packing) => Ok(packing.into()),
+            None => Err(AppError::NotFound(format!(
+                "Embalagem de ID {} não encontrada",
+                id
+            ))),
+        }
+    }
+
+    /// Looks the code up ignoring case.
+    pub async fn find_by_code(&self, code: &str) -> Result<Option<Packing>, AppError> {
+        let packing = query_as!(
+            PackingDb,
+            r#"
+            SELECT id, code, description, kg_per_unit, active
+            FROM packings
+            WHERE code = ?
+            "#,
+            code
+        )
+        .fetch_optional(&*self.pool)
+        .await?;
+
+        Ok(packing.map(Packing::from))
+    }
+
+    pub async fn count_batches(&self, code: &str) -> Result<i64, AppError> {
+        let count = query!(
+            r#"
+            SELECT COUNT(*) as count
+            FROM batches
+            WHERE packing = ?
+            "#,
+            code
+        )
+        .fetch_one(&*self.pool)
+        .await?;
+
+        Ok(count.count)
+    }
+
+    pub async fn insert(&self, mut packing: Packing) -> Result<Packing, AppError> {
+        let code = packing.code().to_string();
+        let description = packing.description().to_string();
+        let kg_per_unit = packing.kg_per_unit();
+        let active = packing.active();
+
+        let packing_id = query!(
+            r#"
+            INSERT INTO packings (code, description, kg_per_unit, active)
+            VALUES (?, ?, ?, ?)
+            RETURNING id
+            "#,
+            code,
+            description,
+            kg_per_unit,
+            active,
+        )
+        .fetch_one(&*self.pool)
+        .await?;
+
+        packing.set_id(Some(packing_id.id));
+
+        Ok(packing)
+    }
+
+    pub async fn update(&self, id: i64, mut packing: Packing) -> Result<Packing, AppError> {
+        let code = packing.code().to_string();
+        let description = packing.description().to_string();
+        let kg_per_unit = packing.kg_per_unit();
+        let active = packing.active();
+
+        query!(
+            r#"
+            UPDATE packings
+            SET code = ?, description = ?, kg_per_unit = ?, active = ?
+            WHERE id = ?
+            "#,
+            code,
+            description,
+            kg_per_unit,
+            active,
+            id,
+        )
+        .execute(&*self.pool)
+        .await?;
+
+        packing.set_id(Some(id));
+
+        Ok(packing)
+    }
+
+    pub async fn delete(&self, id: i64) -> Result<(), AppError> {
+        query!(
+            r#"
+            DELETE FROM packings
+            WHERE id = ?
+            "#,
+            id,
+        )
+        .execute(&*self.pool)
+        .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,135 @@
+use sqlx::{query, query_as, SqlitePool};
+
+use crate::{
+    errors::AppError,
+    models::{Plot, PlotBoundary},
+};
+
+#[derive(Debug)]
+pub struct PlotDb {
+    id: i64,
+    farm_id: i64,
+    name: String,
+    boundary: String,
+}
+
+impl From<PlotDb> for Plot {
+    fn from(plot: PlotDb) -> Self {
+        let boundary: PlotBoundary = serde_json::from_str(&plot.boundary).unwrap();
+        Plot::new(Some(plot.id), plot.farm_id, plot.name, boundary).unwrap()
+    }
+}
+
+pub struct PlotRepository {
+    pool: Box<SqlitePool>,
+}
+
+impl PlotRepository {
+    pub fn new(pool: Box<SqlitePool>) -> Self {
+        Self { pool }
+    }
+
+    pub async fn list(&self, farm_id: Option<i64>) -> Result<Vec<Plot>, AppError> {
+        let plots = query_as!(
+            PlotDb,
+            r#"
+            SELECT id, farm_id, name, boundary
+            FROM plots
+            WHERE ?1 IS NULL OR farm_id = ?1
+            ORDER BY farm_id, name
+            "#,
+            farm_id
+        )
+        .fetch_all(&*self.pool)
+        .await?;
+
+        Ok(plots.into_iter().map(Plot::from).collect())
+    }
+
+    pub async fn find_by_id(&self, id: i64) -> Result<Plot, AppError> {
+        let plot = query_as!(
+            PlotDb,
+            r#"
+            SELECT id, farm_id, name, boundary
+            FROM plots
+            WHERE id = ?
+            "#,
+            id
+        )
+        .fetch_optional(&*self.pool)
+        .await?;
+
+        match plot {
+            Some(plot) => Ok(plot.into()),
+            None => Err(AppError::NotFound(format!(
+                "Talhão de ID {} não encontrado",
+                id
+            ))),
+        }
+    }
+
+    pub async fn insert(&self, mut plot: Plot) -> Result<Plot, AppError> {
+        let farm_id = plot.farm_id();
+        let name = plot.name().to_string();
+        let boundary = serde_json::to_string(plot.boundary()).unwrap();
+        let area = plot.area();
+
+        let plot_id = query!(
+            r#"
+            INSERT INTO plots (farm_id, name, boundary, area)
+            VALUES (?, ?, ?, ?)
+            RETURNING id
+            "#,
+            farm_id,
+            name,
+            boundary,
+            area,
+        )
+        .fetch_one(&*self.pool)
+        .await?;
+
+        plot.set_id(Some(plot_id.id));
+
+        Ok(plot)
+    }
+
+    pub async fn update(&self, id: i64, mut plot: Plot) -> Result<Plot, AppError> {
+        let farm_id = plot.farm_id();
+        let name = plot.name().to_string();
+        let boundary = serde_json::to_string(plot.boundary()).unwrap();
+        let area = plot.area();
+
+        query!(
+            r#"
+            UPDATE plots
+            SET farm_id = ?, name = ?, boundary = ?, area = ?
+            WHERE id = ?
+            "#,
+            farm_id,
+            name,
+            boundary,
+            area,
+            id,
+        )
+        .execute(&*self.pool)
+        .await?;
+
+        plot.set_id(Some(id));
+
+        Ok(plot)
+    }
+
+    pub async fn delete(&self, id: i64) -> Result<(), AppError> {
+        query!(
+            r#"
+            DELETE FROM plots
+            WHERE id = ?
+            "#,
+            id,
+        )
+        .execute(&*self.pool)
+        .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,163 @@
+use sqlx::{query, query_as, SqlitePool};
+
+use crate::{errors::AppError, models::Product};
+
+#[derive(Debug)]
+pub struct ProductDb {
+    id: i64,
+    gtin: String,
+    name: String,
+    description: Option<String>,
+}
+
+impl From<ProductDb> for Product {
+    fn from(product: ProductDb) -> Self {
+        Product::new(
+            Some(product.id),
+            product.gtin,
+            product.name,
+            product.description,
+        )
+        .unwrap()
+    }
+}
+
+pub struct ProductRepository {
+    pool: Box<SqlitePool>,
+}
+
+impl ProductRepository {
+    pub fn new(pool: Box<SqlitePool>) -> Self {
+        Self { pool }
+    }
+
+    pub async fn list(&self) -> Result<Vec<Product>, AppError> {
+        let products = query_as!(
+            ProductDb,
+            r#"
+            SELECT id, gtin, name, description
+            FROM products
+            ORDER BY name
+            "#,
+        )
+        .fetch_all(&*self.pool)
+        .await?;
+
+        Ok(products.into_iter().map(Product::from).collect())
+    }
+
+    pub async fn find_by_id(&self, id: i64) -> Result<Product, AppError> {
+        let product = query_as!(
+            ProductDb,
+            r#"
+            SELECT id, gtin, name, description
+            FROM products
+            WHERE id = ?
+            "#,
+            id
+        )
+        .fetch_optional(&*self.pool)
+        .await?;
+
+        match product {
+            Some(product) => Ok(product.into()),
+            None => Err(AppError::NotFound(format!(
+                "Produto de ID {} não encontrado",
+                id
+            ))),
+        }
+    }
+
+    /// `gtin` must be in its 14 digit form.
+    pub async fn find_by_gtin(&self, gtin: &str) -> Result<Option<Product>, AppError> {
+        let product = query_as!(
+            ProductDb,
+            r#"
+            SELECT id, gtin, name, description
+            FROM products
+            WHERE gtin = ?
+            "#,
+            gtin
+        )
+        .fetch_optional(&*self.pool)
+        .await?;
+
+        Ok(product.map(Product::from))
+    }
+
+    pub async fn count_batches(&self, id: i64) -> Result<i64, AppError> {
+        let count = query!(
+            r#"
+            SELECT COUNT(*) as count
+            FROM batches
+            WHERE product_id = ?
+            "#,
+            id
+        )
+        .fetch_one(&*self.pool)
+        .await?;
+
+        Ok(count.count)
+    }
+
+    pub async fn insert(&self, mut product: Product) -> Result<Product, AppError> {
+        let gtin = product.gtin().to_string();
+        let name = product.name().to_string();
+        let description = product.description().clone();
+
+        let product_id = query!(
+            r#"
+            INSERT INTO products (gtin, name, description)
+            VALUES (?, ?, ?)
+            RETURNING id
+            "#,
+            gtin,
+            name,
+            description,
+        )
+        .fetch_one(&*self.pool)
+        .await?;
+
+        product.set_id(Some(product_id.id));
+
+        Ok(product)
+    }
+
+    pub async fn update(&self, id: i64, mut product: Product) -> Result<Product, AppError> {
+        let gtin = product.gtin().to_string();
+        let name = product.name().to_string();
+        let description = product.description().clone();
+
+        query!(
+            r#"
+            UPDATE products
+            SET gtin = ?, name = ?, description = ?
+            WHERE id = ?
+            "#,
+            gtin,
+            name,
+            description,
+            id,
+        )
+        .execute(&*self.pool)
+        .await?;
+
+        product.set_id(Some(id));
+
+        Ok(product)
+    }
+
+    pub async fn delete(&self, id: i64) -> Result<(), AppError> {
+        query!(
+            r#"
+            DELETE FROM products
+            WHERE id = ?
+            "#,
+            id,
+        )
+        .execute(&*self.pool)
+        .await?;
+
+        Ok(())
+    }
+}
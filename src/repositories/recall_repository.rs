@@ -0,0 +1,177 @@
+use sqlx::{query, query_as, SqlitePool};
+
+use crate::{
+    errors::AppError,
+    models::{Recall, RecallStatus},
+};
+
+#[derive(Debug)]
+pub struct RecallDb {
+    id: i64,
+    crop_id: Option<i64>,
+    batch_id: Option<i64>,
+    cultivation: Option<String>,
+    start_date: Option<chrono::NaiveDate>,
+    end_date: Option<chrono::NaiveDate>,
+    reason: String,
+    status: RecallStatus,
+    created_at: chrono::NaiveDateTime,
+}
+
+impl From<RecallDb> for Recall {
+    fn from(recall: RecallDb) -> Self {
+        Recall::new(
+            Some(recall.id),
+            recall.crop_id,
+            recall.batch_id,
+            recall.cultivation,
+            recall.start_date,
+            recall.end_date,
+            recall.reason,
+            recall.status,
+            recall.created_at,
+        )
+        .unwrap()
+    }
+}
+
+pub struct RecallRepository {
+    pool: Box<SqlitePool>,
+}
+
+impl RecallRepository {
+    pub fn new(pool: Box<SqlitePool>) -> Self {
+        Self { pool }
+    }
+
+    async fn find_batch_ids(&self, recall_id: i64) -> Result<Vec<i64>, AppError> {
+        let batches = query!(
+            r#"
+            SELECT batch_id
+            FROM recall_batches
+            WHERE recall_id = ?
+            ORDER BY batch_id
+            "#,
+            recall_id
+        )
+        .fetch_all(&*self.pool)
+        .await?;
+
+        Ok(batches.into_iter().map(|batch| batch.batch_id).collect())
+    }
+
+    pub async fn list(&self) -> Result<Vec<Recall>, AppError> {
+        let recalls = query_as!(
+            RecallDb,
+            r#"
+            SELECT id, crop_id, batch_id, cultivation, start_date, end_date, reason, status as "status: RecallStatus", created_at
+            FROM recalls
+            ORDER BY created_at DESC, id DESC
+            "#,
+        )
+        .fetch_all(&*self.pool)
+        .await?;
+
+        let mut result = Vec::with_capacity(recalls.len());
+        for recall in recalls {
+            let mut recall = Recall::from(recall);
+            recall.set_batch_ids(self.find_batch_ids(recall.id().unwrap()).await?);
+            result.push(recall);
+        }
+
+        Ok(result)
+    }
+
+    pub async fn find_by_id(&self, id: i64) -> Result<Recall, AppError> {
+        let recall = query_as!(
+            RecallDb,
+            r#"
+            SELECT id, crop_id, batch_id, cultivation, start_date, end_date, reason, status as "status: RecallStatus", created_at
+            FROM recalls
+            WHERE id = ?
+            "#,
+            id
+        )
+        .fetch_optional(&*self.pool)
+        .await?;
+
+        match recall {
+            Some(recall) => {
+                let mut recall = Recall::from(recall);
+                recall.set_batch_ids(self.find_batch_ids(id).await?);
+                Ok(recall)
+            }
+            None => Err(AppError::NotFound(format!(
+                "Recall de ID {} não encontrado",
+                id
+            ))),
+        }
+    }
+
+    pub async fn insert(&self, mut recall: Recall) -> Result<Recall, AppError> {
+        let crop_id = recall.crop_id();
+        let batch_id = recall.batch_id();
+        let cultivation = recall.cultivation().clone();
+        let start_date = recall.start_date();
+        let end_date = recall.end_date();
+        let reason = recall.reason().to_string();
+        let status = recall.status();
+        let created_at = recall.created_at();
+
+        let mut transaction = self.pool.begin().await?;
+
+        let recall_id = query!(
+            r#"
+            INSERT INTO recalls (crop_id, batch_id, cultivation, start_date, end_date, reason, status, created_at)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+            RETURNING id
+            "#,
+            crop_id,
+            batch_id,
+            cultivation,
+            start_date,
+            end_date,
+            reason,
+            status,
+            created_at,
+        )
+        .fetch_one(&mut *transaction)
+        .await?
+        .id;
+
+        for batch_id in recall.batch_ids() {
+            query!(
+                r#"
+                INSERT INTO recall_batches (recall_id, batch_id)
+                VALUES (?, ?)
+                "#,
+                recall_id,
+                batch_id,
+            )
+            .execute(&mut *transaction)
+            .await?;
+        }
+
+        transaction.commit().await?;
+
+        recall.set_id(Some(recall_id));
+
+        Ok(recall)
+    }
+
+    pub async fn update_status(&self, id: i64, status: RecallStatus) -> Result<(), AppError> {
+        query!(
+            r#"
+            UPDATE recalls
+            SET status = ?
+            WHERE id = ?
+            "#,
+            status,
+            id,
+        )
+        .execute(&*self.pool)
+        .await?;
+
+        Ok(())
+    }
+}
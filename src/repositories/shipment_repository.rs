@@ -0,0 +1,316 @@
+use sqlx::{query, query_as, SqliteConnection, SqlitePool};
+
+use crate::{
+    errors::AppError,
+    models::{Shipment, ShipmentItem},
+};
+
+#[derive(Debug)]
+pub struct ShipmentDb {
+    id: i64,
+    customer_id: i64,
+    dispatched_at: chrono::NaiveDate,
+    invoice_number: String,
+}
+
+#[derive(Debug)]
+pub struct ShipmentItemDb {
+    id: i64,
+    shipment_id: i64,
+    batch_id: i64,
+    quantity: f64,
+}
+
+/// Attaches the rows of `items` to the shipments they belong to.
+fn assemble(shipments: Vec<ShipmentDb>, items: Vec<ShipmentItemDb>) -> Vec<Shipment> {
+    shipments
+        .into_iter()
+        .map(|shipment| {
+            let shipment_items = items
+                .iter()
+                .filter(|item| item.shipment_id == shipment.id)
+                .map(|item| ShipmentItem::new(Some(item.id), item.batch_id, item.quantity).unwrap())
+                .collect();
+
+            Shipment::new(
+                Some(shipment.id),
+                shipment.customer_id,
+                shipment.dispatched_at,
+                shipment.invoice_number,
+                shipment_items,
+            )
+            .unwrap()
+        })
+        .collect()
+}
+
+pub struct ShipmentRepository {
+    pool: Box<SqlitePool>,
+}
+
+impl ShipmentRepository {
+    pub fn new(pool: Box<SqlitePool>) -> Self {
+        Self { pool }
+    }
+
+    /// Shipments dispatched between `from` and `to`, both inclusive.
+    pub async fn list(
+        &self,
+        from: Option<chrono::NaiveDate>,
+        to: Option<chrono::NaiveDate>,
+    ) -> Result<Vec<Shipment>, AppError> {
+        let shipments = query_as!(
+            ShipmentDb,
+            r#"
+            SELECT id, customer_id, dispatched_at, invoice_number
+            FROM shipments
+            WHERE (?1 IS NULL OR dispatched_at >= ?1) AND (?2 IS NULL OR dispatched_at <= ?2)
+            ORDER BY dispatched_at, id
+            "#,
+            from,
+            to
+        )
+        .fetch_all(&*self.pool)
+        .await?;
+
+        let items = query_as!(
+            ShipmentItemDb,
+            r#"
+            SELECT i.id, i.shipment_id, i.batch_id, i.quantity
+            FROM shipment_items i
+            INNER JOIN shipments s ON s.id = i.shipment_id
+            WHERE (?1 IS NULL OR s.dispatched_at >= ?1) AND (?2 IS NULL OR s.dispatched_at <= ?2)
+            ORDER BY i.id
+            "#,
+            from,
+            to
+        )
+        .fetch_all(&*self.pool)
+        .await?;
+
+        Ok(assemble(shipments, items))
+    }
+
+    pub async fn find_by_id(&self, id: i64) -> Result<Shipment, AppError> {
+        let shipment = query_as!(
+            ShipmentDb,
+            r#"
+            SELECT id, customer_id, dispatched_at, invoice_number
+            FROM shipments
+            WHERE id = ?
+            "#,
+            id
+        )
+        .fetch_optional(&*self.pool)
+        .await?;
+
+        let Some(shipment) = shipment else {
+            return Err(AppError::NotFound(format!(
+                "Remessa de ID {} não encontrada",
+                id
+            )));
+        };
+
+        let items = query_as!(
+            ShipmentItemDb,
+            r#"
+            SELECT id, shipment_id, batch_id, quantity
+            FROM shipment_items
+            WHERE shipment_id = ?
+            ORDER BY id
+            "#,
+            id
+        )
+        .fetch_all(&*self.pool)
+        .await?;
+
+        Ok(assemble(vec![shipment], items).remove(0))
+    }
+
+    /// Shipments with at least one item taken from batch `batch_id`.
+    pub async fn find_by_batch_id(&self, batch_id: i64) -> Result<Vec<Shipment>, AppError> {
+        let shipments = query_as!(
+            ShipmentDb,
+            r#"
+            SELECT id, customer_id, dispatched_at, invoice_number
+            FROM shipments
+            WHERE id IN (SELECT shipment_id FROM shipment_items WHERE batch_id = ?)
+            ORDER BY dispatched_at, id
+            "#,
+            batch_id
+        )
+        .fetch_all(&*self.pool)
+        .await?;
+
+        let items = query_as!(
+            ShipmentItemDb,
+            r#"
+            SELECT id, shipment_id, batch_id, quantity
+            FROM shipment_items
+            WHERE shipment_id IN (SELECT shipment_id FROM shipment_items WHERE batch_id = ?)
+            ORDER BY id
+            "#,
+            batch_id
+        )
+        .fetch_all(&*self.pool)
+        .await?;
+
+        Ok(assemble(shipments, items))
+    }
+
+    pub async fn count_by_customer_id(&self, customer_id: i64) -> Result<i64, AppError> {
+        let count = query!(
+            r#"
+            SELECT COUNT(*) as count
+            FROM shipments
+            WHERE customer_id = ?
+            "#,
+            customer_id
+        )
+        .fetch_one(&*self.pool)
+        .await?;
+
+        Ok(count.count)
+    }
+
+    /// Inserts each item only if its batch is not deleted and still has the
+    /// quantity available, counting the items already inserted in the same transaction, so
+    /// concurrent shipments can't over-ship a batch.
+    async fn insert_items(
+        connection: &mut SqliteConnection,
+        shipment_id: i64,
+        items: &mut [ShipmentItem],
+    ) -> Result<(), AppError> {
+        for item in items.iter_mut() {
+            let batch_id = item.batch_id();
+            let quantity = item.quantity();
+
+            let item_id = query!(
+                r#"
+                INSERT INTO shipment_items (shipment_id, batch_id, quantity)
+                SELECT ?1, ?2, ?3
+                WHERE (SELECT quantity FROM batches WHERE id = ?2 AND deleted_at IS NULL)
+                    - (SELECT COALESCE(SUM(quantity), 0) FROM shipment_items WHERE batch_id = ?2) >= ?3
+                RETURNING id
+                "#,
+                shipment_id,
+                batch_id,
+                quantity,
+            )
+            .fetch_optional(&mut *connection)
+            .await?;
+
+            let Some(item_id) = item_id else {
+                return Err(AppError::BadRequest(format!(
+                    "A quantidade expedida do lote de ID {batch_id} ({quantity}) excede a quantidade disponível"
+                )));
+            };
+
+            item.set_id(Some(item_id.id));
+        }
+
+        Ok(())
+    }
+
+    pub async fn insert(&self, mut shipment: Shipment) -> Result<Shipment, AppError> {
+        let customer_id = shipment.customer_id();
+        let dispatched_at = shipment.dispatched_at();
+        let invoice_number = shipment.invoice_number().to_string();
+
+        let mut transaction = self.pool.begin().await?;
+
+        let shipment_id = query!(
+            r#"
+            INSERT INTO shipments (customer_id, dispatched_at, invoice_number)
+            VALUES (?, ?, ?)
+            RETURNING id
+            "#,
+            customer_id,
+            dispatched_at,
+            invoice_number,
+        )
+        .fetch_one(&mut *transaction)
+        .await?
+        .id;
+
+        let mut items = shipment.items().to_vec();
+        Self::insert_items(&mut transaction, shipment_id, &mut items).await?;
+
+        transaction.commit().await?;
+
+        shipment.set_id(Some(shipment_id));
+        shipment.set_items(items);
+
+        Ok(shipment)
+    }
+
+    pub async fn update(&self, id: i64, mut shipment: Shipment) -> Result<Shipment, AppError> {
+        let customer_id = shipment.customer_id();
+        let dispatched_at = shipment.dispatched_at();
+        let invoice_number = shipment.invoice_number().to_string();
+
+        let mut transaction = self.pool.begin().await?;
+
+        query!(
+            r#"
+            UPDATE shipments
+            SET customer_id = ?, dispatched_at = ?, invoice_number = ?
+            WHERE id = ?
+            "#,
+            customer_id,
+            dispatched_at,
+            invoice_number,
+            id,
+        )
+        .execute(&mut *transaction)
+        .await?;
+
+        query!(
+            r#"
+            DELETE FROM shipment_items
+            WHERE shipment_id = ?
+            "#,
+            id,
+        )
+        .execute(&mut *transaction)
+        .await?;
+
+        let mut items = shipment.items().to_vec();
+        Self::insert_items(&mut transaction, id, &mut items).await?;
+
+        transaction.commit().await?;
+
+        shipment.set_id(Some(id));
+        shipment.set_items(items);
+
+        Ok(shipment)
+    }
+
+    pub async fn delete(&self, id: i64) -> Result<(), AppError> {
+        let mut transaction = self.pool.begin().await?;
+
+        query!(
+            r#"
+            DELETE FROM shipment_items
+            WHERE shipment_id = ?
+            "#,
+            id,
+        )
+        .execute(&mut *transaction)
+        .await?;
+
+        query!(
+            r#"
+            DELETE FROM shipments
+            WHERE id = ?
+            "#,
+            id,
+        )
+        .execute(&mut *transaction)
+        .await?;
+
+        transaction.commit().await?;
+
+        Ok(())
+    }
+}
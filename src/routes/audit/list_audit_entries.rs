@@ -0,0 +1,28 @@
+use axum::{debug_handler, extract::Query, Json};
+
+use crate::{
+    dtos::{AuditEntryResponseDTO, AuditQueryDTO},
+    errors::AppError,
+    services::AuditService,
+};
+
+#[cfg(debug_assertions)]
+use crate::AppState;
+
+#[debug_handler(state = AppState)]
+pub async fn list_audit_entries(
+    audit_service: AuditService,
+    query: Query<AuditQueryDTO>,
+) -> Result<Json<Vec<AuditEntryResponseDTO>>, AppError> {
+    if query.id.is_some() && query.entity.is_none() {
+        return Err(AppError::BadRequest(
+            "Informe a entidade junto com o ID".to_string(),
+        ));
+    }
+
+    let entries = audit_service.list(query.entity, query.id).await?;
+
+    Ok(Json(
+        entries.iter().map(AuditEntryResponseDTO::from).collect(),
+    ))
+}
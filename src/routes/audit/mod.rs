@@ -0,0 +1,3 @@
+mod list_audit_entries;
+
+pub use self::list_audit_entries::list_audit_entries;
@@ -1,6 +1,6 @@
-use axum::{debug_handler, extract::Path, Json};
+use axum::{debug_handler, extract::Path, http::HeaderMap, Json};
 
-use crate::{errors::AppError, services::BatchService};
+use crate::{errors::AppError, misc::etag::IfMatch, services::BatchService};
 
 #[cfg(debug_assertions)]
 use crate::AppState;
@@ -9,8 +9,32 @@ use crate::AppState;
 pub async fn delete_batch(
     batch_service: BatchService,
     id: Path<i64>,
+    headers: HeaderMap,
 ) -> Result<Json<()>, AppError> {
-    batch_service.delete(*id).await?;
+    batch_service
+        .delete(*id, &IfMatch::from_headers(&headers)?)
+        .await?;
 
     Ok(Json(()))
 }
+
+#[cfg(test)]
+mod tests {
+    use sqlx::SqlitePool;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn delete_without_if_match_must_be_refused() -> Result<(), String> {
+        let pool = SqlitePool::connect("sqlite::memory:")
+            .await
+            .map_err(|e| e.to_string())?;
+
+        let result =
+            delete_batch(BatchService::new(Box::new(pool)), Path(1), HeaderMap::new()).await;
+
+        assert!(matches!(result, Err(AppError::PreconditionRequired(_))));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,70 @@
+use async_stream::try_stream;
+use axum::{
+    body::Body,
+    debug_handler,
+    extract::Query,
+    http::{
+        header::{CONTENT_DISPOSITION, CONTENT_TYPE},
+        HeaderValue,
+    },
+    response::{IntoResponse, Response},
+};
+use futures::{pin_mut, TryStreamExt};
+
+use crate::{
+    dtos::{BatchExportQueryDTO, ExportFormat},
+    errors::AppError,
+    misc::export::{batch_cells, csv_body, csv_line, XlsxExport, BATCH_COLUMNS},
+    services::BatchService,
+};
+
+#[cfg(debug_assertions)]
+use crate::AppState;
+
+/// CSV is sent as the batches are read. XLSX rows are written to a temporary
+/// file and the workbook is sent once complete.
+#[debug_handler(state = AppState)]
+pub async fn export_batches(
+    batch_service: BatchService,
+    query: Query<BatchExportQueryDTO>,
+) -> Result<Response, AppError> {
+    let (unit, include_deleted) = (query.unit, query.include_deleted);
+    let (content_type, extension, body) = match query.format {
+        ExportFormat::Csv => {
+            let body = csv_body(try_stream! {
+                yield csv_line(BATCH_COLUMNS)?;
+                let batches = batch_service.stream(unit, include_deleted);
+                pin_mut!(batches);
+                while let Some(batch) = batches.try_next().await? {
+                    yield csv_line(batch_cells(&batch))?;
+                }
+            });
+            ("text/csv; charset=utf-8", "csv", body)
+        }
+        ExportFormat::Xlsx => {
+            let mut xlsx = XlsxExport::new("Lotes", &BATCH_COLUMNS)?;
+            let batches = batch_service.stream(unit, include_deleted);
+            pin_mut!(batches);
+            while let Some(batch) = batches.try_next().await? {
+                xlsx.push(batch_cells(&batch))?;
+            }
+            (
+                "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet",
+                "xlsx",
+                Body::from(xlsx.finish()?),
+            )
+        }
+    };
+
+    Ok((
+        [
+            (CONTENT_TYPE, HeaderValue::from_static(content_type)),
+            (
+                CONTENT_DISPOSITION,
+                HeaderValue::from_str(&format!("attachment; filename=\"lotes.{extension}\""))?,
+            ),
+        ],
+        body,
+    )
+        .into_response())
+}
@@ -0,0 +1,38 @@
+use axum::{
+    debug_handler,
+    extract::{Path, Query},
+    http::{
+        header::{ACCEPT, CONTENT_TYPE},
+        HeaderMap, HeaderValue,
+    },
+    response::{IntoResponse, Response},
+};
+
+use crate::{
+    dtos::CodeImageQueryDTO, errors::AppError, misc::codes::ImageFormat, services::LabelService,
+};
+
+#[cfg(debug_assertions)]
+use crate::AppState;
+
+#[debug_handler(state = AppState)]
+pub async fn get_batch_barcode(
+    label_service: LabelService,
+    id: Path<i64>,
+    query: Query<CodeImageQueryDTO>,
+    headers: HeaderMap,
+) -> Result<Response, AppError> {
+    let format = query.format.unwrap_or_else(|| {
+        ImageFormat::from_accept(headers.get(ACCEPT).and_then(|value| value.to_str().ok()))
+    });
+    let image = label_service.barcode(*id, format).await?;
+
+    Ok((
+        [(
+            CONTENT_TYPE,
+            HeaderValue::from_static(format.content_type()),
+        )],
+        image,
+    )
+        .into_response())
+}
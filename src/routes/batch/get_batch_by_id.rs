@@ -1,6 +1,17 @@
-use axum::{debug_handler, extract::Path, Json};
+use axum::{
+    debug_handler,
+    extract::{Path, Query},
+    http::header::ETAG,
+    response::{IntoResponse, Response},
+    Json,
+};
 
-use crate::{dtos::BatchResponseDTO, errors::AppError, services::BatchService};
+use crate::{
+    dtos::{BatchResponseDTO, QuantityUnitQueryDTO},
+    errors::AppError,
+    misc::etag::etag,
+    services::BatchService,
+};
 
 #[cfg(debug_assertions)]
 use crate::AppState;
@@ -9,16 +20,16 @@ use crate::AppState;
 pub async fn find_batch_by_id(
     batch_service: BatchService,
     id: Path<i64>,
-) -> Result<Json<BatchResponseDTO>, AppError> {
-    let batch = batch_service.find_by_id(*id).await?;
-    let batch_dto = BatchResponseDTO {
-        id: batch.id().unwrap(),
-        crop: batch.crop().id().unwrap(),
-        classification: batch.classification().clone(),
-        processing: batch.processing().clone(),
-        packing: batch.packing().to_string(),
-        quantity: batch.quantity(),
-        tracking_code: batch.tracking_code().as_ref().unwrap().to_string(),
+    query: Query<QuantityUnitQueryDTO>,
+) -> Result<Response, AppError> {
+    let mut batch = if query.include_deleted {
+        batch_service.find_by_id_including_deleted(*id).await?
+    } else {
+        batch_service.find_by_id(*id).await?
     };
-    Ok(Json(batch_dto))
+    if let Some(unit) = query.unit {
+        batch = batch_service.convert(batch, unit).await?;
+    }
+    let batch_dto = BatchResponseDTO::from(&batch);
+    Ok(([(ETAG, etag(batch.version())?)], Json(batch_dto)).into_response())
 }
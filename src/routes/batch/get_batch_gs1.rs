@@ -0,0 +1,25 @@
+use axum::{debug_handler, extract::Path, Json};
+
+use crate::{
+    dtos::Gs1ResponseDTO, errors::AppError, misc::gs1::element_string, services::LabelService,
+};
+
+#[cfg(debug_assertions)]
+use crate::AppState;
+
+#[debug_handler(state = AppState)]
+pub async fn get_batch_gs1(
+    label_service: LabelService,
+    id: Path<i64>,
+) -> Result<Json<Gs1ResponseDTO>, AppError> {
+    let (product, batch) = label_service.gs1_lot(*id).await?;
+    let lot = batch.tracking_code().clone().unwrap();
+
+    Ok(Json(Gs1ResponseDTO {
+        gtin: product.gtin().to_string(),
+        element_string: element_string(product.gtin(), &lot, batch.date()),
+        digital_link: label_service.digital_link(product.gtin(), &lot),
+        pack_date: batch.date(),
+        lot,
+    }))
+}
@@ -0,0 +1,26 @@
+use axum::{
+    debug_handler,
+    extract::{Path, Query},
+    http::{header::CONTENT_TYPE, HeaderValue},
+    response::{IntoResponse, Response},
+};
+
+use crate::{dtos::LabelQueryDTO, errors::AppError, services::LabelService};
+
+#[cfg(debug_assertions)]
+use crate::AppState;
+
+#[debug_handler(state = AppState)]
+pub async fn get_batch_label_pdf(
+    label_service: LabelService,
+    id: Path<i64>,
+    query: Query<LabelQueryDTO>,
+) -> Result<Response, AppError> {
+    let pdf = label_service.label_pdf(*id, query.template).await?;
+
+    Ok((
+        [(CONTENT_TYPE, HeaderValue::from_static("application/pdf"))],
+        pdf,
+    )
+        .into_response())
+}
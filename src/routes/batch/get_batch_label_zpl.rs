@@ -0,0 +1,29 @@
+use axum::{
+    debug_handler,
+    extract::{Path, Query},
+    http::{header::CONTENT_TYPE, HeaderValue},
+    response::{IntoResponse, Response},
+};
+
+use crate::{dtos::LabelQueryDTO, errors::AppError, services::LabelService};
+
+#[cfg(debug_assertions)]
+use crate::AppState;
+
+#[debug_handler(state = AppState)]
+pub async fn get_batch_label_zpl(
+    label_service: LabelService,
+    id: Path<i64>,
+    query: Query<LabelQueryDTO>,
+) -> Result<Response, AppError> {
+    let zpl = label_service.label_zpl(*id, query.template).await?;
+
+    Ok((
+        [(
+            CONTENT_TYPE,
+            HeaderValue::from_static("text/plain; charset=utf-8"),
+        )],
+        zpl,
+    )
+        .into_response())
+}
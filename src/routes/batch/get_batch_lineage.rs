@@ -0,0 +1,44 @@
+use axum::{
+    debug_handler,
+    extract::{Path, Query},
+    http::{header::CONTENT_TYPE, HeaderValue},
+    response::{IntoResponse, Response},
+    Json,
+};
+
+use crate::{
+    dtos::{LineageFormat, LineageGraphDTO, LineageQueryDTO},
+    errors::AppError,
+    misc::lineage::render_dot,
+    models::LineageDirection,
+    services::BatchService,
+};
+
+#[cfg(debug_assertions)]
+use crate::AppState;
+
+const DEFAULT_DEPTH: i64 = 10;
+
+#[debug_handler(state = AppState)]
+pub async fn get_batch_lineage(
+    batch_service: BatchService,
+    id: Path<i64>,
+    query: Query<LineageQueryDTO>,
+) -> Result<Response, AppError> {
+    let direction = query.direction.unwrap_or(LineageDirection::Up);
+    let depth = query.depth.unwrap_or(DEFAULT_DEPTH);
+
+    let lineage = batch_service.lineage(*id, direction, depth).await?;
+
+    match query.format.unwrap_or(LineageFormat::Json) {
+        LineageFormat::Json => Ok(Json(LineageGraphDTO::from(&lineage)).into_response()),
+        LineageFormat::Dot => Ok((
+            [(
+                CONTENT_TYPE,
+                HeaderValue::from_static("text/vnd.graphviz; charset=utf-8"),
+            )],
+            render_dot(&lineage),
+        )
+            .into_response()),
+    }
+}
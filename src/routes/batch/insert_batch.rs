@@ -28,19 +28,14 @@ pub async fn insert_batch(
         body.processing.clone(),
         body.packing.clone(),
         body.quantity,
+        body.quantity_unit,
         None,
         body.date,
+        body.harvest_id,
+        body.product_id,
     )?;
 
     let batch = batch_service.insert(batch).await?;
 
-    Ok(Json(BatchResponseDTO {
-        id: batch.id().unwrap(),
-        crop: batch.crop().id().unwrap(),
-        classification: batch.classification().clone(),
-        processing: batch.processing().clone(),
-        packing: batch.packing().to_string(),
-        quantity: batch.quantity(),
-        tracking_code: batch.tracking_code().as_ref().unwrap().to_string(),
-    }))
+    Ok(Json(BatchResponseDTO::from(&batch)))
 }
@@ -1,6 +1,10 @@
-use axum::{debug_handler, Json};
+use axum::{debug_handler, extract::Query, Json};
 
-use crate::{dtos::BatchResponseDTO, errors::AppError, services::BatchService};
+use crate::{
+    dtos::{BatchResponseDTO, QuantityUnitQueryDTO},
+    errors::AppError,
+    services::BatchService,
+};
 
 #[cfg(debug_assertions)]
 use crate::AppState;
@@ -8,19 +12,19 @@ use crate::AppState;
 #[debug_handler(state = AppState)]
 pub async fn list_batches(
     batch_service: BatchService,
+    query: Query<QuantityUnitQueryDTO>,
 ) -> Result<Json<Vec<BatchResponseDTO>>, AppError> {
-    let batches = batch_service.list().await?;
+    let mut batches = batch_service.list(query.include_deleted).await?;
+    if let Some(unit) = query.unit {
+        let mut converted = Vec::with_capacity(batches.len());
+        for batch in batches {
+            converted.push(batch_service.convert(batch, unit).await?);
+        }
+        batches = converted;
+    }
     let batches_dto = batches
         .iter()
-        .map(|batch| BatchResponseDTO {
-            id: batch.id().unwrap(),
-            crop: batch.crop().id().unwrap(),
-            classification: batch.classification().clone(),
-            processing: batch.processing().clone(),
-            packing: batch.packing().to_string(),
-            quantity: batch.quantity(),
-            tracking_code: batch.tracking_code().as_ref().unwrap().to_string(),
-        })
+        .map(BatchResponseDTO::from)
         .collect::<Vec<BatchResponseDTO>>();
     Ok(Json(batches_dto))
 }
@@ -0,0 +1,46 @@
+use axum::{debug_handler, Json};
+use validator::Validate;
+
+use crate::{
+    dtos::{BatchMergeRequestDTO, BatchResponseDTO},
+    errors::AppError,
+    models::Batch,
+    services::BatchService,
+};
+
+#[cfg(debug_assertions)]
+use crate::AppState;
+
+#[debug_handler(state = AppState)]
+pub async fn merge_batches(
+    batch_service: BatchService,
+    body: Json<BatchMergeRequestDTO>,
+) -> Result<Json<BatchResponseDTO>, AppError> {
+    body.validate()?;
+
+    let first = batch_service.find_by_id(body.sources[0].batch_id).await?;
+
+    let batch = Batch::new(
+        None,
+        first.crop().clone(),
+        body.classification.clone(),
+        body.processing.clone(),
+        body.packing.clone(),
+        0.0,
+        first.quantity_unit(),
+        None,
+        body.date,
+        None,
+        body.product_id,
+    )?;
+
+    let sources = body
+        .sources
+        .iter()
+        .map(|source| (source.batch_id, source.quantity))
+        .collect();
+
+    let batch = batch_service.merge(batch, sources).await?;
+
+    Ok(Json(BatchResponseDTO::from(&batch)))
+}
@@ -1,10 +1,29 @@
 mod delete_batch;
+mod export_batches;
+mod get_batch_barcode;
 mod get_batch_by_id;
+mod get_batch_gs1;
+mod get_batch_gs1_128;
+mod get_batch_label_pdf;
+mod get_batch_label_zpl;
+mod get_batch_lineage;
+mod get_batch_qrcode;
 mod insert_batch;
 mod list_batches;
+mod merge_batches;
+mod resolve_digital_link;
+mod restore_batch;
+mod split_batch;
+mod track_batch;
 mod update_batch;
 
 pub use self::{
-    delete_batch::delete_batch, get_batch_by_id::find_batch_by_id, insert_batch::insert_batch,
-    list_batches::list_batches, update_batch::update_batch,
+    delete_batch::delete_batch, export_batches::export_batches,
+    get_batch_barcode::get_batch_barcode, get_batch_by_id::find_batch_by_id,
+    get_batch_gs1::get_batch_gs1, get_batch_gs1_128::get_batch_gs1_128,
+    get_batch_label_pdf::get_batch_label_pdf, get_batch_label_zpl::get_batch_label_zpl,
+    get_batch_lineage::get_batch_lineage, get_batch_qrcode::get_batch_qrcode,
+    insert_batch::insert_batch, list_batches::list_batches, merge_batches::merge_batches,
+    resolve_digital_link::resolve_digital_link, restore_batch::restore_batch,
+    split_batch::split_batch, track_batch::track_batch, update_batch::update_batch,
 };
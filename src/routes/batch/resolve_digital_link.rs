@@ -0,0 +1,21 @@
+use axum::{
+    debug_handler,
+    extract::Path,
+    response::{IntoResponse, Redirect, Response},
+};
+
+use crate::{errors::AppError, services::LabelService};
+
+#[cfg(debug_assertions)]
+use crate::AppState;
+
+/// Sends a scanned GS1 Digital Link to the lot's tracking page.
+#[debug_handler(state = AppState)]
+pub async fn resolve_digital_link(
+    label_service: LabelService,
+    Path((gtin, lot)): Path<(String, String)>,
+) -> Result<Response, AppError> {
+    let url = label_service.resolve_digital_link(&gtin, &lot).await?;
+
+    Ok(Redirect::temporary(&url).into_response())
+}
@@ -0,0 +1,16 @@
+use axum::{debug_handler, extract::Path, Json};
+
+use crate::{dtos::BatchResponseDTO, errors::AppError, services::BatchService};
+
+#[cfg(debug_assertions)]
+use crate::AppState;
+
+#[debug_handler(state = AppState)]
+pub async fn restore_batch(
+    batch_service: BatchService,
+    id: Path<i64>,
+) -> Result<Json<BatchResponseDTO>, AppError> {
+    let batch = batch_service.restore(*id).await?;
+
+    Ok(Json(BatchResponseDTO::from(&batch)))
+}
@@ -0,0 +1,51 @@
+use axum::{debug_handler, extract::Path, Json};
+use validator::Validate;
+
+use crate::{
+    dtos::{BatchResponseDTO, BatchSplitRequestDTO, BatchSplitResponseDTO},
+    errors::AppError,
+    models::Batch,
+    services::BatchService,
+};
+
+#[cfg(debug_assertions)]
+use crate::AppState;
+
+#[debug_handler(state = AppState)]
+pub async fn split_batch(
+    batch_service: BatchService,
+    id: Path<i64>,
+    body: Json<BatchSplitRequestDTO>,
+) -> Result<Json<BatchSplitResponseDTO>, AppError> {
+    body.validate()?;
+
+    let parent = batch_service.find_by_id(*id).await?;
+
+    let children = body
+        .children
+        .iter()
+        .map(|child| {
+            Batch::new(
+                None,
+                parent.crop().clone(),
+                parent.classification().clone(),
+                parent.processing().clone(),
+                child.packing.clone(),
+                child.quantity,
+                parent.quantity_unit(),
+                None,
+                parent.date(),
+                parent.harvest_id(),
+                parent.product_id(),
+            )
+        })
+        .collect::<Result<Vec<Batch>, _>>()?;
+
+    let children = batch_service.split(*id, children).await?;
+    let parent = batch_service.find_by_id(*id).await?;
+
+    Ok(Json(BatchSplitResponseDTO {
+        parent: BatchResponseDTO::from(&parent),
+        children: children.iter().map(BatchResponseDTO::from).collect(),
+    }))
+}
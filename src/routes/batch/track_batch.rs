@@ -0,0 +1,106 @@
+use axum::{debug_handler, extract::Path, Json};
+
+use crate::{
+    dtos::{
+        BatchResponseDTO, CropResponseDTO, PlotResponseDTO, TrackingEventDTO, TrackingResponseDTO,
+    },
+    errors::AppError,
+    services::{BatchEventService, BatchService, HarvestService, PlotService},
+};
+
+#[cfg(debug_assertions)]
+use crate::AppState;
+
+#[debug_handler(state = AppState)]
+pub async fn track_batch(
+    batch_service: BatchService,
+    batch_event_service: BatchEventService,
+    plot_service: PlotService,
+    harvest_service: HarvestService,
+    code: Path<String>,
+) -> Result<Json<TrackingResponseDTO>, AppError> {
+    let batch = batch_service.find_by_tracking_code(&code).await?;
+    let crop = batch.crop();
+
+    let mut plots: Vec<PlotResponseDTO> = vec![];
+    for plot_id in batch.origins().iter().filter_map(|origin| origin.plot_id()) {
+        if !plots.iter().any(|plot| plot.id == plot_id) {
+            plots.push(PlotResponseDTO::from(
+                &plot_service.find_by_id(plot_id).await?,
+            ));
+        }
+    }
+
+    let mut events = vec![];
+    for origin in batch.origins() {
+        events.push(TrackingEventDTO {
+            event_type: "planting".to_string(),
+            date: origin.planted_at(),
+            description: format!(
+                "Plantio de {} ({})",
+                origin.name(),
+                origin.cultivation().full_name()
+            ),
+            ..Default::default()
+        });
+        if let Some(closed_at) = origin.closed_at() {
+            events.push(TrackingEventDTO {
+                event_type: "closing".to_string(),
+                date: *closed_at,
+                description: format!("Encerramento do plantio {}", origin.name()),
+                ..Default::default()
+            });
+        }
+    }
+    let origins = batch.origins();
+    for harvest in harvest_service
+        .find_by_batch_lineage(batch.id().unwrap())
+        .await?
+    {
+        let name = origins
+            .iter()
+            .find(|origin| *origin.id() == Some(harvest.crop_id()))
+            .map_or(crop.name(), |origin| origin.name());
+        events.push(TrackingEventDTO {
+            event_type: "harvest".to_string(),
+            date: harvest.harvested_at(),
+            description: format!(
+                "Colheita de {} ({} {})",
+                name,
+                harvest.quantity(),
+                harvest.quantity_unit().as_str()
+            ),
+            operator: harvest.team().clone(),
+            ..Default::default()
+        });
+    }
+    events.push(TrackingEventDTO {
+        event_type: "batch".to_string(),
+        date: batch.date(),
+        description: format!("Lote embalado em {}", batch.packing()),
+        ..Default::default()
+    });
+    for event in batch_event_service.list(batch.id().unwrap()).await? {
+        events.push(TrackingEventDTO {
+            event_type: event.event_type().as_str().to_string(),
+            date: event.occurred_at().date(),
+            occurred_at: Some(event.occurred_at()),
+            description: event.event_type().description().to_string(),
+            operator: event.operator().clone(),
+            notes: event.notes().clone(),
+        });
+    }
+    events.sort_by_key(|event| (event.date, event.occurred_at));
+
+    Ok(Json(TrackingResponseDTO {
+        batch: BatchResponseDTO::from(&batch),
+        crop: CropResponseDTO::from(crop),
+        origins: batch
+            .origins()
+            .into_iter()
+            .map(CropResponseDTO::from)
+            .collect(),
+        plots,
+        events,
+    }))
+}
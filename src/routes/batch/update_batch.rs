@@ -1,9 +1,16 @@
-use axum::{debug_handler, extract::Path, Json};
+use axum::{
+    debug_handler,
+    extract::Path,
+    http::{header::ETAG, HeaderMap},
+    response::{IntoResponse, Response},
+    Json,
+};
 use validator::Validate;
 
 use crate::{
     dtos::{BatchRequestDTO, BatchResponseDTO},
     errors::AppError,
+    misc::etag::{etag, IfMatch},
     models::Batch,
     services::{BatchService, CropService},
 };
@@ -16,8 +23,10 @@ pub async fn update_batch(
     batch_service: BatchService,
     crop_service: CropService,
     id: Path<i64>,
+    headers: HeaderMap,
     body: Json<BatchRequestDTO>,
-) -> Result<Json<BatchResponseDTO>, AppError> {
+) -> Result<Response, AppError> {
+    let if_match = IfMatch::from_headers(&headers)?;
     body.validate()?;
 
     let crop = crop_service.find_by_id(body.crop_id).await?;
@@ -29,19 +38,52 @@ pub async fn update_batch(
         body.processing.clone(),
         body.packing.clone(),
         body.quantity,
+        body.quantity_unit,
         None,
         body.date,
+        body.harvest_id,
+        body.product_id,
     )?;
 
-    let batch = batch_service.update(*id, &batch).await?;
-
-    Ok(Json(BatchResponseDTO {
-        id: batch.id().unwrap(),
-        crop: batch.crop().id().unwrap(),
-        classification: batch.classification().clone(),
-        processing: batch.processing().clone(),
-        packing: batch.packing().to_string(),
-        quantity: batch.quantity(),
-        tracking_code: batch.tracking_code().as_ref().unwrap().to_string(),
-    }))
+    let batch = batch_service.update(*id, &batch, &if_match).await?;
+
+    Ok((
+        [(ETAG, etag(batch.version())?)],
+        Json(BatchResponseDTO::from(&batch)),
+    )
+        .into_response())
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlx::SqlitePool;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn update_without_if_match_must_be_refused() -> Result<(), String> {
+        let pool = SqlitePool::connect("sqlite::memory:")
+            .await
+            .map_err(|e| e.to_string())?;
+        let body = serde_json::from_value(serde_json::json!({
+            "cropId": 1,
+            "packing": "Caixa",
+            "quantity": 10.0,
+            "date": "2024-01-01"
+        }))
+        .map_err(|e| e.to_string())?;
+
+        let result = update_batch(
+            BatchService::new(Box::new(pool.clone())),
+            CropService::new(Box::new(pool)),
+            Path(1),
+            HeaderMap::new(),
+            Json(body),
+        )
+        .await;
+
+        assert!(matches!(result, Err(AppError::PreconditionRequired(_))));
+
+        Ok(())
+    }
 }
@@ -0,0 +1,34 @@
+use axum::{debug_handler, extract::Path, Json};
+use validator::Validate;
+
+use crate::{
+    dtos::{BatchEventRequestDTO, BatchEventResponseDTO},
+    errors::AppError,
+    models::BatchEvent,
+    services::BatchEventService,
+};
+
+#[cfg(debug_assertions)]
+use crate::AppState;
+
+#[debug_handler(state = AppState)]
+pub async fn insert_batch_event(
+    batch_event_service: BatchEventService,
+    batch_id: Path<i64>,
+    body: Json<BatchEventRequestDTO>,
+) -> Result<Json<BatchEventResponseDTO>, AppError> {
+    body.validate()?;
+
+    let event = BatchEvent::new(
+        None,
+        *batch_id,
+        body.event_type,
+        body.occurred_at,
+        body.operator.clone(),
+        body.notes.clone(),
+    )?;
+
+    let event = batch_event_service.insert(&event).await?;
+
+    Ok(Json(BatchEventResponseDTO::from(&event)))
+}
@@ -0,0 +1,18 @@
+use axum::{debug_handler, extract::Path, Json};
+
+use crate::{dtos::BatchEventResponseDTO, errors::AppError, services::BatchEventService};
+
+#[cfg(debug_assertions)]
+use crate::AppState;
+
+#[debug_handler(state = AppState)]
+pub async fn list_batch_events(
+    batch_event_service: BatchEventService,
+    batch_id: Path<i64>,
+) -> Result<Json<Vec<BatchEventResponseDTO>>, AppError> {
+    let events = batch_event_service.list(*batch_id).await?;
+
+    Ok(Json(
+        events.iter().map(BatchEventResponseDTO::from).collect(),
+    ))
+}
@@ -0,0 +1,4 @@
+mod insert_batch_event;
+mod list_batch_events;
+
+pub use self::{insert_batch_event::insert_batch_event, list_batch_events::list_batch_events};
@@ -0,0 +1,16 @@
+use axum::{debug_handler, extract::Path, Json};
+
+use crate::{errors::AppError, models::CatalogKind, services::CatalogService};
+
+#[cfg(debug_assertions)]
+use crate::AppState;
+
+#[debug_handler(state = AppState)]
+pub async fn delete_catalog_entry(
+    catalog_service: CatalogService,
+    Path((kind, id)): Path<(CatalogKind, i64)>,
+) -> Result<Json<()>, AppError> {
+    catalog_service.delete(kind, id).await?;
+
+    Ok(Json(()))
+}
@@ -0,0 +1,18 @@
+use axum::{debug_handler, extract::Path, Json};
+
+use crate::{
+    dtos::CatalogEntryResponseDTO, errors::AppError, models::CatalogKind, services::CatalogService,
+};
+
+#[cfg(debug_assertions)]
+use crate::AppState;
+
+#[debug_handler(state = AppState)]
+pub async fn find_catalog_entry_by_id(
+    catalog_service: CatalogService,
+    Path((kind, id)): Path<(CatalogKind, i64)>,
+) -> Result<Json<CatalogEntryResponseDTO>, AppError> {
+    let entry = catalog_service.find_by_id(kind, id).await?;
+
+    Ok(Json(CatalogEntryResponseDTO::from(&entry)))
+}
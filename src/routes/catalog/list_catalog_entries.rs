@@ -0,0 +1,28 @@
+use axum::{
+    debug_handler,
+    extract::{Path, Query},
+    Json,
+};
+
+use crate::{
+    dtos::{CatalogEntryResponseDTO, CatalogQueryDTO},
+    errors::AppError,
+    models::CatalogKind,
+    services::CatalogService,
+};
+
+#[cfg(debug_assertions)]
+use crate::AppState;
+
+#[debug_handler(state = AppState)]
+pub async fn list_catalog_entries(
+    catalog_service: CatalogService,
+    kind: Path<CatalogKind>,
+    query: Query<CatalogQueryDTO>,
+) -> Result<Json<Vec<CatalogEntryResponseDTO>>, AppError> {
+    let entries = catalog_service.list(*kind, query.active).await?;
+
+    Ok(Json(
+        entries.iter().map(CatalogEntryResponseDTO::from).collect(),
+    ))
+}
@@ -0,0 +1,11 @@
+mod delete_catalog_entry;
+mod find_catalog_entry_by_id;
+mod insert_catalog_entry;
+mod list_catalog_entries;
+mod update_catalog_entry;
+
+pub use self::{
+    delete_catalog_entry::delete_catalog_entry, find_catalog_entry_by_id::find_catalog_entry_by_id,
+    insert_catalog_entry::insert_catalog_entry, list_catalog_entries::list_catalog_entries,
+    update_catalog_entry::update_catalog_entry,
+};
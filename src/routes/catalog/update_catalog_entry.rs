@@ -0,0 +1,33 @@
+use axum::{debug_handler, extract::Path, Json};
+use validator::Validate;
+
+use crate::{
+    dtos::{CatalogEntryRequestDTO, CatalogEntryResponseDTO},
+    errors::AppError,
+    models::{CatalogEntry, CatalogKind},
+    services::CatalogService,
+};
+
+#[cfg(debug_assertions)]
+use crate::AppState;
+
+#[debug_handler(state = AppState)]
+pub async fn update_catalog_entry(
+    catalog_service: CatalogService,
+    Path((kind, id)): Path<(CatalogKind, i64)>,
+    body: Json<CatalogEntryRequestDTO>,
+) -> Result<Json<CatalogEntryResponseDTO>, AppError> {
+    body.validate()?;
+
+    let entry = CatalogEntry::new(
+        None,
+        kind,
+        body.code.clone(),
+        body.description.clone(),
+        body.active,
+    )?;
+
+    let entry = catalog_service.update(id, &entry).await?;
+
+    Ok(Json(CatalogEntryResponseDTO::from(&entry)))
+}
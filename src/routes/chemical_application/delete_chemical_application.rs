@@ -0,0 +1,16 @@
+use axum::{debug_handler, extract::Path, Json};
+
+use crate::{errors::AppError, services::ChemicalApplicationService};
+
+#[cfg(debug_assertions)]
+use crate::AppState;
+
+#[debug_handler(state = AppState)]
+pub async fn delete_chemical_application(
+    chemical_application_service: ChemicalApplicationService,
+    Path((crop_id, id)): Path<(i64, i64)>,
+) -> Result<Json<()>, AppError> {
+    chemical_application_service.delete(crop_id, id).await?;
+
+    Ok(Json(()))
+}
@@ -0,0 +1,36 @@
+use axum::{debug_handler, extract::Path, Json};
+use validator::Validate;
+
+use crate::{
+    dtos::{ChemicalApplicationRequestDTO, ChemicalApplicationResponseDTO},
+    errors::AppError,
+    models::ChemicalApplication,
+    services::ChemicalApplicationService,
+};
+
+#[cfg(debug_assertions)]
+use crate::AppState;
+
+#[debug_handler(state = AppState)]
+pub async fn insert_chemical_application(
+    chemical_application_service: ChemicalApplicationService,
+    crop_id: Path<i64>,
+    body: Json<ChemicalApplicationRequestDTO>,
+) -> Result<Json<ChemicalApplicationResponseDTO>, AppError> {
+    body.validate()?;
+
+    let application = ChemicalApplication::new(
+        None,
+        *crop_id,
+        body.product.clone(),
+        body.active_ingredient.clone(),
+        body.dose,
+        body.dose_unit.clone(),
+        body.applied_at,
+        body.withdrawal_days,
+    )?;
+
+    let application = chemical_application_service.insert(&application).await?;
+
+    Ok(Json(ChemicalApplicationResponseDTO::from(&application)))
+}
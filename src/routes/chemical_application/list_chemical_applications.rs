@@ -0,0 +1,23 @@
+use axum::{debug_handler, extract::Path, Json};
+
+use crate::{
+    dtos::ChemicalApplicationResponseDTO, errors::AppError, services::ChemicalApplicationService,
+};
+
+#[cfg(debug_assertions)]
+use crate::AppState;
+
+#[debug_handler(state = AppState)]
+pub async fn list_chemical_applications(
+    chemical_application_service: ChemicalApplicationService,
+    crop_id: Path<i64>,
+) -> Result<Json<Vec<ChemicalApplicationResponseDTO>>, AppError> {
+    let applications = chemical_application_service.list(*crop_id).await?;
+
+    Ok(Json(
+        applications
+            .iter()
+            .map(ChemicalApplicationResponseDTO::from)
+            .collect(),
+    ))
+}
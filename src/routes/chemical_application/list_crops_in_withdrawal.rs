@@ -0,0 +1,27 @@
+use axum::{debug_handler, extract::Query, Json};
+use chrono::Local;
+
+use crate::{
+    dtos::{CropWithdrawalDTO, WithdrawalQueryDTO},
+    errors::AppError,
+    services::ChemicalApplicationService,
+};
+
+#[cfg(debug_assertions)]
+use crate::AppState;
+
+#[debug_handler(state = AppState)]
+pub async fn list_crops_in_withdrawal(
+    chemical_application_service: ChemicalApplicationService,
+    query: Query<WithdrawalQueryDTO>,
+) -> Result<Json<Vec<CropWithdrawalDTO>>, AppError> {
+    let date = query.date.unwrap_or_else(|| Local::now().date_naive());
+    let crops = chemical_application_service.in_withdrawal(date).await?;
+
+    Ok(Json(
+        crops
+            .iter()
+            .map(|(crop, applications)| CropWithdrawalDTO::new(crop, applications))
+            .collect(),
+    ))
+}
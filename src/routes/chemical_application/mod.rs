@@ -0,0 +1,11 @@
+mod delete_chemical_application;
+mod insert_chemical_application;
+mod list_chemical_applications;
+mod list_crops_in_withdrawal;
+
+pub use self::{
+    delete_chemical_application::delete_chemical_application,
+    insert_chemical_application::insert_chemical_application,
+    list_chemical_applications::list_chemical_applications,
+    list_crops_in_withdrawal::list_crops_in_withdrawal,
+};
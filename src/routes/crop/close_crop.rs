@@ -0,0 +1,24 @@
+use axum::{debug_handler, extract::Path, Json};
+use validator::Validate;
+
+use crate::{
+    dtos::{CropCloseRequestDTO, CropResponseDTO},
+    errors::AppError,
+    services::CropService,
+};
+
+#[cfg(debug_assertions)]
+use crate::AppState;
+
+#[debug_handler(state = AppState)]
+pub async fn close_crop(
+    crop_service: CropService,
+    id: Path<i64>,
+    body: Json<CropCloseRequestDTO>,
+) -> Result<Json<CropResponseDTO>, AppError> {
+    body.validate()?;
+
+    let crop = crop_service.close(*id, body.closed_at).await?;
+
+    Ok(Json(CropResponseDTO::from(&crop)))
+}
@@ -1,12 +1,18 @@
-use crate::{errors::AppError, services::CropService};
-use axum::{debug_handler, extract::Path, Json};
+use crate::{errors::AppError, misc::etag::IfMatch, services::CropService};
+use axum::{debug_handler, extract::Path, http::HeaderMap, Json};
 
 #[cfg(debug_assertions)]
 use crate::AppState;
 
 #[debug_handler(state = AppState)]
-pub async fn delete_crop(crop_service: CropService, id: Path<i64>) -> Result<Json<()>, AppError> {
-    crop_service.delete(*id).await?;
+pub async fn delete_crop(
+    crop_service: CropService,
+    id: Path<i64>,
+    headers: HeaderMap,
+) -> Result<Json<()>, AppError> {
+    crop_service
+        .delete(*id, &IfMatch::from_headers(&headers)?)
+        .await?;
 
     Ok(Json(()))
 }
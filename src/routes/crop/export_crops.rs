@@ -0,0 +1,70 @@
+use async_stream::try_stream;
+use axum::{
+    body::Body,
+    debug_handler,
+    extract::Query,
+    http::{
+        header::{CONTENT_DISPOSITION, CONTENT_TYPE},
+        HeaderValue,
+    },
+    response::{IntoResponse, Response},
+};
+use futures::{pin_mut, TryStreamExt};
+
+use crate::{
+    dtos::{CropExportQueryDTO, ExportFormat},
+    errors::AppError,
+    misc::export::{crop_cells, csv_body, csv_line, XlsxExport, CROP_COLUMNS},
+    services::CropService,
+};
+
+#[cfg(debug_assertions)]
+use crate::AppState;
+
+/// CSV is sent as the crops are read. XLSX rows are written to a temporary
+/// file and the workbook is sent once complete.
+#[debug_handler(state = AppState)]
+pub async fn export_crops(
+    crop_service: CropService,
+    query: Query<CropExportQueryDTO>,
+) -> Result<Response, AppError> {
+    let (unit, include_deleted) = (query.unit, query.include_deleted);
+    let (content_type, extension, body) = match query.format {
+        ExportFormat::Csv => {
+            let body = csv_body(try_stream! {
+                yield csv_line(CROP_COLUMNS)?;
+                let crops = crop_service.stream(unit, include_deleted);
+                pin_mut!(crops);
+                while let Some(crop) = crops.try_next().await? {
+                    yield csv_line(crop_cells(&crop))?;
+                }
+            });
+            ("text/csv; charset=utf-8", "csv", body)
+        }
+        ExportFormat::Xlsx => {
+            let mut xlsx = XlsxExport::new("Plantios", &CROP_COLUMNS)?;
+            let crops = crop_service.stream(unit, include_deleted);
+            pin_mut!(crops);
+            while let Some(crop) = crops.try_next().await? {
+                xlsx.push(crop_cells(&crop))?;
+            }
+            (
+                "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet",
+                "xlsx",
+                Body::from(xlsx.finish()?),
+            )
+        }
+    };
+
+    Ok((
+        [
+            (CONTENT_TYPE, HeaderValue::from_static(content_type)),
+            (
+                CONTENT_DISPOSITION,
+                HeaderValue::from_str(&format!("attachment; filename=\"plantios.{extension}\""))?,
+            ),
+        ],
+        body,
+    )
+        .into_response())
+}
@@ -1,6 +1,17 @@
-use axum::{debug_handler, extract::Path, Json};
+use axum::{
+    debug_handler,
+    extract::{Path, Query},
+    http::header::ETAG,
+    response::{IntoResponse, Response},
+    Json,
+};
 
-use crate::{dtos::CropResponseDTO, errors::AppError, services::CropService};
+use crate::{
+    dtos::{AreaUnitQueryDTO, CropResponseDTO},
+    errors::AppError,
+    misc::etag::etag,
+    services::CropService,
+};
 
 #[cfg(debug_assertions)]
 use crate::AppState;
@@ -9,15 +20,20 @@ use crate::AppState;
 pub async fn find_crop_by_id(
     crop_service: CropService,
     id: Path<i64>,
-) -> Result<Json<CropResponseDTO>, AppError> {
-    let crop = crop_service.find_by_id(*id).await?;
+    query: Query<AreaUnitQueryDTO>,
+) -> Result<Response, AppError> {
+    let mut crop = if query.include_deleted {
+        crop_service.find_by_id_including_deleted(*id).await?
+    } else {
+        crop_service.find_by_id(*id).await?
+    };
+    if let Some(unit) = query.unit {
+        crop.convert_area(unit);
+    }
 
-    Ok(Json(CropResponseDTO {
-        id: crop.id().unwrap(),
-        name: crop.name().to_string(),
-        area: crop.area(),
-        cultivation: crop.cultivation().to_string(),
-        planted_at: crop.planted_at(),
-        harvested_at: *crop.harvested_at(),
-    }))
+    Ok((
+        [(ETAG, etag(crop.version())?)],
+        Json(CropResponseDTO::from(&crop)),
+    )
+        .into_response())
 }
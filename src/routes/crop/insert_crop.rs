@@ -5,7 +5,7 @@ use crate::{
     dtos::{CropRequestDTO, CropResponseDTO},
     errors::AppError,
     models::Crop,
-    services::CropService,
+    services::{CropService, CultivationService},
 };
 
 #[cfg(debug_assertions)]
@@ -14,27 +14,25 @@ use crate::AppState;
 #[debug_handler(state = AppState)]
 pub async fn insert_crop(
     crop_service: CropService,
+    cultivation_service: CultivationService,
     body: Json<CropRequestDTO>,
 ) -> Result<Json<CropResponseDTO>, AppError> {
     body.validate()?;
 
+    let cultivation = cultivation_service.find_by_id(body.cultivation_id).await?;
+
     let crop = Crop::new(
         None,
         body.name.clone(),
-        body.area,
-        body.cultivation.clone(),
+        body.area.unwrap_or_default(),
+        body.area_unit,
+        cultivation,
         body.planted_at,
-        body.harvested_at,
+        None,
+        body.plot_id,
     )?;
 
     let crop = crop_service.insert(&crop).await?;
 
-    Ok(Json(CropResponseDTO {
-        id: crop.id().unwrap(),
-        name: crop.name().to_string(),
-        area: crop.area(),
-        cultivation: crop.cultivation().to_string(),
-        planted_at: crop.planted_at(),
-        harvested_at: *crop.harvested_at(),
-    }))
+    Ok(Json(CropResponseDTO::from(&crop)))
 }
@@ -1,25 +1,23 @@
-use axum::{debug_handler, Json};
+use axum::{debug_handler, extract::Query, Json};
 
-use crate::{dtos::CropResponseDTO, errors::AppError, services::CropService};
+use crate::{
+    dtos::{AreaUnitQueryDTO, CropResponseDTO},
+    errors::AppError,
+    services::CropService,
+};
 
 #[cfg(debug_assertions)]
 use crate::AppState;
 
 #[debug_handler(state = AppState)]
-pub async fn list_crops(crop_service: CropService) -> Result<Json<Vec<CropResponseDTO>>, AppError> {
-    let crops = crop_service.list().await?;
+pub async fn list_crops(
+    crop_service: CropService,
+    query: Query<AreaUnitQueryDTO>,
+) -> Result<Json<Vec<CropResponseDTO>>, AppError> {
+    let mut crops = crop_service.list(query.include_deleted).await?;
+    if let Some(unit) = query.unit {
+        crops.iter_mut().for_each(|crop| crop.convert_area(unit));
+    }
 
-    Ok(Json(
-        crops
-            .iter()
-            .map(|crop| CropResponseDTO {
-                id: crop.id().unwrap(),
-                name: crop.name().to_string(),
-                area: crop.area(),
-                cultivation: crop.cultivation().to_string(),
-                planted_at: crop.planted_at(),
-                harvested_at: *crop.harvested_at(),
-            })
-            .collect(),
-    ))
+    Ok(Json(crops.iter().map(CropResponseDTO::from).collect()))
 }
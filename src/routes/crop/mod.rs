@@ -1,10 +1,14 @@
+mod close_crop;
 mod delete_crop;
+mod export_crops;
 mod find_crop_by_id;
 mod insert_crop;
 mod list_crop;
+mod restore_crop;
 mod update_crop;
 
 pub use self::{
-    delete_crop::delete_crop, find_crop_by_id::find_crop_by_id, insert_crop::insert_crop,
-    list_crop::list_crops, update_crop::update_crop,
+    close_crop::close_crop, delete_crop::delete_crop, export_crops::export_crops,
+    find_crop_by_id::find_crop_by_id, insert_crop::insert_crop, list_crop::list_crops,
+    restore_crop::restore_crop, update_crop::update_crop,
 };
@@ -0,0 +1,16 @@
+use axum::{debug_handler, extract::Path, Json};
+
+use crate::{dtos::CropResponseDTO, errors::AppError, services::CropService};
+
+#[cfg(debug_assertions)]
+use crate::AppState;
+
+#[debug_handler(state = AppState)]
+pub async fn restore_crop(
+    crop_service: CropService,
+    id: Path<i64>,
+) -> Result<Json<CropResponseDTO>, AppError> {
+    let crop = crop_service.restore(*id).await?;
+
+    Ok(Json(CropResponseDTO::from(&crop)))
+}
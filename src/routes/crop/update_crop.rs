@@ -1,11 +1,18 @@
-use axum::{debug_handler, extract::Path, Json};
+use axum::{
+    debug_handler,
+    extract::Path,
+    http::{header::ETAG, HeaderMap},
+    response::{IntoResponse, Response},
+    Json,
+};
 use validator::Validate;
 
 use crate::{
     dtos::{CropRequestDTO, CropResponseDTO},
     errors::AppError,
+    misc::etag::{etag, IfMatch},
     models::Crop,
-    services::CropService,
+    services::{CropService, CultivationService},
 };
 
 #[cfg(debug_assertions)]
@@ -14,28 +21,32 @@ use crate::AppState;
 #[debug_handler(state = AppState)]
 pub async fn update_crop(
     crop_service: CropService,
+    cultivation_service: CultivationService,
     id: Path<i64>,
+    headers: HeaderMap,
     body: Json<CropRequestDTO>,
-) -> Result<Json<CropResponseDTO>, AppError> {
+) -> Result<Response, AppError> {
+    let if_match = IfMatch::from_headers(&headers)?;
     body.validate()?;
 
+    let cultivation = cultivation_service.find_by_id(body.cultivation_id).await?;
+
     let crop = Crop::new(
         None,
         body.name.clone(),
-        body.area,
-        body.cultivation.clone(),
+        body.area.unwrap_or_default(),
+        body.area_unit,
+        cultivation,
         body.planted_at,
-        body.harvested_at,
+        None,
+        body.plot_id,
     )?;
 
-    let crop = crop_service.update(*id, &crop).await?;
+    let crop = crop_service.update(*id, &crop, &if_match).await?;
 
-    Ok(Json(CropResponseDTO {
-        id: crop.id().unwrap(),
-        name: crop.name().to_string(),
-        area: crop.area(),
-        cultivation: crop.cultivation().to_string(),
-        planted_at: crop.planted_at(),
-        harvested_at: *crop.harvested_at(),
-    }))
+    Ok((
+        [(ETAG, etag(crop.version())?)],
+        Json(CropResponseDTO::from(&crop)),
+    )
+        .into_response())
 }
@@ -0,0 +1,16 @@
+use axum::{debug_handler, extract::Path, Json};
+
+use crate::{errors::AppError, services::CultivationService};
+
+#[cfg(debug_assertions)]
+use crate::AppState;
+
+#[debug_handler(state = AppState)]
+pub async fn delete_cultivation(
+    cultivation_service: CultivationService,
+    id: Path<i64>,
+) -> Result<Json<()>, AppError> {
+    cultivation_service.delete(*id).await?;
+
+    Ok(Json(()))
+}
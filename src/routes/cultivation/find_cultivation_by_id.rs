@@ -0,0 +1,16 @@
+use axum::{debug_handler, extract::Path, Json};
+
+use crate::{dtos::CultivationResponseDTO, errors::AppError, services::CultivationService};
+
+#[cfg(debug_assertions)]
+use crate::AppState;
+
+#[debug_handler(state = AppState)]
+pub async fn find_cultivation_by_id(
+    cultivation_service: CultivationService,
+    id: Path<i64>,
+) -> Result<Json<CultivationResponseDTO>, AppError> {
+    let cultivation = cultivation_service.find_by_id(*id).await?;
+
+    Ok(Json(CultivationResponseDTO::from(&cultivation)))
+}
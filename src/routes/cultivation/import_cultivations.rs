@@ -0,0 +1,22 @@
+use axum::{debug_handler, Json};
+
+use crate::{
+    dtos::CultivationImportResponseDTO, errors::AppError,
+    misc::cultivation_csv::parse_cultivations_csv, services::CultivationService,
+};
+
+#[cfg(debug_assertions)]
+use crate::AppState;
+
+/// Takes the CSV file as the request body.
+#[debug_handler(state = AppState)]
+pub async fn import_cultivations(
+    cultivation_service: CultivationService,
+    body: String,
+) -> Result<Json<CultivationImportResponseDTO>, AppError> {
+    let cultivations = parse_cultivations_csv(&body)?;
+
+    let (inserted, updated) = cultivation_service.import(&cultivations).await?;
+
+    Ok(Json(CultivationImportResponseDTO { inserted, updated }))
+}
@@ -0,0 +1,33 @@
+use axum::{debug_handler, Json};
+use validator::Validate;
+
+use crate::{
+    dtos::{CultivationRequestDTO, CultivationResponseDTO},
+    errors::AppError,
+    models::Cultivation,
+    services::CultivationService,
+};
+
+#[cfg(debug_assertions)]
+use crate::AppState;
+
+#[debug_handler(state = AppState)]
+pub async fn insert_cultivation(
+    cultivation_service: CultivationService,
+    body: Json<CultivationRequestDTO>,
+) -> Result<Json<CultivationResponseDTO>, AppError> {
+    body.validate()?;
+
+    let cultivation = Cultivation::new(
+        None,
+        body.name.clone(),
+        body.scientific_name.clone(),
+        body.variety.clone(),
+        body.cycle_days,
+        body.shelf_life_days,
+    )?;
+
+    let cultivation = cultivation_service.insert(&cultivation).await?;
+
+    Ok(Json(CultivationResponseDTO::from(&cultivation)))
+}
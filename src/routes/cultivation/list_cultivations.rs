@@ -0,0 +1,20 @@
+use axum::{debug_handler, Json};
+
+use crate::{dtos::CultivationResponseDTO, errors::AppError, services::CultivationService};
+
+#[cfg(debug_assertions)]
+use crate::AppState;
+
+#[debug_handler(state = AppState)]
+pub async fn list_cultivations(
+    cultivation_service: CultivationService,
+) -> Result<Json<Vec<CultivationResponseDTO>>, AppError> {
+    let cultivations = cultivation_service.list().await?;
+
+    Ok(Json(
+        cultivations
+            .iter()
+            .map(CultivationResponseDTO::from)
+            .collect(),
+    ))
+}
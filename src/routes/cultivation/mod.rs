@@ -0,0 +1,12 @@
+mod delete_cultivation;
+mod find_cultivation_by_id;
+mod import_cultivations;
+mod insert_cultivation;
+mod list_cultivations;
+mod update_cultivation;
+
+pub use self::{
+    delete_cultivation::delete_cultivation, find_cultivation_by_id::find_cultivation_by_id,
+    import_cultivations::import_cultivations, insert_cultivation::insert_cultivation,
+    list_cultivations::list_cultivations, update_cultivation::update_cultivation,
+};
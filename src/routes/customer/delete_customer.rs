@@ -0,0 +1,16 @@
+use axum::{debug_handler, extract::Path, Json};
+
+use crate::{errors::AppError, services::CustomerService};
+
+#[cfg(debug_assertions)]
+use crate::AppState;
+
+#[debug_handler(state = AppState)]
+pub async fn delete_customer(
+    customer_service: CustomerService,
+    id: Path<i64>,
+) -> Result<Json<()>, AppError> {
+    customer_service.delete(*id).await?;
+
+    Ok(Json(()))
+}
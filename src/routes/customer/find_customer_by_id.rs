@@ -0,0 +1,16 @@
+use axum::{debug_handler, extract::Path, Json};
+
+use crate::{dtos::CustomerResponseDTO, errors::AppError, services::CustomerService};
+
+#[cfg(debug_assertions)]
+use crate::AppState;
+
+#[debug_handler(state = AppState)]
+pub async fn find_customer_by_id(
+    customer_service: CustomerService,
+    id: Path<i64>,
+) -> Result<Json<CustomerResponseDTO>, AppError> {
+    let customer = customer_service.find_by_id(*id).await?;
+
+    Ok(Json(CustomerResponseDTO::from(&customer)))
+}
@@ -0,0 +1,32 @@
+use axum::{debug_handler, Json};
+use validator::Validate;
+
+use crate::{
+    dtos::{CustomerRequestDTO, CustomerResponseDTO},
+    errors::AppError,
+    models::Customer,
+    services::CustomerService,
+};
+
+#[cfg(debug_assertions)]
+use crate::AppState;
+
+#[debug_handler(state = AppState)]
+pub async fn insert_customer(
+    customer_service: CustomerService,
+    body: Json<CustomerRequestDTO>,
+) -> Result<Json<CustomerResponseDTO>, AppError> {
+    body.validate()?;
+
+    let customer = Customer::new(
+        None,
+        body.name.clone(),
+        body.document.clone(),
+        body.address.clone(),
+        body.email.clone(),
+    )?;
+
+    let customer = customer_service.insert(&customer).await?;
+
+    Ok(Json(CustomerResponseDTO::from(&customer)))
+}
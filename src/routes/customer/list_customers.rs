@@ -0,0 +1,17 @@
+use axum::{debug_handler, Json};
+
+use crate::{dtos::CustomerResponseDTO, errors::AppError, services::CustomerService};
+
+#[cfg(debug_assertions)]
+use crate::AppState;
+
+#[debug_handler(state = AppState)]
+pub async fn list_customers(
+    customer_service: CustomerService,
+) -> Result<Json<Vec<CustomerResponseDTO>>, AppError> {
+    let customers = customer_service.list().await?;
+
+    Ok(Json(
+        customers.iter().map(CustomerResponseDTO::from).collect(),
+    ))
+}
@@ -0,0 +1,11 @@
+mod delete_customer;
+mod find_customer_by_id;
+mod insert_customer;
+mod list_customers;
+mod update_customer;
+
+pub use self::{
+    delete_customer::delete_customer, find_customer_by_id::find_customer_by_id,
+    insert_customer::insert_customer, list_customers::list_customers,
+    update_customer::update_customer,
+};
@@ -0,0 +1,33 @@
+use axum::{debug_handler, extract::Path, Json};
+use validator::Validate;
+
+use crate::{
+    dtos::{CustomerRequestDTO, CustomerResponseDTO},
+    errors::AppError,
+    models::Customer,
+    services::CustomerService,
+};
+
+#[cfg(debug_assertions)]
+use crate::AppState;
+
+#[debug_handler(state = AppState)]
+pub async fn update_customer(
+    customer_service: CustomerService,
+    id: Path<i64>,
+    body: Json<CustomerRequestDTO>,
+) -> Result<Json<CustomerResponseDTO>, AppError> {
+    body.validate()?;
+
+    let customer = Customer::new(
+        None,
+        body.name.clone(),
+        body.document.clone(),
+        body.address.clone(),
+        body.email.clone(),
+    )?;
+
+    let customer = customer_service.update(*id, &customer).await?;
+
+    Ok(Json(CustomerResponseDTO::from(&customer)))
+}
@@ -0,0 +1,31 @@
+use axum::{debug_handler, Json};
+
+use crate::{
+    dtos::{EpcisCaptureResponseDTO, EpcisDocumentDTO},
+    errors::AppError,
+    services::EpcisService,
+};
+
+#[cfg(debug_assertions)]
+use crate::AppState;
+
+#[debug_handler(state = AppState)]
+pub async fn capture_epcis_events(
+    epcis_service: EpcisService,
+    body: Json<EpcisDocumentDTO>,
+) -> Result<Json<EpcisCaptureResponseDTO>, AppError> {
+    let Json(body) = body;
+    if body.kind != "EPCISDocument" {
+        return Err(AppError::BadRequest(format!(
+            "Tipo de documento EPCIS inválido: {}",
+            body.kind
+        )));
+    }
+
+    let (captured, duplicates) = epcis_service.capture(body.epcis_body.event_list).await?;
+
+    Ok(Json(EpcisCaptureResponseDTO {
+        captured,
+        duplicates,
+    }))
+}
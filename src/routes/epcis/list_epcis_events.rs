@@ -0,0 +1,36 @@
+use axum::{debug_handler, extract::Query, Json};
+use chrono::Utc;
+
+use crate::{
+    dtos::{
+        EpcisQueryBodyDTO, EpcisQueryDTO, EpcisQueryDocumentDTO, EpcisQueryResultsDTO,
+        EpcisResultsBodyDTO,
+    },
+    errors::AppError,
+    misc::epcis::{time_zone, EPCIS_CONTEXT, EPCIS_SCHEMA_VERSION},
+    services::EpcisService,
+};
+
+#[cfg(debug_assertions)]
+use crate::AppState;
+
+#[debug_handler(state = AppState)]
+pub async fn list_epcis_events(
+    epcis_service: EpcisService,
+    query: Query<EpcisQueryDTO>,
+) -> Result<Json<EpcisQueryDocumentDTO>, AppError> {
+    let events = epcis_service.list(query.from, query.to).await?;
+
+    Ok(Json(EpcisQueryDocumentDTO {
+        context: vec![EPCIS_CONTEXT],
+        kind: "EPCISQueryDocument",
+        schema_version: EPCIS_SCHEMA_VERSION,
+        creation_date: Utc::now().with_timezone(&time_zone()),
+        epcis_body: EpcisQueryBodyDTO {
+            query_results: EpcisQueryResultsDTO {
+                query_name: "SimpleEventQuery",
+                results_body: EpcisResultsBodyDTO { event_list: events },
+            },
+        },
+    }))
+}
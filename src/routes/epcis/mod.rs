@@ -0,0 +1,4 @@
+mod capture_epcis_events;
+mod list_epcis_events;
+
+pub use self::{capture_epcis_events::capture_epcis_events, list_epcis_events::list_epcis_events};
@@ -0,0 +1,13 @@
+use axum::{debug_handler, extract::Path, Json};
+
+use crate::{errors::AppError, services::FarmService};
+
+#[cfg(debug_assertions)]
+use crate::AppState;
+
+#[debug_handler(state = AppState)]
+pub async fn delete_farm(farm_service: FarmService, id: Path<i64>) -> Result<Json<()>, AppError> {
+    farm_service.delete(*id).await?;
+
+    Ok(Json(()))
+}
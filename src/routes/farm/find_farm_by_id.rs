@@ -0,0 +1,16 @@
+use axum::{debug_handler, extract::Path, Json};
+
+use crate::{dtos::FarmResponseDTO, errors::AppError, services::FarmService};
+
+#[cfg(debug_assertions)]
+use crate::AppState;
+
+#[debug_handler(state = AppState)]
+pub async fn find_farm_by_id(
+    farm_service: FarmService,
+    id: Path<i64>,
+) -> Result<Json<FarmResponseDTO>, AppError> {
+    let farm = farm_service.find_by_id(*id).await?;
+
+    Ok(Json(FarmResponseDTO::from(&farm)))
+}
@@ -0,0 +1,31 @@
+use axum::{debug_handler, Json};
+use validator::Validate;
+
+use crate::{
+    dtos::{FarmRequestDTO, FarmResponseDTO},
+    errors::AppError,
+    models::Farm,
+    services::FarmService,
+};
+
+#[cfg(debug_assertions)]
+use crate::AppState;
+
+#[debug_handler(state = AppState)]
+pub async fn insert_farm(
+    farm_service: FarmService,
+    body: Json<FarmRequestDTO>,
+) -> Result<Json<FarmResponseDTO>, AppError> {
+    body.validate()?;
+
+    let farm = Farm::new(
+        None,
+        body.name.clone(),
+        body.document.clone(),
+        body.address.clone(),
+    )?;
+
+    let farm = farm_service.insert(&farm).await?;
+
+    Ok(Json(FarmResponseDTO::from(&farm)))
+}
@@ -0,0 +1,13 @@
+use axum::{debug_handler, Json};
+
+use crate::{dtos::FarmResponseDTO, errors::AppError, services::FarmService};
+
+#[cfg(debug_assertions)]
+use crate::AppState;
+
+#[debug_handler(state = AppState)]
+pub async fn list_farms(farm_service: FarmService) -> Result<Json<Vec<FarmResponseDTO>>, AppError> {
+    let farms = farm_service.list().await?;
+
+    Ok(Json(farms.iter().map(FarmResponseDTO::from).collect()))
+}
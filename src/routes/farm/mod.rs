@@ -0,0 +1,10 @@
+mod delete_farm;
+mod find_farm_by_id;
+mod insert_farm;
+mod list_farms;
+mod update_farm;
+
+pub use self::{
+    delete_farm::delete_farm, find_farm_by_id::find_farm_by_id, insert_farm::insert_farm,
+    list_farms::list_farms, update_farm::update_farm,
+};
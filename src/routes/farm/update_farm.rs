@@ -0,0 +1,32 @@
+use axum::{debug_handler, extract::Path, Json};
+use validator::Validate;
+
+use crate::{
+    dtos::{FarmRequestDTO, FarmResponseDTO},
+    errors::AppError,
+    models::Farm,
+    services::FarmService,
+};
+
+#[cfg(debug_assertions)]
+use crate::AppState;
+
+#[debug_handler(state = AppState)]
+pub async fn update_farm(
+    farm_service: FarmService,
+    id: Path<i64>,
+    body: Json<FarmRequestDTO>,
+) -> Result<Json<FarmResponseDTO>, AppError> {
+    body.validate()?;
+
+    let farm = Farm::new(
+        None,
+        body.name.clone(),
+        body.document.clone(),
+        body.address.clone(),
+    )?;
+
+    let farm = farm_service.update(*id, &farm).await?;
+
+    Ok(Json(FarmResponseDTO::from(&farm)))
+}
@@ -0,0 +1,16 @@
+use axum::{debug_handler, extract::Path, Json};
+
+use crate::{errors::AppError, services::HarvestService};
+
+#[cfg(debug_assertions)]
+use crate::AppState;
+
+#[debug_handler(state = AppState)]
+pub async fn delete_harvest(
+    harvest_service: HarvestService,
+    Path((crop_id, id)): Path<(i64, i64)>,
+) -> Result<Json<()>, AppError> {
+    harvest_service.delete(crop_id, id).await?;
+
+    Ok(Json(()))
+}
@@ -0,0 +1,34 @@
+use axum::{debug_handler, extract::Path, Json};
+use validator::Validate;
+
+use crate::{
+    dtos::{HarvestRequestDTO, HarvestResponseDTO},
+    errors::AppError,
+    models::Harvest,
+    services::HarvestService,
+};
+
+#[cfg(debug_assertions)]
+use crate::AppState;
+
+#[debug_handler(state = AppState)]
+pub async fn insert_harvest(
+    harvest_service: HarvestService,
+    crop_id: Path<i64>,
+    body: Json<HarvestRequestDTO>,
+) -> Result<Json<HarvestResponseDTO>, AppError> {
+    body.validate()?;
+
+    let harvest = Harvest::new(
+        None,
+        *crop_id,
+        body.harvested_at,
+        body.quantity,
+        body.quantity_unit,
+        body.team.clone(),
+    )?;
+
+    let harvest = harvest_service.insert(&harvest).await?;
+
+    Ok(Json(HarvestResponseDTO::from(&harvest)))
+}
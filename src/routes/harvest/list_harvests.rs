@@ -0,0 +1,18 @@
+use axum::{debug_handler, extract::Path, Json};
+
+use crate::{dtos::HarvestResponseDTO, errors::AppError, services::HarvestService};
+
+#[cfg(debug_assertions)]
+use crate::AppState;
+
+#[debug_handler(state = AppState)]
+pub async fn list_harvests(
+    harvest_service: HarvestService,
+    crop_id: Path<i64>,
+) -> Result<Json<Vec<HarvestResponseDTO>>, AppError> {
+    let harvests = harvest_service.list(*crop_id).await?;
+
+    Ok(Json(
+        harvests.iter().map(HarvestResponseDTO::from).collect(),
+    ))
+}
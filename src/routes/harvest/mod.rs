@@ -0,0 +1,7 @@
+mod delete_harvest;
+mod insert_harvest;
+mod list_harvests;
+
+pub use self::{
+    delete_harvest::delete_harvest, insert_harvest::insert_harvest, list_harvests::list_harvests,
+};
@@ -0,0 +1,35 @@
+use axum::{
+    body::Bytes,
+    debug_handler,
+    extract::Query,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+
+use crate::{
+    dtos::{ImportQueryDTO, ImportResponseDTO},
+    errors::AppError,
+    services::ImportService,
+};
+
+#[cfg(debug_assertions)]
+use crate::AppState;
+
+/// Takes the CSV or XLSX file as the request body. Responds with 422 and the
+/// errors of each row when any row is invalid.
+#[debug_handler(state = AppState)]
+pub async fn import_batches(
+    import_service: ImportService,
+    query: Query<ImportQueryDTO>,
+    body: Bytes,
+) -> Result<Response, AppError> {
+    let report = import_service.import_batches(&body, query.dry_run).await?;
+
+    let status = if report.errors.is_empty() {
+        StatusCode::OK
+    } else {
+        StatusCode::UNPROCESSABLE_ENTITY
+    };
+    Ok((status, Json(ImportResponseDTO::new(&report, query.dry_run))).into_response())
+}
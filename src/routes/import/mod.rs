@@ -0,0 +1,4 @@
+mod import_batches;
+mod import_crops;
+
+pub use self::{import_batches::import_batches, import_crops::import_crops};
@@ -0,0 +1,16 @@
+use axum::{debug_handler, extract::Path, Json};
+
+use crate::{errors::AppError, services::LabelTemplateService};
+
+#[cfg(debug_assertions)]
+use crate::AppState;
+
+#[debug_handler(state = AppState)]
+pub async fn delete_label_template(
+    label_template_service: LabelTemplateService,
+    id: Path<i64>,
+) -> Result<Json<()>, AppError> {
+    label_template_service.delete(*id).await?;
+
+    Ok(Json(()))
+}
@@ -0,0 +1,16 @@
+use axum::{debug_handler, extract::Path, Json};
+
+use crate::{dtos::LabelTemplateResponseDTO, errors::AppError, services::LabelTemplateService};
+
+#[cfg(debug_assertions)]
+use crate::AppState;
+
+#[debug_handler(state = AppState)]
+pub async fn find_label_template_by_id(
+    label_template_service: LabelTemplateService,
+    id: Path<i64>,
+) -> Result<Json<LabelTemplateResponseDTO>, AppError> {
+    let template = label_template_service.find_by_id(*id).await?;
+
+    Ok(Json(LabelTemplateResponseDTO::from(&template)))
+}
@@ -0,0 +1,36 @@
+use axum::{debug_handler, Json};
+use validator::Validate;
+
+use crate::{
+    dtos::{LabelTemplateRequestDTO, LabelTemplateResponseDTO},
+    errors::AppError,
+    models::LabelTemplate,
+    services::LabelTemplateService,
+};
+
+#[cfg(debug_assertions)]
+use crate::AppState;
+
+#[debug_handler(state = AppState)]
+pub async fn insert_label_template(
+    label_template_service: LabelTemplateService,
+    body: Json<LabelTemplateRequestDTO>,
+) -> Result<Json<LabelTemplateResponseDTO>, AppError> {
+    body.validate()?;
+
+    let template = LabelTemplate::new(
+        None,
+        body.organization.clone(),
+        body.name.clone(),
+        body.page_width,
+        body.page_height,
+        body.font,
+        body.font_size,
+        body.fields.clone(),
+        body.is_default,
+    )?;
+
+    let template = label_template_service.insert(&template).await?;
+
+    Ok(Json(LabelTemplateResponseDTO::from(&template)))
+}
@@ -0,0 +1,27 @@
+use axum::{debug_handler, extract::Query, Json};
+
+use crate::{
+    dtos::{LabelTemplateQueryDTO, LabelTemplateResponseDTO},
+    errors::AppError,
+    services::LabelTemplateService,
+};
+
+#[cfg(debug_assertions)]
+use crate::AppState;
+
+#[debug_handler(state = AppState)]
+pub async fn list_label_templates(
+    label_template_service: LabelTemplateService,
+    query: Query<LabelTemplateQueryDTO>,
+) -> Result<Json<Vec<LabelTemplateResponseDTO>>, AppError> {
+    let templates = label_template_service
+        .list(query.organization.clone())
+        .await?;
+
+    Ok(Json(
+        templates
+            .iter()
+            .map(LabelTemplateResponseDTO::from)
+            .collect(),
+    ))
+}
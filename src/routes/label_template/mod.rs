@@ -0,0 +1,12 @@
+mod delete_label_template;
+mod find_label_template_by_id;
+mod insert_label_template;
+mod list_label_templates;
+mod update_label_template;
+
+pub use self::{
+    delete_label_template::delete_label_template,
+    find_label_template_by_id::find_label_template_by_id,
+    insert_label_template::insert_label_template, list_label_templates::list_label_templates,
+    update_label_template::update_label_template,
+};
@@ -1,2 +1,19 @@
+pub mod audit;
 pub mod batch;
+pub mod batch_event;
+pub mod catalog;
+pub mod chemical_application;
 pub mod crop;
+pub mod cultivation;
+pub mod customer;
+pub mod epcis;
+pub mod farm;
+pub mod harvest;
+pub mod import;
+pub mod label_template;
+pub mod packing;
+pub mod plot;
+pub mod product;
+pub mod recall;
+pub mod report;
+pub mod shipment;
@@ -0,0 +1,16 @@
+use axum::{debug_handler, extract::Path, Json};
+
+use crate::{errors::AppError, services::PackingService};
+
+#[cfg(debug_assertions)]
+use crate::AppState;
+
+#[debug_handler(state = AppState)]
+pub async fn delete_packing(
+    packing_service: PackingService,
+    id: Path<i64>,
+) -> Result<Json<()>, AppError> {
+    packing_service.delete(*id).await?;
+
+    Ok(Json(()))
+}
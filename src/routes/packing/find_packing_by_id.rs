@@ -0,0 +1,16 @@
+use axum::{debug_handler, extract::Path, Json};
+
+use crate::{dtos::PackingResponseDTO, errors::AppError, services::PackingService};
+
+#[cfg(debug_assertions)]
+use crate::AppState;
+
+#[debug_handler(state = AppState)]
+pub async fn find_packing_by_id(
+    packing_service: PackingService,
+    id: Path<i64>,
+) -> Result<Json<PackingResponseDTO>, AppError> {
+    let packing = packing_service.find_by_id(*id).await?;
+
+    Ok(Json(PackingResponseDTO::from(&packing)))
+}
@@ -0,0 +1,22 @@
+use axum::{debug_handler, extract::Query, Json};
+
+use crate::{
+    dtos::{CatalogQueryDTO, PackingResponseDTO},
+    errors::AppError,
+    services::PackingService,
+};
+
+#[cfg(debug_assertions)]
+use crate::AppState;
+
+#[debug_handler(state = AppState)]
+pub async fn list_packings(
+    packing_service: PackingService,
+    query: Query<CatalogQueryDTO>,
+) -> Result<Json<Vec<PackingResponseDTO>>, AppError> {
+    let packings = packing_service.list(query.active).await?;
+
+    Ok(Json(
+        packings.iter().map(PackingResponseDTO::from).collect(),
+    ))
+}
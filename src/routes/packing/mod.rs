@@ -0,0 +1,10 @@
+mod delete_packing;
+mod find_packing_by_id;
+mod insert_packing;
+mod list_packings;
+mod update_packing;
+
+pub use self::{
+    delete_packing::delete_packing, find_packing_by_id::find_packing_by_id,
+    insert_packing::insert_packing, list_packings::list_packings, update_packing::update_packing,
+};
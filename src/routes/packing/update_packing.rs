@@ -0,0 +1,33 @@
+use axum::{debug_handler, extract::Path, Json};
+use validator::Validate;
+
+use crate::{
+    dtos::{PackingRequestDTO, PackingResponseDTO},
+    errors::AppError,
+    models::Packing,
+    services::PackingService,
+};
+
+#[cfg(debug_assertions)]
+use crate::AppState;
+
+#[debug_handler(state = AppState)]
+pub async fn update_packing(
+    packing_service: PackingService,
+    id: Path<i64>,
+    body: Json<PackingRequestDTO>,
+) -> Result<Json<PackingResponseDTO>, AppError> {
+    body.validate()?;
+
+    let packing = Packing::new(
+        None,
+        body.code.clone(),
+        body.description.clone(),
+        body.kg_per_unit,
+        body.active,
+    )?;
+
+    let packing = packing_service.update(*id, &packing).await?;
+
+    Ok(Json(PackingResponseDTO::from(&packing)))
+}
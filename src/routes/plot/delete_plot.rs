@@ -0,0 +1,13 @@
+use axum::{debug_handler, extract::Path, Json};
+
+use crate::{errors::AppError, services::PlotService};
+
+#[cfg(debug_assertions)]
+use crate::AppState;
+
+#[debug_handler(state = AppState)]
+pub async fn delete_plot(plot_service: PlotService, id: Path<i64>) -> Result<Json<()>, AppError> {
+    plot_service.delete(*id).await?;
+
+    Ok(Json(()))
+}
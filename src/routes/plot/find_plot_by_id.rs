@@ -0,0 +1,16 @@
+use axum::{debug_handler, extract::Path, Json};
+
+use crate::{dtos::PlotResponseDTO, errors::AppError, services::PlotService};
+
+#[cfg(debug_assertions)]
+use crate::AppState;
+
+#[debug_handler(state = AppState)]
+pub async fn find_plot_by_id(
+    plot_service: PlotService,
+    id: Path<i64>,
+) -> Result<Json<PlotResponseDTO>, AppError> {
+    let plot = plot_service.find_by_id(*id).await?;
+
+    Ok(Json(PlotResponseDTO::from(&plot)))
+}
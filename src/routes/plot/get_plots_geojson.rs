@@ -0,0 +1,33 @@
+use axum::{
+    debug_handler,
+    extract::Query,
+    http::{header::CONTENT_TYPE, HeaderValue},
+    response::{IntoResponse, Response},
+    Json,
+};
+
+use crate::{
+    dtos::{PlotFeatureCollectionDTO, PlotQueryDTO},
+    errors::AppError,
+    services::PlotService,
+};
+
+#[cfg(debug_assertions)]
+use crate::AppState;
+
+#[debug_handler(state = AppState)]
+pub async fn get_plots_geojson(
+    plot_service: PlotService,
+    query: Query<PlotQueryDTO>,
+) -> Result<Response, AppError> {
+    let plots = plot_service.list(query.farm).await?;
+
+    Ok((
+        [(
+            CONTENT_TYPE,
+            HeaderValue::from_static("application/geo+json"),
+        )],
+        Json(PlotFeatureCollectionDTO::new(&plots)),
+    )
+        .into_response())
+}
@@ -0,0 +1,26 @@
+use axum::{debug_handler, Json};
+use validator::Validate;
+
+use crate::{
+    dtos::{PlotRequestDTO, PlotResponseDTO},
+    errors::AppError,
+    models::Plot,
+    services::PlotService,
+};
+
+#[cfg(debug_assertions)]
+use crate::AppState;
+
+#[debug_handler(state = AppState)]
+pub async fn insert_plot(
+    plot_service: PlotService,
+    body: Json<PlotRequestDTO>,
+) -> Result<Json<PlotResponseDTO>, AppError> {
+    body.validate()?;
+
+    let plot = Plot::new(None, body.farm_id, body.name.clone(), body.boundary.clone())?;
+
+    let plot = plot_service.insert(&plot).await?;
+
+    Ok(Json(PlotResponseDTO::from(&plot)))
+}
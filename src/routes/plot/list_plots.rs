@@ -0,0 +1,20 @@
+use axum::{debug_handler, extract::Query, Json};
+
+use crate::{
+    dtos::{PlotQueryDTO, PlotResponseDTO},
+    errors::AppError,
+    services::PlotService,
+};
+
+#[cfg(debug_assertions)]
+use crate::AppState;
+
+#[debug_handler(state = AppState)]
+pub async fn list_plots(
+    plot_service: PlotService,
+    query: Query<PlotQueryDTO>,
+) -> Result<Json<Vec<PlotResponseDTO>>, AppError> {
+    let plots = plot_service.list(query.farm).await?;
+
+    Ok(Json(plots.iter().map(PlotResponseDTO::from).collect()))
+}
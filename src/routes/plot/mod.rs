@@ -0,0 +1,12 @@
+mod delete_plot;
+mod find_plot_by_id;
+mod get_plots_geojson;
+mod insert_plot;
+mod list_plots;
+mod update_plot;
+
+pub use self::{
+    delete_plot::delete_plot, find_plot_by_id::find_plot_by_id,
+    get_plots_geojson::get_plots_geojson, insert_plot::insert_plot, list_plots::list_plots,
+    update_plot::update_plot,
+};
@@ -0,0 +1,27 @@
+use axum::{debug_handler, extract::Path, Json};
+use validator::Validate;
+
+use crate::{
+    dtos::{PlotRequestDTO, PlotResponseDTO},
+    errors::AppError,
+    models::Plot,
+    services::PlotService,
+};
+
+#[cfg(debug_assertions)]
+use crate::AppState;
+
+#[debug_handler(state = AppState)]
+pub async fn update_plot(
+    plot_service: PlotService,
+    id: Path<i64>,
+    body: Json<PlotRequestDTO>,
+) -> Result<Json<PlotResponseDTO>, AppError> {
+    body.validate()?;
+
+    let plot = Plot::new(None, body.farm_id, body.name.clone(), body.boundary.clone())?;
+
+    let plot = plot_service.update(*id, &plot).await?;
+
+    Ok(Json(PlotResponseDTO::from(&plot)))
+}
@@ -0,0 +1,16 @@
+use axum::{debug_handler, extract::Path, Json};
+
+use crate::{errors::AppError, services::ProductService};
+
+#[cfg(debug_assertions)]
+use crate::AppState;
+
+#[debug_handler(state = AppState)]
+pub async fn delete_product(
+    product_service: ProductService,
+    id: Path<i64>,
+) -> Result<Json<()>, AppError> {
+    product_service.delete(*id).await?;
+
+    Ok(Json(()))
+}
@@ -0,0 +1,16 @@
+use axum::{debug_handler, extract::Path, Json};
+
+use crate::{dtos::ProductResponseDTO, errors::AppError, services::ProductService};
+
+#[cfg(debug_assertions)]
+use crate::AppState;
+
+#[debug_handler(state = AppState)]
+pub async fn find_product_by_id(
+    product_service: ProductService,
+    id: Path<i64>,
+) -> Result<Json<ProductResponseDTO>, AppError> {
+    let product = product_service.find_by_id(*id).await?;
+
+    Ok(Json(ProductResponseDTO::from(&product)))
+}
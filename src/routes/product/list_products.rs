@@ -0,0 +1,17 @@
+use axum::{debug_handler, Json};
+
+use crate::{dtos::ProductResponseDTO, errors::AppError, services::ProductService};
+
+#[cfg(debug_assertions)]
+use crate::AppState;
+
+#[debug_handler(state = AppState)]
+pub async fn list_products(
+    product_service: ProductService,
+) -> Result<Json<Vec<ProductResponseDTO>>, AppError> {
+    let products = product_service.list().await?;
+
+    Ok(Json(
+        products.iter().map(ProductResponseDTO::from).collect(),
+    ))
+}
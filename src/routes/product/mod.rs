@@ -0,0 +1,10 @@
+mod delete_product;
+mod find_product_by_id;
+mod insert_product;
+mod list_products;
+mod update_product;
+
+pub use self::{
+    delete_product::delete_product, find_product_by_id::find_product_by_id,
+    insert_product::insert_product, list_products::list_products, update_product::update_product,
+};
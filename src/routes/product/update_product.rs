@@ -0,0 +1,32 @@
+use axum::{debug_handler, extract::Path, Json};
+use validator::Validate;
+
+use crate::{
+    dtos::{ProductRequestDTO, ProductResponseDTO},
+    errors::AppError,
+    models::Product,
+    services::ProductService,
+};
+
+#[cfg(debug_assertions)]
+use crate::AppState;
+
+#[debug_handler(state = AppState)]
+pub async fn update_product(
+    product_service: ProductService,
+    id: Path<i64>,
+    body: Json<ProductRequestDTO>,
+) -> Result<Json<ProductResponseDTO>, AppError> {
+    body.validate()?;
+
+    let product = Product::new(
+        None,
+        body.gtin.clone(),
+        body.name.clone(),
+        body.description.clone(),
+    )?;
+
+    let product = product_service.update(*id, &product).await?;
+
+    Ok(Json(ProductResponseDTO::from(&product)))
+}
@@ -0,0 +1,16 @@
+use axum::{debug_handler, extract::Path, Json};
+
+use crate::{dtos::RecallResponseDTO, errors::AppError, services::RecallService};
+
+#[cfg(debug_assertions)]
+use crate::AppState;
+
+#[debug_handler(state = AppState)]
+pub async fn find_recall_by_id(
+    recall_service: RecallService,
+    id: Path<i64>,
+) -> Result<Json<RecallResponseDTO>, AppError> {
+    let recall = recall_service.find_by_id(*id).await?;
+
+    Ok(Json(RecallResponseDTO::from(&recall)))
+}
@@ -0,0 +1,54 @@
+use axum::{
+    debug_handler,
+    extract::{Path, Query},
+    http::{
+        header::{CONTENT_DISPOSITION, CONTENT_TYPE},
+        HeaderValue,
+    },
+    response::{IntoResponse, Response},
+    Json,
+};
+
+use crate::{
+    dtos::{RecallReportDTO, RecallReportFormat, RecallReportQueryDTO},
+    errors::AppError,
+    misc::recall_report::render_recall_csv,
+    services::RecallService,
+};
+
+#[cfg(debug_assertions)]
+use crate::AppState;
+
+#[debug_handler(state = AppState)]
+pub async fn get_recall_report(
+    recall_service: RecallService,
+    id: Path<i64>,
+    query: Query<RecallReportQueryDTO>,
+) -> Result<Response, AppError> {
+    let report = recall_service.report(*id).await?;
+
+    match query.format.unwrap_or(RecallReportFormat::Json) {
+        RecallReportFormat::Json => Ok((
+            [(
+                CONTENT_DISPOSITION,
+                HeaderValue::from_str(&format!("attachment; filename=\"recall-{}.json\"", *id))?,
+            )],
+            Json(RecallReportDTO::from(&report)),
+        )
+            .into_response()),
+        RecallReportFormat::Csv => Ok((
+            [
+                (
+                    CONTENT_TYPE,
+                    HeaderValue::from_static("text/csv; charset=utf-8"),
+                ),
+                (
+                    CONTENT_DISPOSITION,
+                    HeaderValue::from_str(&format!("attachment; filename=\"recall-{}.csv\"", *id))?,
+                ),
+            ],
+            render_recall_csv(&report)?,
+        )
+            .into_response()),
+    }
+}
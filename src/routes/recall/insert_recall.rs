@@ -0,0 +1,37 @@
+use axum::{debug_handler, Json};
+use chrono::Local;
+use validator::Validate;
+
+use crate::{
+    dtos::{RecallRequestDTO, RecallResponseDTO},
+    errors::AppError,
+    models::{Recall, RecallStatus},
+    services::RecallService,
+};
+
+#[cfg(debug_assertions)]
+use crate::AppState;
+
+#[debug_handler(state = AppState)]
+pub async fn insert_recall(
+    recall_service: RecallService,
+    body: Json<RecallRequestDTO>,
+) -> Result<Json<RecallResponseDTO>, AppError> {
+    body.validate()?;
+
+    let recall = Recall::new(
+        None,
+        body.crop_id,
+        body.batch_id,
+        body.cultivation.clone(),
+        body.start_date,
+        body.end_date,
+        body.reason.clone(),
+        RecallStatus::Open,
+        Local::now().naive_local(),
+    )?;
+
+    let recall = recall_service.insert(&recall).await?;
+
+    Ok(Json(RecallResponseDTO::from(&recall)))
+}
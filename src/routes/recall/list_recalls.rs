@@ -0,0 +1,15 @@
+use axum::{debug_handler, Json};
+
+use crate::{dtos::RecallResponseDTO, errors::AppError, services::RecallService};
+
+#[cfg(debug_assertions)]
+use crate::AppState;
+
+#[debug_handler(state = AppState)]
+pub async fn list_recalls(
+    recall_service: RecallService,
+) -> Result<Json<Vec<RecallResponseDTO>>, AppError> {
+    let recalls = recall_service.list().await?;
+
+    Ok(Json(recalls.iter().map(RecallResponseDTO::from).collect()))
+}
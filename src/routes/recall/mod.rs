@@ -0,0 +1,11 @@
+mod find_recall_by_id;
+mod get_recall_report;
+mod insert_recall;
+mod list_recalls;
+mod update_recall_status;
+
+pub use self::{
+    find_recall_by_id::find_recall_by_id, get_recall_report::get_recall_report,
+    insert_recall::insert_recall, list_recalls::list_recalls,
+    update_recall_status::update_recall_status,
+};
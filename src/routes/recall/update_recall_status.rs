@@ -0,0 +1,21 @@
+use axum::{debug_handler, extract::Path, Json};
+
+use crate::{
+    dtos::{RecallResponseDTO, RecallStatusRequestDTO},
+    errors::AppError,
+    services::RecallService,
+};
+
+#[cfg(debug_assertions)]
+use crate::AppState;
+
+#[debug_handler(state = AppState)]
+pub async fn update_recall_status(
+    recall_service: RecallService,
+    id: Path<i64>,
+    body: Json<RecallStatusRequestDTO>,
+) -> Result<Json<RecallResponseDTO>, AppError> {
+    let recall = recall_service.update_status(*id, body.status).await?;
+
+    Ok(Json(RecallResponseDTO::from(&recall)))
+}
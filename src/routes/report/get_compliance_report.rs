@@ -0,0 +1,56 @@
+use axum::{
+    debug_handler,
+    extract::Query,
+    http::{
+        header::{CONTENT_DISPOSITION, CONTENT_TYPE},
+        HeaderValue,
+    },
+    response::{IntoResponse, Response},
+};
+
+use crate::{
+    dtos::{ComplianceReportFormat, ComplianceReportQueryDTO},
+    errors::AppError,
+    misc::compliance_report::{render_compliance_csv, render_compliance_pdf},
+    services::ReportService,
+};
+
+#[cfg(debug_assertions)]
+use crate::AppState;
+
+#[debug_handler(state = AppState)]
+pub async fn get_compliance_report(
+    report_service: ReportService,
+    query: Query<ComplianceReportQueryDTO>,
+) -> Result<Response, AppError> {
+    let report = report_service
+        .compliance_report(query.from, query.to)
+        .await?;
+    let name = format!(
+        "rastreabilidade-{}-{}",
+        report.from.format("%Y%m%d"),
+        report.to.format("%Y%m%d")
+    );
+
+    let (content_type, extension, body) = match query.format.unwrap_or(ComplianceReportFormat::Pdf)
+    {
+        ComplianceReportFormat::Pdf => ("application/pdf", "pdf", render_compliance_pdf(&report)?),
+        ComplianceReportFormat::Csv => (
+            "text/csv; charset=utf-8",
+            "csv",
+            render_compliance_csv(&report)?,
+        ),
+    };
+
+    Ok((
+        [
+            (CONTENT_TYPE, HeaderValue::from_static(content_type)),
+            (
+                CONTENT_DISPOSITION,
+                HeaderValue::from_str(&format!("attachment; filename=\"{name}.{extension}\""))?,
+            ),
+        ],
+        body,
+    )
+        .into_response())
+}
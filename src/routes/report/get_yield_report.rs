@@ -0,0 +1,48 @@
+use axum::{
+    debug_handler,
+    extract::Query,
+    http::{
+        header::{CONTENT_DISPOSITION, CONTENT_TYPE},
+        HeaderValue,
+    },
+    response::{IntoResponse, Response},
+    Json,
+};
+
+use crate::{
+    dtos::{YieldReportDTO, YieldReportFormat, YieldReportQueryDTO},
+    errors::AppError,
+    misc::yield_report::render_yield_csv,
+    services::ReportService,
+};
+
+#[cfg(debug_assertions)]
+use crate::AppState;
+
+#[debug_handler(state = AppState)]
+pub async fn get_yield_report(
+    report_service: ReportService,
+    query: Query<YieldReportQueryDTO>,
+) -> Result<Response, AppError> {
+    let report = report_service
+        .yield_report(query.from, query.to, query.group_by)
+        .await?;
+
+    match query.format.unwrap_or(YieldReportFormat::Json) {
+        YieldReportFormat::Json => Ok(Json(YieldReportDTO::from(&report)).into_response()),
+        YieldReportFormat::Csv => Ok((
+            [
+                (
+                    CONTENT_TYPE,
+                    HeaderValue::from_static("text/csv; charset=utf-8"),
+                ),
+                (
+                    CONTENT_DISPOSITION,
+                    HeaderValue::from_static("attachment; filename=\"yield.csv\""),
+                ),
+            ],
+            render_yield_csv(&report)?,
+        )
+            .into_response()),
+    }
+}
@@ -0,0 +1,4 @@
+mod get_compliance_report;
+mod get_yield_report;
+
+pub use self::{get_compliance_report::get_compliance_report, get_yield_report::get_yield_report};
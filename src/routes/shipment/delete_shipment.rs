@@ -0,0 +1,16 @@
+use axum::{debug_handler, extract::Path, Json};
+
+use crate::{errors::AppError, services::ShipmentService};
+
+#[cfg(debug_assertions)]
+use crate::AppState;
+
+#[debug_handler(state = AppState)]
+pub async fn delete_shipment(
+    shipment_service: ShipmentService,
+    id: Path<i64>,
+) -> Result<Json<()>, AppError> {
+    shipment_service.delete(*id).await?;
+
+    Ok(Json(()))
+}
@@ -0,0 +1,16 @@
+use axum::{debug_handler, extract::Path, Json};
+
+use crate::{dtos::ShipmentResponseDTO, errors::AppError, services::ShipmentService};
+
+#[cfg(debug_assertions)]
+use crate::AppState;
+
+#[debug_handler(state = AppState)]
+pub async fn find_shipment_by_id(
+    shipment_service: ShipmentService,
+    id: Path<i64>,
+) -> Result<Json<ShipmentResponseDTO>, AppError> {
+    let shipment = shipment_service.find_by_id(*id).await?;
+
+    Ok(Json(ShipmentResponseDTO::from(&shipment)))
+}
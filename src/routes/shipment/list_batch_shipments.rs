@@ -0,0 +1,18 @@
+use axum::{debug_handler, extract::Path, Json};
+
+use crate::{dtos::ShipmentResponseDTO, errors::AppError, services::ShipmentService};
+
+#[cfg(debug_assertions)]
+use crate::AppState;
+
+#[debug_handler(state = AppState)]
+pub async fn list_batch_shipments(
+    shipment_service: ShipmentService,
+    id: Path<i64>,
+) -> Result<Json<Vec<ShipmentResponseDTO>>, AppError> {
+    let shipments = shipment_service.find_by_batch_id(*id).await?;
+
+    Ok(Json(
+        shipments.iter().map(ShipmentResponseDTO::from).collect(),
+    ))
+}
@@ -0,0 +1,17 @@
+use axum::{debug_handler, Json};
+
+use crate::{dtos::ShipmentResponseDTO, errors::AppError, services::ShipmentService};
+
+#[cfg(debug_assertions)]
+use crate::AppState;
+
+#[debug_handler(state = AppState)]
+pub async fn list_shipments(
+    shipment_service: ShipmentService,
+) -> Result<Json<Vec<ShipmentResponseDTO>>, AppError> {
+    let shipments = shipment_service.list().await?;
+
+    Ok(Json(
+        shipments.iter().map(ShipmentResponseDTO::from).collect(),
+    ))
+}
@@ -0,0 +1,12 @@
+mod delete_shipment;
+mod find_shipment_by_id;
+mod insert_shipment;
+mod list_batch_shipments;
+mod list_shipments;
+mod update_shipment;
+
+pub use self::{
+    delete_shipment::delete_shipment, find_shipment_by_id::find_shipment_by_id,
+    insert_shipment::insert_shipment, list_batch_shipments::list_batch_shipments,
+    list_shipments::list_shipments, update_shipment::update_shipment,
+};
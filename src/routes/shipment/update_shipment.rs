@@ -0,0 +1,38 @@
+use axum::{debug_handler, extract::Path, Json};
+use validator::Validate;
+
+use crate::{
+    dtos::{ShipmentRequestDTO, ShipmentResponseDTO},
+    errors::AppError,
+    models::{Shipment, ShipmentItem},
+    services::ShipmentService,
+};
+
+#[cfg(debug_assertions)]
+use crate::AppState;
+
+#[debug_handler(state = AppState)]
+pub async fn update_shipment(
+    shipment_service: ShipmentService,
+    id: Path<i64>,
+    body: Json<ShipmentRequestDTO>,
+) -> Result<Json<ShipmentResponseDTO>, AppError> {
+    body.validate()?;
+
+    let items = body
+        .items
+        .iter()
+        .map(|item| ShipmentItem::new(None, item.batch_id, item.quantity))
+        .collect::<Result<Vec<_>, _>>()?;
+    let shipment = Shipment::new(
+        None,
+        body.customer_id,
+        body.dispatched_at,
+        body.invoice_number.clone(),
+        items,
+    )?;
+
+    let shipment = shipment_service.update(*id, &shipment).await?;
+
+    Ok(Json(ShipmentResponseDTO::from(&shipment)))
+}
@@ -0,0 +1,154 @@
+use axum::{async_trait, extract::FromRequestParts, http::request::Parts};
+use chrono::Local;
+use serde_json::Value;
+use sqlx::{SqliteConnection, SqlitePool};
+
+use crate::{
+    errors::AppError,
+    misc::audit::{actor, diff},
+    models::{AuditAction, AuditEntity, AuditEntry},
+    repositories::AuditRepository,
+    StateTrait,
+};
+
+pub struct AuditService {
+    repository: AuditRepository,
+    actor: Option<String>,
+}
+
+impl AuditService {
+    pub fn new(pool: Box<SqlitePool>) -> Self {
+        Self {
+            repository: AuditRepository::new(pool),
+            actor: None,
+        }
+    }
+
+    /// Records the changes made from now on as done by `actor`.
+    pub fn with_actor(mut self, actor: Option<String>) -> Self {
+        self.actor = actor;
+        self
+    }
+
+    pub async fn list(
+        &self,
+        entity: Option<AuditEntity>,
+        entity_id: Option<i64>,
+    ) -> Result<Vec<AuditEntry>, AppError> {
+        self.repository.list(entity, entity_id).await
+    }
+
+    /// Writes the entry on `connection`, in the transaction of the change it
+    /// records, so neither is kept without the other.
+    async fn record(
+        &self,
+        connection: &mut SqliteConnection,
+        entity: AuditEntity,
+        entity_id: i64,
+        action: AuditAction,
+        before: Option<Value>,
+        after: Option<Value>,
+    ) -> Result<(), AppError> {
+        let entry = AuditEntry::new(
+            None,
+            entity,
+            entity_id,
+            action,
+            self.actor.clone(),
+            Local::now().naive_local(),
+            before,
+            after,
+        )?;
+        self.repository.insert(connection, entry).await?;
+        Ok(())
+    }
+
+    pub async fn created(
+        &self,
+        connection: &mut SqliteConnection,
+        entity: AuditEntity,
+        entity_id: i64,
+        after: Value,
+    ) -> Result<(), AppError> {
+        self.record(
+            connection,
+            entity,
+            entity_id,
+            AuditAction::Create,
+            None,
+            Some(after),
+        )
+        .await
+    }
+
+    /// Records the fields that differ between the snapshots, if any.
+    pub async fn updated(
+        &self,
+        connection: &mut SqliteConnection,
+        entity: AuditEntity,
+        entity_id: i64,
+        before: &Value,
+        after: &Value,
+    ) -> Result<(), AppError> {
+        let (before, after) = diff(before, after);
+        if before.is_empty() && after.is_empty() {
+            return Ok(());
+        }
+        self.record(
+            connection,
+            entity,
+            entity_id,
+            AuditAction::Update,
+            Some(before.into()),
+            Some(after.into()),
+        )
+        .await
+    }
+
+    pub async fn restored(
+        &self,
+        connection: &mut SqliteConnection,
+        entity: AuditEntity,
+        entity_id: i64,
+        after: Value,
+    ) -> Result<(), AppError> {
+        self.record(
+            connection,
+            entity,
+            entity_id,
+            AuditAction::Restore,
+            None,
+            Some(after),
+        )
+        .await
+    }
+
+    pub async fn deleted(
+        &self,
+        connection: &mut SqliteConnection,
+        entity: AuditEntity,
+        entity_id: i64,
+        before: Value,
+    ) -> Result<(), AppError> {
+        self.record(
+            connection,
+            entity,
+            entity_id,
+            AuditAction::Delete,
+            Some(before),
+            None,
+        )
+        .await
+    }
+}
+
+#[async_trait]
+impl<S> FromRequestParts<S> for AuditService
+where
+    S: Send + Sync + StateTrait,
+{
+    type Rejection = AppError;
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        Ok(Self::new(state.get_pool()).with_actor(actor(parts)?))
+    }
+}
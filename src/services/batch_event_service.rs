@@ -0,0 +1,48 @@
+use axum::{async_trait, extract::FromRequestParts, http::request::Parts};
+use sqlx::SqlitePool;
+
+use crate::{errors::AppError, models::BatchEvent, repositories::BatchEventRepository, StateTrait};
+
+use super::BatchService;
+
+pub struct BatchEventService {
+    repository: BatchEventRepository,
+    batch_service: BatchService,
+}
+
+impl BatchEventService {
+    pub fn new(pool: Box<SqlitePool>) -> Self {
+        Self {
+            repository: BatchEventRepository::new(pool.clone()),
+            batch_service: BatchService::new(pool),
+        }
+    }
+
+    pub async fn list(&self, batch_id: i64) -> Result<Vec<BatchEvent>, AppError> {
+        self.batch_service.find_by_id(batch_id).await?;
+        self.repository.list_by_batch_id(batch_id).await
+    }
+
+    pub async fn insert(&self, event: &BatchEvent) -> Result<BatchEvent, AppError> {
+        let batch = self.batch_service.find_by_id(event.batch_id()).await?;
+        if event.occurred_at().date() < batch.crop().planted_at() {
+            return Err(AppError::BadRequest(format!(
+                "A data do evento ({}) não pode ser anterior a data do plantio ({})",
+                event.occurred_at().format("%d/%m/%Y %H:%M"),
+                batch.crop().planted_at().format("%d/%m/%Y")
+            )));
+        }
+        self.repository.insert(event.clone()).await
+    }
+}
+
+#[async_trait]
+impl<S> FromRequestParts<S> for BatchEventService
+where
+    S: Send + Sync + StateTrait,
+{
+    type Rejection = AppError;
+    async fn from_request_parts(_parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        Ok(Self::new(state.get_pool()))
+    }
+}
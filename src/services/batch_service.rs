@@ -1,24 +1,64 @@
 use axum::{async_trait, extract::FromRequestParts, http::request::Parts};
+use chrono::Local;
+use futures::{Stream, TryStreamExt};
 use sqlx::SqlitePool;
 
 use crate::{
-    errors::AppError, misc::utils::generate_token, models::Batch, repositories::BatchRepository,
+    errors::AppError,
+    misc::{
+        audit::{actor, batch_snapshot},
+        etag::IfMatch,
+        utils::generate_token,
+    },
+    models::{AuditEntity, Batch, CatalogKind, LineageDirection, LineageGraph, QuantityUnit},
+    repositories::{
+        BatchRepository, CatalogRepository, ChemicalApplicationRepository, CropRepository,
+        HarvestRepository, PackingRepository, ProductRepository,
+    },
     StateTrait,
 };
 
+use super::AuditService;
+
 const CODE_LENGTH: usize = 12;
+const MAX_LINEAGE_DEPTH: i64 = 50;
 
 pub struct BatchService {
     repository: BatchRepository,
+    packing_repository: PackingRepository,
+    catalog_repository: CatalogRepository,
+    application_repository: ChemicalApplicationRepository,
+    harvest_repository: HarvestRepository,
+    product_repository: ProductRepository,
+    crop_repository: CropRepository,
+    audit_service: AuditService,
 }
 
 impl BatchService {
     pub fn new(pool: Box<SqlitePool>) -> Self {
         Self {
             repository: BatchRepository::new(pool.clone()),
+            packing_repository: PackingRepository::new(pool.clone()),
+            catalog_repository: CatalogRepository::new(pool.clone()),
+            application_repository: ChemicalApplicationRepository::new(pool.clone()),
+            harvest_repository: HarvestRepository::new(pool.clone()),
+            product_repository: ProductRepository::new(pool.clone()),
+            crop_repository: CropRepository::new(pool.clone()),
+            audit_service: AuditService::new(pool),
         }
     }
 
+    #[cfg(test)]
+    pub fn pool(&self) -> Box<SqlitePool> {
+        self.repository.pool()
+    }
+
+    /// Records the changes made through this service as done by `actor`.
+    pub fn with_actor(mut self, actor: Option<String>) -> Self {
+        self.audit_service = self.audit_service.with_actor(actor);
+        self
+    }
+
     async fn generate_code(&self) -> Result<String, AppError> {
         for _ in 0..500 {
             let code = generate_token(CODE_LENGTH);
@@ -40,43 +80,617 @@ impl BatchService {
             .is_empty())
     }
 
-    fn validate(&self, batch: &Batch) -> Result<(), AppError> {
+    /// Rejects batches dated before the crop was planted or inside the
+    /// withdrawal period of an agrochemical applied to the crop.
+    async fn validate(&self, batch: &Batch) -> Result<(), AppError> {
         if batch.date() < batch.crop().planted_at() {
             return Err(AppError::BadRequest(format!(
-                "A data do lote ({}) não pode ser anterior a data da colheita do plantio ({})",
+                "A data do lote ({}) não pode ser anterior a data do plantio ({})",
                 batch.date().format("%d/%m/%Y"),
-                batch.crop().harvested_at().unwrap().format("%d/%m/%Y")
+                batch.crop().planted_at().format("%d/%m/%Y")
             )));
         }
+
+        let crop_id = batch.crop().id().unwrap();
+        // Without a recorded harvest, the batch date stands in for it.
+        let mut harvested_at = batch.date();
+        if let Some(harvest_id) = batch.harvest_id() {
+            let harvest = self.harvest_repository.find_by_id(harvest_id).await?;
+            if harvest.crop_id() != crop_id {
+                return Err(AppError::BadRequest(format!(
+                    "A colheita de ID {harvest_id} não pertence ao plantio {}",
+                    batch.crop().name()
+                )));
+            }
+            if batch.date() < harvest.harvested_at() {
+                return Err(AppError::BadRequest(format!(
+                    "A data do lote ({}) não pode ser anterior a data da colheita ({})",
+                    batch.date().format("%d/%m/%Y"),
+                    harvest.harvested_at().format("%d/%m/%Y")
+                )));
+            }
+            harvested_at = harvest.harvested_at();
+        }
+
+        if let Some(product_id) = batch.product_id() {
+            self.product_repository.find_by_id(product_id).await?;
+        }
+
+        for application in self.application_repository.list_by_crop_id(crop_id).await? {
+            if application.is_withdrawal_active_on(harvested_at) {
+                return Err(AppError::BadRequest(format!(
+                    "A data da colheita ({}) está dentro do período de carência do produto {} ({}) aplicado no plantio {} em {}. A colheita só é permitida a partir de {}",
+                    harvested_at.format("%d/%m/%Y"),
+                    application.product(),
+                    application.active_ingredient(),
+                    batch.crop().name(),
+                    application.applied_at().format("%d/%m/%Y"),
+                    application.withdrawal_ends_at().format("%d/%m/%Y")
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Code of the `kind` catalog entry matching `code`, ignoring case. An
+    /// inactive entry is only accepted when the batch already had it.
+    async fn resolve_entry(
+        &self,
+        kind: CatalogKind,
+        code: &str,
+        kept: bool,
+    ) -> Result<String, AppError> {
+        match self.catalog_repository.find_by_code(kind, code).await? {
+            Some(entry) if entry.active() || kept => Ok(entry.code().to_string()),
+            Some(entry) => Err(AppError::BadRequest(format!(
+                "O código {} do catálogo de {} está inativo",
+                entry.code(),
+                kind.catalog_name()
+            ))),
+            None => Err(AppError::BadRequest(format!(
+                "O código {code} não existe no catálogo de {}",
+                kind.catalog_name()
+            ))),
+        }
+    }
+
+    /// Replaces the classification, processing and packing of `batch` by the
+    /// catalog codes they match. `current` is the batch being changed, whose
+    /// values stay accepted even if their entries were deactivated since.
+    async fn resolve_catalogs(
+        &self,
+        batch: &mut Batch,
+        current: Option<&Batch>,
+    ) -> Result<(), AppError> {
+        let same = |a: &Option<String>, b: &str| {
+            a.as_deref()
+                .is_some_and(|value| value.eq_ignore_ascii_case(b))
+        };
+
+        if let Some(code) = batch.classification().clone() {
+            let kept = current.is_some_and(|current| same(current.classification(), &code));
+            let code = self
+                .resolve_entry(CatalogKind::Classification, &code, kept)
+                .await?;
+            batch.set_classification(Some(code));
+        }
+        if let Some(code) = batch.processing().clone() {
+            let kept = current.is_some_and(|current| same(current.processing(), &code));
+            let code = self
+                .resolve_entry(CatalogKind::Processing, &code, kept)
+                .await?;
+            batch.set_processing(Some(code));
+        }
+
+        let code = batch.packing().to_string();
+        let kept = current.is_some_and(|current| current.packing().eq_ignore_ascii_case(&code));
+        match self.packing_repository.find_by_code(&code).await? {
+            Some(packing) if packing.active() || kept => {
+                batch.set_packing(packing.code().to_string())
+            }
+            Some(packing) => {
+                return Err(AppError::BadRequest(format!(
+                    "O código {} do catálogo de embalagens está inativo",
+                    packing.code()
+                )))
+            }
+            None => {
+                return Err(AppError::BadRequest(format!(
+                    "O código {code} não existe no catálogo de embalagens"
+                )))
+            }
+        }
+
         Ok(())
     }
 
+    /// Whether a batch that was not deleted came from the crop.
     pub async fn is_crop_in_use(&self, crop_id: i64) -> Result<bool, AppError> {
-        Ok(!self.repository.find_by_crop_id(crop_id).await?.is_empty())
+        Ok(self
+            .repository
+            .find_by_crop_id(crop_id)
+            .await?
+            .iter()
+            .any(|batch| batch.deleted_at().is_none()))
+    }
+
+    pub async fn list(&self, include_deleted: bool) -> Result<Vec<Batch>, AppError> {
+        self.repository.list(include_deleted).await
     }
 
-    pub async fn list(&self) -> Result<Vec<Batch>, AppError> {
-        self.repository.list().await
+    /// Every batch, fetched as it is read and expressed in `unit` when given.
+    pub fn stream(
+        &self,
+        unit: Option<QuantityUnit>,
+        include_deleted: bool,
+    ) -> impl Stream<Item = Result<Batch, AppError>> + Send + '_ {
+        self.repository
+            .stream(include_deleted)
+            .and_then(move |batch| async move {
+                match unit {
+                    Some(unit) => self.convert(batch, unit).await,
+                    None => Ok(batch),
+                }
+            })
     }
 
+    /// The batch, unless it was deleted.
     pub async fn find_by_id(&self, id: i64) -> Result<Batch, AppError> {
+        let batch = self.repository.find_by_id(id).await?;
+        if batch.deleted_at().is_some() {
+            return Err(AppError::NotFound(format!(
+                "Lote de ID {id} não encontrado"
+            )));
+        }
+        Ok(batch)
+    }
+
+    pub async fn find_by_id_including_deleted(&self, id: i64) -> Result<Batch, AppError> {
         self.repository.find_by_id(id).await
     }
 
-    pub async fn insert(&self, mut batch: Batch) -> Result<Batch, AppError> {
+    /// Deleted batches are not tracked, though their codes stay reserved.
+    pub async fn find_by_tracking_code(&self, code: &str) -> Result<Batch, AppError> {
+        let mut batch = self
+            .repository
+            .find_by_tracking_code(code)
+            .await?
+            .into_iter()
+            .find(|batch| batch.deleted_at().is_none())
+            .ok_or_else(|| {
+                AppError::NotFound(format!("Lote com código de rastreio {code} não encontrado"))
+            })?;
+        batch.set_origins(self.repository.find_origins(batch.id().unwrap()).await?);
+        Ok(batch)
+    }
+
+    /// Weight of one package of `packing`, if registered.
+    async fn kg_per_unit(&self, packing: &str) -> Result<Option<f64>, AppError> {
+        Ok(self
+            .packing_repository
+            .find_by_code(packing)
+            .await?
+            .and_then(|packing| packing.kg_per_unit()))
+    }
+
+    /// Converts `quantity`, given in the unit and packing of `from`, to the
+    /// unit and packing of `to`. Packages of different packings are only
+    /// compared through their weights, never taken as equal.
+    async fn repack(&self, quantity: f64, from: &Batch, to: &Batch) -> Result<f64, AppError> {
+        let (from_unit, to_unit) = (from.quantity_unit(), to.quantity_unit());
+        let same_packing = from.packing().eq_ignore_ascii_case(to.packing());
+        if from_unit == to_unit && (!from_unit.is_package() || same_packing) {
+            return Ok(quantity);
+        }
+
+        let from_kg = self.kg_per_unit(from.packing()).await?;
+        let to_kg = self.kg_per_unit(to.packing()).await?;
+        from_unit
+            .convert(quantity, QuantityUnit::Kg, from_kg)
+            .and_then(|kg| QuantityUnit::Kg.convert(kg, to_unit, to_kg))
+            .ok_or_else(|| {
+                AppError::BadRequest(format!(
+                    "Não é possível converter {} {} da embalagem \"{}\" para {} da embalagem \"{}\"",
+                    quantity,
+                    from_unit.as_str(),
+                    from.packing(),
+                    to_unit.as_str(),
+                    to.packing()
+                ))
+            })
+    }
+
+    /// Expresses the quantities of `batch` in `unit`. Package units are
+    /// converted through the weight registered for the batch's packing.
+    pub async fn convert(&self, mut batch: Batch, unit: QuantityUnit) -> Result<Batch, AppError> {
+        let from = batch.quantity_unit();
+        if from == unit {
+            return Ok(batch);
+        }
+
+        let kg_per_unit = self.kg_per_unit(batch.packing()).await?;
+        let convert = |value: f64| {
+            from.convert(value, unit, kg_per_unit).ok_or_else(|| {
+                AppError::BadRequest(format!(
+                    "Não é possível converter a quantidade do lote {} de {} para {} com a embalagem \"{}\"",
+                    batch.tracking_code().as_deref().unwrap_or_default(),
+                    from.as_str(),
+                    unit.as_str(),
+                    batch.packing()
+                ))
+            })
+        };
+        let quantity = convert(batch.quantity())?;
+        let shipped_quantity = convert(batch.shipped_quantity())?;
+
+        batch.set_quantity(quantity);
+        batch.set_shipped_quantity(shipped_quantity);
+        batch.set_quantity_unit(unit);
+        Ok(batch)
+    }
+
+    /// Gives `batch` a tracking code and checks it as [`BatchService::insert`]
+    /// would, without inserting it.
+    pub async fn prepare_insert(&self, mut batch: Batch) -> Result<Batch, AppError> {
         let tracking_code = self.generate_code().await?;
         batch.set_tracking_code(Some(tracking_code));
-        self.validate(&batch)?;
-        self.repository.insert(batch).await
+        self.resolve_catalogs(&mut batch, None).await?;
+        self.validate(&batch).await?;
+        Ok(batch)
+    }
+
+    pub async fn insert(&self, batch: Batch) -> Result<Batch, AppError> {
+        let batch = self.prepare_insert(batch).await?;
+        let mut transaction = self.repository.begin().await?;
+        let batch = self.repository.insert(&mut transaction, batch).await?;
+        self.audit_service
+            .created(
+                &mut transaction,
+                AuditEntity::Batch,
+                batch.id().unwrap(),
+                batch_snapshot(&batch),
+            )
+            .await?;
+        transaction.commit().await?;
+        Ok(batch)
+    }
+
+    /// Inserts batches checked by [`BatchService::prepare_insert`] in a
+    /// single transaction.
+    pub async fn insert_all(&self, batches: Vec<Batch>) -> Result<Vec<Batch>, AppError> {
+        let mut transaction = self.repository.begin().await?;
+        let batches = self
+            .repository
+            .insert_all(&mut transaction, batches)
+            .await?;
+        for batch in &batches {
+            self.audit_service
+                .created(
+                    &mut transaction,
+                    AuditEntity::Batch,
+                    batch.id().unwrap(),
+                    batch_snapshot(batch),
+                )
+                .await?;
+        }
+        transaction.commit().await?;
+        Ok(batches)
     }
 
-    pub async fn update(&self, id: i64, batch: &Batch) -> Result<Batch, AppError> {
-        self.validate(batch)?;
-        self.repository.update(id, batch.clone()).await
+    /// Refused unless `if_match` names the current version, so a change
+    /// made since the batch was read isn't overwritten.
+    pub async fn update(
+        &self,
+        id: i64,
+        batch: &Batch,
+        if_match: &IfMatch,
+    ) -> Result<Batch, AppError> {
+        let current = self.find_by_id(id).await?;
+        if !if_match.matches(current.version()) {
+            return Err(BatchRepository::stale(id));
+        }
+        let mut batch = batch.clone();
+        batch.set_id(Some(id));
+        batch.set_tracking_code(current.tracking_code().clone());
+        batch.set_shipped_quantity(current.shipped_quantity());
+
+        if batch.quantity() < current.shipped_quantity() {
+            return Err(BatchRepository::over_shipped(
+                batch.quantity(),
+                current.shipped_quantity(),
+            ));
+        }
+        if current.shipped_quantity() > 0.0 && batch.quantity_unit() != current.quantity_unit() {
+            return Err(AppError::BadRequest(format!(
+                "O lote de ID {id} possui remessas, e portanto sua unidade não pode ser alterada."
+            )));
+        }
+        self.resolve_catalogs(&mut batch, Some(&current)).await?;
+        self.validate(&batch).await?;
+        let mut transaction = self.repository.begin().await?;
+        let batch = self
+            .repository
+            .update(&mut transaction, id, current.version(), batch)
+            .await?;
+        self.audit_service
+            .updated(
+                &mut transaction,
+                AuditEntity::Batch,
+                id,
+                &batch_snapshot(&current),
+                &batch_snapshot(&batch),
+            )
+            .await?;
+        transaction.commit().await?;
+        Ok(batch)
     }
 
-    pub async fn delete(&self, id: i64) -> Result<(), AppError> {
-        self.repository.delete(id).await
+    /// Moves part of batch `id` into `children`. Children packed differently
+    /// from the parent take their weight from it, converted to the parent's
+    /// unit and packing.
+    pub async fn split(&self, id: i64, mut children: Vec<Batch>) -> Result<Vec<Batch>, AppError> {
+        let mut parent = self.find_by_id(id).await?;
+
+        let mut codes: Vec<String> = Vec::with_capacity(children.len());
+        for child in children.iter_mut() {
+            let mut code = self.generate_code().await?;
+            while codes.contains(&code) {
+                code = self.generate_code().await?;
+            }
+            codes.push(code.clone());
+            child.set_tracking_code(Some(code));
+            self.resolve_catalogs(child, Some(&parent)).await?;
+            self.validate(child).await?;
+        }
+
+        let mut moved: Vec<(Batch, f64)> = Vec::with_capacity(children.len());
+        for child in children {
+            let quantity = self.repack(child.quantity(), &child, &parent).await?;
+            moved.push((child, quantity));
+        }
+
+        let total: f64 = moved.iter().map(|(_, quantity)| quantity).sum();
+        if total > parent.available_quantity() {
+            return Err(AppError::BadRequest(format!(
+                "A soma das quantidades dos lotes filhos ({}) excede a quantidade disponível do lote {} ({})",
+                total,
+                parent.tracking_code().as_ref().unwrap(),
+                parent.available_quantity()
+            )));
+        }
+
+        let mut transaction = self.repository.begin().await?;
+        let children = self
+            .repository
+            .split(&mut transaction, &parent, moved)
+            .await?;
+        let before = batch_snapshot(&parent);
+        parent.set_quantity(parent.quantity() - total);
+
+        self.audit_service
+            .updated(
+                &mut transaction,
+                AuditEntity::Batch,
+                id,
+                &before,
+                &batch_snapshot(&parent),
+            )
+            .await?;
+        for child in &children {
+            self.audit_service
+                .created(
+                    &mut transaction,
+                    AuditEntity::Batch,
+                    child.id().unwrap(),
+                    batch_snapshot(child),
+                )
+                .await?;
+        }
+        transaction.commit().await?;
+        Ok(children)
+    }
+
+    /// Creates `batch` out of `sources`, given as batch ids and the quantity
+    /// taken from each one (all of it when `None`). The crop that contributes
+    /// the most becomes the batch's main crop.
+    pub async fn merge(
+        &self,
+        mut batch: Batch,
+        sources: Vec<(i64, Option<f64>)>,
+    ) -> Result<Batch, AppError> {
+        if sources.len() < 2 {
+            return Err(AppError::BadRequest(
+                "Informe ao menos dois lotes de origem".to_string(),
+            ));
+        }
+
+        let mut loaded: Vec<(Batch, f64)> = Vec::with_capacity(sources.len());
+        for (id, quantity) in sources {
+            if loaded.iter().any(|(source, _)| *source.id() == Some(id)) {
+                return Err(AppError::BadRequest(format!(
+                    "O lote de ID {id} foi informado mais de uma vez"
+                )));
+            }
+
+            let source = self.find_by_id(id).await?;
+            let quantity = quantity.unwrap_or(source.available_quantity());
+            if quantity <= 0.0 || quantity > source.available_quantity() {
+                return Err(AppError::BadRequest(format!(
+                    "A quantidade retirada do lote {} ({}) deve ser maior que zero e não pode exceder a quantidade disponível do lote ({})",
+                    source.tracking_code().as_ref().unwrap(),
+                    quantity,
+                    source.available_quantity()
+                )));
+            }
+            if batch.date() < source.date() {
+                return Err(AppError::BadRequest(format!(
+                    "A data do lote ({}) não pode ser anterior à data do lote de origem {} ({})",
+                    batch.date().format("%d/%m/%Y"),
+                    source.tracking_code().as_ref().unwrap(),
+                    source.date().format("%d/%m/%Y")
+                )));
+            }
+            loaded.push((source, quantity));
+        }
+
+        let (main, _) = loaded
+            .iter()
+            .max_by(|(_, a), (_, b)| a.total_cmp(b))
+            .unwrap();
+        batch.set_crop(main.crop().clone());
+        batch.set_quantity_unit(main.quantity_unit());
+        self.resolve_catalogs(&mut batch, None).await?;
+        // Sources packed differently contribute their weight.
+        let mut total = 0.0;
+        for (source, quantity) in &loaded {
+            total += self.repack(*quantity, source, &batch).await?;
+        }
+        batch.set_quantity(total);
+        batch.set_tracking_code(Some(self.generate_code().await?));
+        self.validate(&batch).await?;
+
+        let mut transaction = self.repository.begin().await?;
+        let mut batch = self
+            .repository
+            .merge(&mut transaction, &loaded, batch)
+            .await?;
+
+        for (source, quantity) in &loaded {
+            let mut after = source.clone();
+            after.set_quantity(source.quantity() - quantity);
+            self.audit_service
+                .updated(
+                    &mut transaction,
+                    AuditEntity::Batch,
+                    source.id().unwrap(),
+                    &batch_snapshot(source),
+                    &batch_snapshot(&after),
+                )
+                .await?;
+        }
+        self.audit_service
+            .created(
+                &mut transaction,
+                AuditEntity::Batch,
+                batch.id().unwrap(),
+                batch_snapshot(&batch),
+            )
+            .await?;
+        transaction.commit().await?;
+
+        batch.set_origins(self.repository.find_origins(batch.id().unwrap()).await?);
+        Ok(batch)
+    }
+
+    /// Ancestry (`Up`) or descendants (`Down`) of batch `id`, up to `depth`
+    /// edges away, as a graph including the batch itself.
+    pub async fn lineage(
+        &self,
+        id: i64,
+        direction: LineageDirection,
+        depth: i64,
+    ) -> Result<LineageGraph, AppError> {
+        if !(1..=MAX_LINEAGE_DEPTH).contains(&depth) {
+            return Err(AppError::BadRequest(format!(
+                "A profundidade deve estar entre 1 e {MAX_LINEAGE_DEPTH}"
+            )));
+        }
+
+        let root = self.find_by_id(id).await?;
+        let links = self.repository.find_lineage(id, direction, depth).await?;
+
+        Ok(LineageGraph::new(root, links))
+    }
+
+    /// Every batch derived from batch `id` through splits and merges, at any
+    /// depth.
+    pub async fn descendants(&self, id: i64) -> Result<Vec<Batch>, AppError> {
+        let root = self.repository.find_by_id(id).await?;
+        let links = self
+            .repository
+            .find_lineage(id, LineageDirection::Down, MAX_LINEAGE_DEPTH)
+            .await?;
+
+        Ok(LineageGraph::new(root, links).batches()[1..].to_vec())
+    }
+
+    pub async fn find_by_crop_id(&self, crop_id: i64) -> Result<Vec<Batch>, AppError> {
+        self.repository.find_by_crop_id(crop_id).await
+    }
+
+    pub async fn find_by_cultivation(
+        &self,
+        cultivation: &str,
+        start: chrono::NaiveDate,
+        end: chrono::NaiveDate,
+    ) -> Result<Vec<Batch>, AppError> {
+        self.repository
+            .find_by_cultivation(cultivation, start, end)
+            .await
+    }
+
+    /// Refused unless `if_match` names the current version.
+    pub async fn delete(&self, id: i64, if_match: &IfMatch) -> Result<(), AppError> {
+        let current = self.find_by_id(id).await?;
+        if !if_match.matches(current.version()) {
+            return Err(BatchRepository::stale(id));
+        }
+        if current.shipped_quantity() > 0.0 {
+            return Err(BatchRepository::shipped(id));
+        }
+        let mut transaction = self.repository.begin().await?;
+        self.repository
+            .delete(
+                &mut transaction,
+                id,
+                current.version(),
+                Local::now().naive_local(),
+            )
+            .await?;
+        self.audit_service
+            .deleted(
+                &mut transaction,
+                AuditEntity::Batch,
+                id,
+                batch_snapshot(&current),
+            )
+            .await?;
+        transaction.commit().await?;
+        Ok(())
+    }
+
+    /// Undoes the deletion of a batch. Its crop must not be deleted.
+    pub async fn restore(&self, id: i64) -> Result<Batch, AppError> {
+        let mut batch = self.find_by_id_including_deleted(id).await?;
+        if batch.deleted_at().is_none() {
+            return Err(BatchRepository::not_deleted(id));
+        }
+        let crop = self
+            .crop_repository
+            .find_by_id(batch.crop().id().unwrap())
+            .await?;
+        if crop.deleted_at().is_some() {
+            return Err(AppError::BadRequest(format!(
+                "O plantio {} do lote de ID {id} está excluído, e deve ser restaurado antes.",
+                crop.name()
+            )));
+        }
+
+        let mut transaction = self.repository.begin().await?;
+        self.repository.restore(&mut transaction, id).await?;
+        batch.set_deleted_at(None);
+        batch.set_version(batch.version() + 1);
+        self.audit_service
+            .restored(
+                &mut transaction,
+                AuditEntity::Batch,
+                id,
+                batch_snapshot(&batch),
+            )
+            .await?;
+        transaction.commit().await?;
+        Ok(batch)
     }
 }
 
@@ -86,47 +700,678 @@ where
     S: Send + Sync + StateTrait,
 {
     type Rejection = AppError;
-    async fn from_request_parts(_parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
-        Ok(Self::new(state.get_pool()))
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        Ok(Self::new(state.get_pool()).with_actor(actor(parts)?))
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{
+        models::{AuditAction, AuditEntry, ChemicalApplication},
+        services::{test_support::*, CropService},
+    };
+
+    async fn init() -> Result<BatchService, String> {
+        Ok(BatchService::new(database().await?))
+    }
+
+    #[tokio::test]
+    async fn generated_code_must_have_12_characters() -> Result<(), String> {
+        let service = init().await?;
+        for _ in 0..500 {
+            let code = service.generate_code().await.unwrap();
+            assert_eq!(code.len(), 12);
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn split_must_reduce_parent_quantity() -> Result<(), String> {
+        let service = init().await?;
+        let parent = insert_batch(&service, 100.0).await?;
+        let id = parent.id().unwrap();
 
-    async fn get_database_pool() -> Result<SqlitePool, String> {
-        Ok(SqlitePool::connect("sqlite::memory:")
+        let children = service
+            .split(id, vec![child_of(&parent, 30.0), child_of(&parent, 20.0)])
             .await
-            .map_err(|e| e.to_string())?)
+            .map_err(|e| e.to_string())?;
+
+        assert_eq!(children.len(), 2);
+        assert_ne!(children[0].tracking_code(), children[1].tracking_code());
+        let parent = service.find_by_id(id).await.map_err(|e| e.to_string())?;
+        assert_eq!(parent.quantity(), 50.0);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn split_must_not_exceed_parent_quantity() -> Result<(), String> {
+        let service = init().await?;
+        let parent = insert_batch(&service, 10.0).await?;
+        let id = parent.id().unwrap();
+
+        let result = service
+            .split(id, vec![child_of(&parent, 6.0), child_of(&parent, 5.0)])
+            .await;
+
+        assert!(matches!(result, Err(AppError::BadRequest(_))));
+        let parent = service.find_by_id(id).await.map_err(|e| e.to_string())?;
+        assert_eq!(parent.quantity(), 10.0);
+
+        Ok(())
     }
 
-    async fn prepare_database(pool: &SqlitePool) -> Result<(), String> {
-        sqlx::migrate!("./migrations")
-            .run(pool)
+    #[tokio::test]
+    async fn split_must_not_take_quantity_taken_since_read() -> Result<(), String> {
+        let service = init().await?;
+        let parent = insert_batch(&service, 10.0).await?;
+        let id = parent.id().unwrap();
+
+        service
+            .split(id, vec![child_of(&parent, 6.0)])
+            .await
+            .map_err(|e| e.to_string())?;
+        let mut child = child_of(&parent, 6.0);
+        child.set_tracking_code(Some(service.generate_code().await.unwrap()));
+        let mut transaction = service
+            .repository
+            .begin()
             .await
             .map_err(|e| e.to_string())?;
+        let result = service
+            .repository
+            .split(&mut transaction, &parent, vec![(child, 6.0)])
+            .await;
+        drop(transaction);
+
+        assert!(matches!(result, Err(AppError::PreconditionFailed(_))));
+        let parent = service.find_by_id(id).await.map_err(|e| e.to_string())?;
+        assert_eq!(parent.quantity(), 4.0);
 
         Ok(())
     }
 
-    async fn get_service(pool: Box<SqlitePool>) -> Result<BatchService, String> {
-        Ok(BatchService::new(pool))
+    #[tokio::test]
+    async fn merge_must_not_take_from_sources_changed_since_read() -> Result<(), String> {
+        let service = init().await?;
+        let crop = insert_crop(&service).await?;
+        let first = insert_batch_of(&service, &crop, "Caixa", 10.0, QuantityUnit::Kg).await?;
+        let second = insert_batch_of(&service, &crop, "Caixa", 10.0, QuantityUnit::Kg).await?;
+        let first_id = first.id().unwrap();
+
+        let mut changed = first.clone();
+        changed.set_quantity(8.0);
+        service
+            .update(
+                first_id,
+                &changed,
+                &IfMatch::Versions(vec![first.version()]),
+            )
+            .await
+            .map_err(|e| e.to_string())?;
+        let mut batch = child_of(&first, 20.0);
+        batch.set_tracking_code(Some(service.generate_code().await.unwrap()));
+        let mut transaction = service
+            .repository
+            .begin()
+            .await
+            .map_err(|e| e.to_string())?;
+        let result = service
+            .repository
+            .merge(&mut transaction, &[(first, 10.0), (second, 10.0)], batch)
+            .await;
+        drop(transaction);
+
+        assert!(matches!(result, Err(AppError::PreconditionFailed(_))));
+        let first = service
+            .find_by_id(first_id)
+            .await
+            .map_err(|e| e.to_string())?;
+        assert_eq!(first.quantity(), 8.0);
+
+        Ok(())
     }
 
-    async fn init() -> Result<BatchService, String> {
-        let pool = get_database_pool().await?;
-        prepare_database(&pool).await?;
-        get_service(Box::new(pool)).await
+    #[tokio::test]
+    async fn merge_must_record_lineage_and_reduce_sources() -> Result<(), String> {
+        let service = init().await?;
+        let crop = insert_crop(&service).await?;
+        let first = insert_batch_of(&service, &crop, "Caixa", 100.0, QuantityUnit::Kg).await?;
+        let second = insert_batch_of(&service, &crop, "Caixa", 50.0, QuantityUnit::Kg).await?;
+        let (first_id, second_id) = (first.id().unwrap(), second.id().unwrap());
+
+        let batch = service
+            .merge(
+                child_of(&first, 0.0),
+                vec![(first_id, Some(30.0)), (second_id, None)],
+            )
+            .await
+            .map_err(|e| e.to_string())?;
+
+        let id = batch.id().unwrap();
+        assert_eq!(batch.quantity(), 80.0);
+        let links = service
+            .repository
+            .list_links()
+            .await
+            .map_err(|e| e.to_string())?;
+        assert_eq!(links, vec![(first_id, id, 30.0), (second_id, id, 50.0)]);
+        let first = service
+            .find_by_id(first_id)
+            .await
+            .map_err(|e| e.to_string())?;
+        let second = service
+            .find_by_id(second_id)
+            .await
+            .map_err(|e| e.to_string())?;
+        assert_eq!((first.quantity(), second.quantity()), (70.0, 0.0));
+
+        Ok(())
     }
 
     #[tokio::test]
-    async fn generated_code_must_have_12_characters() -> Result<(), String> {
+    async fn merge_must_not_exceed_source_quantity() -> Result<(), String> {
         let service = init().await?;
-        for _ in 0..500 {
-            let code = service.generate_code().await.unwrap();
-            assert_eq!(code.len(), 12);
-        }
+        let crop = insert_crop(&service).await?;
+        let first = insert_batch_of(&service, &crop, "Caixa", 10.0, QuantityUnit::Kg).await?;
+        let second = insert_batch_of(&service, &crop, "Caixa", 10.0, QuantityUnit::Kg).await?;
+        let (first_id, second_id) = (first.id().unwrap(), second.id().unwrap());
+
+        let result = service
+            .merge(
+                child_of(&first, 0.0),
+                vec![(first_id, Some(11.0)), (second_id, None)],
+            )
+            .await;
+
+        assert!(matches!(result, Err(AppError::BadRequest(_))));
+        let first = service
+            .find_by_id(first_id)
+            .await
+            .map_err(|e| e.to_string())?;
+        assert_eq!(first.quantity(), 10.0);
+        assert!(service
+            .repository
+            .list_links()
+            .await
+            .map_err(|e| e.to_string())?
+            .is_empty());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn merge_must_reject_mismatched_units() -> Result<(), String> {
+        let service = init().await?;
+        let crop = insert_crop(&service).await?;
+        let first = insert_batch_of(&service, &crop, "Caixa", 10.0, QuantityUnit::Kg).await?;
+        let second = insert_batch_of(&service, &crop, "Caixa", 10.0, QuantityUnit::Box).await?;
+
+        let result = service
+            .merge(
+                child_of(&first, 0.0),
+                vec![(first.id().unwrap(), None), (second.id().unwrap(), None)],
+            )
+            .await;
+
+        assert!(matches!(result, Err(AppError::BadRequest(_))));
+        let second = service
+            .find_by_id(second.id().unwrap())
+            .await
+            .map_err(|e| e.to_string())?;
+        assert_eq!(second.quantity(), 10.0);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn split_must_take_the_weight_of_differently_packed_children() -> Result<(), String> {
+        let service = init().await?;
+        let crop = insert_crop(&service).await?;
+        insert_packing(&service, "Caixa 20 kg", Some(20.0)).await?;
+        insert_packing(&service, "Caixa 10 kg", Some(10.0)).await?;
+        let parent =
+            insert_batch_of(&service, &crop, "Caixa 20 kg", 10.0, QuantityUnit::Box).await?;
+        let id = parent.id().unwrap();
+
+        // 8 boxes of 10 kg weigh as much as 4 boxes of 20 kg.
+        let children = service
+            .split(
+                id,
+                vec![packed_child_of(
+                    &parent,
+                    "Caixa 10 kg",
+                    8.0,
+                    QuantityUnit::Box,
+                )],
+            )
+            .await
+            .map_err(|e| e.to_string())?;
+
+        assert_eq!(children[0].quantity(), 8.0);
+        let parent = service.find_by_id(id).await.map_err(|e| e.to_string())?;
+        assert_eq!(parent.quantity(), 6.0);
+        let links = service
+            .repository
+            .list_links()
+            .await
+            .map_err(|e| e.to_string())?;
+        assert_eq!(links, vec![(id, children[0].id().unwrap(), 4.0)]);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn merge_must_convert_packages_through_their_packings() -> Result<(), String> {
+        let service = init().await?;
+        let crop = insert_crop(&service).await?;
+        insert_packing(&service, "Caixa 20 kg", Some(20.0)).await?;
+        insert_packing(&service, "Caixa 10 kg", Some(10.0)).await?;
+        let first = insert_batch_of(&service, &crop, "Caixa 20 kg", 5.0, QuantityUnit::Box).await?;
+        let second =
+            insert_batch_of(&service, &crop, "Caixa 10 kg", 10.0, QuantityUnit::Box).await?;
+        let sources = vec![(first.id().unwrap(), None), (second.id().unwrap(), None)];
+
+        let batch = service
+            .merge(
+                packed_child_of(&first, "Caixa 20 kg", 0.0, QuantityUnit::Box),
+                sources,
+            )
+            .await
+            .map_err(|e| e.to_string())?;
+
+        assert_eq!(batch.quantity_unit(), QuantityUnit::Box);
+        assert_eq!(batch.quantity(), 10.0);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn merge_must_reject_packages_of_packings_without_weight() -> Result<(), String> {
+        let service = init().await?;
+        let crop = insert_crop(&service).await?;
+        let first = insert_batch_of(&service, &crop, "Caixa", 5.0, QuantityUnit::Box).await?;
+        let second = insert_batch_of(&service, &crop, "Engradado", 5.0, QuantityUnit::Box).await?;
+        let sources = vec![(first.id().unwrap(), None), (second.id().unwrap(), None)];
+
+        let result = service
+            .merge(
+                packed_child_of(&first, "Caixa", 0.0, QuantityUnit::Box),
+                sources,
+            )
+            .await;
+
+        assert!(matches!(result, Err(AppError::BadRequest(_))));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn closes_checked_against_a_stale_read_must_be_refused() -> Result<(), String> {
+        let service = init().await?;
+        let crop = insert_crop(&service).await?;
+        let crop_id = crop.id().unwrap();
+        let crop_service = CropService::new(service.repository.pool());
+        let day = |day| chrono::NaiveDate::from_ymd_opt(2024, 4, day).unwrap();
+
+        // As if its checks had run before the harvest was recorded.
+        insert_harvest(&service, &crop, 10).await?;
+        let mut transaction = service
+            .crop_repository
+            .begin()
+            .await
+            .map_err(|e| e.to_string())?;
+        let result = service
+            .crop_repository
+            .close(&mut transaction, crop_id, day(5))
+            .await;
+        drop(transaction);
+        assert!(matches!(result, Err(AppError::BadRequest(_))));
+
+        // As if its checks had run before the crop was closed.
+        crop_service
+            .close(crop_id, day(12))
+            .await
+            .map_err(|e| e.to_string())?;
+        let mut transaction = service
+            .crop_repository
+            .begin()
+            .await
+            .map_err(|e| e.to_string())?;
+        let result = service
+            .crop_repository
+            .close(&mut transaction, crop_id, day(15))
+            .await;
+        drop(transaction);
+        assert!(matches!(result, Err(AppError::BadRequest(_))));
+
+        let crop = crop_service
+            .find_by_id(crop_id)
+            .await
+            .map_err(|e| e.to_string())?;
+        assert_eq!(crop.closed_at(), &Some(day(12)));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn batches_must_not_be_harvested_within_withdrawal_period() -> Result<(), String> {
+        let service = init().await?;
+        let crop = insert_crop(&service).await?;
+        insert_packing(&service, "Caixa", None).await?;
+        // Harvesting is allowed again from April 9th.
+        let application = ChemicalApplication::new(
+            None,
+            crop.id().unwrap(),
+            "Fungicida".to_string(),
+            "Mancozebe".to_string(),
+            2.0,
+            "kg/ha".to_string(),
+            date(),
+            7,
+        )
+        .map_err(|e| e.to_string())?;
+        service
+            .application_repository
+            .insert(application)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        let result = service.insert(dated(&crop, 5, None)?).await;
+        assert!(matches!(result, Err(AppError::BadRequest(_))));
+        assert!(service.insert(dated(&crop, 9, None)?).await.is_ok());
+
+        // Packing after the period does not help a harvest made within it.
+        let early_harvest = insert_harvest(&service, &crop, 5).await?;
+        let result = service.insert(dated(&crop, 10, Some(early_harvest))?).await;
+        assert!(matches!(result, Err(AppError::BadRequest(_))));
+
+        let harvest = insert_harvest(&service, &crop, 9).await?;
+        assert!(service
+            .insert(dated(&crop, 10, Some(harvest))?)
+            .await
+            .is_ok());
+        let result = service.insert(dated(&crop, 8, Some(harvest))?).await;
+        assert!(matches!(result, Err(AppError::BadRequest(_))));
+
+        Ok(())
+    }
+
+    async fn audit_entries(service: &BatchService, id: i64) -> Result<Vec<AuditEntry>, String> {
+        service
+            .audit_service
+            .list(Some(AuditEntity::Batch), Some(id))
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    #[tokio::test]
+    async fn update_must_record_the_changed_fields() -> Result<(), String> {
+        let service = init().await?.with_actor(Some("ana".to_string()));
+        let batch = insert_batch(&service, 100.0).await?;
+        let id = batch.id().unwrap();
+
+        let mut changed = batch.clone();
+        changed.set_quantity(80.0);
+        service
+            .update(id, &changed, &IfMatch::Versions(vec![batch.version()]))
+            .await
+            .map_err(|e| e.to_string())?;
+
+        let entries = audit_entries(&service, id).await?;
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].action(), AuditAction::Create);
+        let update = &entries[1];
+        assert_eq!(update.action(), AuditAction::Update);
+        assert_eq!(update.actor().as_deref(), Some("ana"));
+        assert_eq!(
+            update.before(),
+            &Some(serde_json::json!({"quantity": 100.0}))
+        );
+        assert_eq!(update.after(), &Some(serde_json::json!({"quantity": 80.0})));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn changes_must_be_refused_when_based_on_a_stale_version() -> Result<(), String> {
+        let service = init().await?;
+        let batch = insert_batch(&service, 100.0).await?;
+        let id = batch.id().unwrap();
+        let read = IfMatch::Versions(vec![batch.version()]);
+
+        let mut changed = batch.clone();
+        changed.set_quantity(80.0);
+        service
+            .update(id, &changed, &read)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        changed.set_quantity(60.0);
+        assert!(matches!(
+            service.update(id, &changed, &read).await,
+            Err(AppError::PreconditionFailed(_))
+        ));
+        assert!(matches!(
+            service.delete(id, &read).await,
+            Err(AppError::PreconditionFailed(_))
+        ));
+        let batch = service.find_by_id(id).await.map_err(|e| e.to_string())?;
+        assert_eq!(batch.quantity(), 80.0);
+        assert_eq!(audit_entries(&service, id).await?.len(), 2);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn updates_checked_against_a_stale_read_must_not_go_below_shipped() -> Result<(), String>
+    {
+        let service = init().await?;
+        let batch = insert_batch(&service, 100.0).await?;
+        let id = batch.id().unwrap();
+
+        ship(&service, id, 60.0).await?;
+        // As if its check had run before the shipment was stored.
+        let mut changed = batch.clone();
+        changed.set_quantity(50.0);
+        let mut transaction = service
+            .repository
+            .begin()
+            .await
+            .map_err(|e| e.to_string())?;
+        let result = service
+            .repository
+            .update(&mut transaction, id, batch.version(), changed)
+            .await;
+        drop(transaction);
+
+        assert!(matches!(result, Err(AppError::BadRequest(_))));
+        let batch = service.find_by_id(id).await.map_err(|e| e.to_string())?;
+        assert_eq!(batch.quantity(), 100.0);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn split_must_record_the_parent_and_children() -> Result<(), String> {
+        let service = init().await?.with_actor(Some("ana".to_string()));
+        let parent = insert_batch(&service, 100.0).await?;
+        let id = parent.id().unwrap();
+
+        let children = service
+            .split(id, vec![child_of(&parent, 30.0)])
+            .await
+            .map_err(|e| e.to_string())?;
+
+        let entries = audit_entries(&service, id).await?;
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[1].action(), AuditAction::Update);
+        assert_eq!(
+            entries[1].after(),
+            &Some(serde_json::json!({"quantity": 70.0}))
+        );
+        let entries = audit_entries(&service, children[0].id().unwrap()).await?;
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].action(), AuditAction::Create);
+        assert_eq!(entries[0].actor().as_deref(), Some("ana"));
+        assert_eq!(
+            entries[0].after().as_ref().unwrap()["trackingCode"],
+            serde_json::json!(children[0].tracking_code())
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn deleted_batches_must_only_be_listed_when_asked_for() -> Result<(), String> {
+        let service = init().await?;
+        let batch = insert_batch(&service, 100.0).await?;
+        let id = batch.id().unwrap();
+
+        service
+            .delete(id, &IfMatch::Versions(vec![batch.version()]))
+            .await
+            .map_err(|e| e.to_string())?;
+
+        assert!(service
+            .list(false)
+            .await
+            .map_err(|e| e.to_string())?
+            .is_empty());
+        assert!(matches!(
+            service.find_by_id(id).await,
+            Err(AppError::NotFound(_))
+        ));
+        let batches = service.list(true).await.map_err(|e| e.to_string())?;
+        assert_eq!(batches.len(), 1);
+        assert!(batches[0].deleted_at().is_some());
+        let batch = service
+            .find_by_id_including_deleted(id)
+            .await
+            .map_err(|e| e.to_string())?;
+        assert!(batch.deleted_at().is_some());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn deleted_batches_must_keep_their_tracking_codes() -> Result<(), String> {
+        let service = init().await?;
+        let batch = insert_batch(&service, 100.0).await?;
+        service
+            .delete(batch.id().unwrap(), &IfMatch::Any)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        // Generated codes are retried until this says they are unused.
+        let code = batch.tracking_code().as_ref().unwrap();
+        assert!(service.code_exists(code).await.map_err(|e| e.to_string())?);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn batches_must_not_be_restored_while_their_crop_is_deleted() -> Result<(), String> {
+        let service = init().await?;
+        let batch = insert_batch(&service, 100.0).await?;
+        let id = batch.id().unwrap();
+        let crop_id = batch.crop().id().unwrap();
+        let crop_service = CropService::new(service.repository.pool());
+        service
+            .delete(id, &IfMatch::Any)
+            .await
+            .map_err(|e| e.to_string())?;
+        crop_service
+            .delete(crop_id, &IfMatch::Any)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        let result = service.restore(id).await;
+        assert!(matches!(result, Err(AppError::BadRequest(_))));
+
+        crop_service
+            .restore(crop_id)
+            .await
+            .map_err(|e| e.to_string())?;
+        let batch = service.restore(id).await.map_err(|e| e.to_string())?;
+        assert!(batch.deleted_at().is_none());
+        assert!(service.find_by_id(id).await.is_ok());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn deletes_checked_against_a_stale_read_must_not_drop_shipped_batches(
+    ) -> Result<(), String> {
+        let service = init().await?;
+        let batch = insert_batch(&service, 100.0).await?;
+        let id = batch.id().unwrap();
+
+        ship(&service, id, 60.0).await?;
+        // As if its check had run before the shipment was stored.
+        let mut transaction = service
+            .repository
+            .begin()
+            .await
+            .map_err(|e| e.to_string())?;
+        let result = service
+            .repository
+            .delete(
+                &mut transaction,
+                id,
+                batch.version(),
+                Local::now().naive_local(),
+            )
+            .await;
+        drop(transaction);
+
+        assert!(matches!(result, Err(AppError::BadRequest(_))));
+        assert!(service.find_by_id(id).await.is_ok());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn restores_checked_against_a_stale_read_must_not_repeat() -> Result<(), String> {
+        let service = init().await?;
+        let batch = insert_batch(&service, 100.0).await?;
+        let id = batch.id().unwrap();
+        let crop_id = batch.crop().id().unwrap();
+        let crop_service = CropService::new(service.repository.pool());
+        service
+            .delete(id, &IfMatch::Any)
+            .await
+            .map_err(|e| e.to_string())?;
+        crop_service
+            .delete(crop_id, &IfMatch::Any)
+            .await
+            .map_err(|e| e.to_string())?;
+        crop_service
+            .restore(crop_id)
+            .await
+            .map_err(|e| e.to_string())?;
+        service.restore(id).await.map_err(|e| e.to_string())?;
+
+        // As if their checks had run before the restores above.
+        let mut transaction = service
+            .repository
+            .begin()
+            .await
+            .map_err(|e| e.to_string())?;
+        let batch_result = service.repository.restore(&mut transaction, id).await;
+        let crop_result = service
+            .crop_repository
+            .restore(&mut transaction, crop_id)
+            .await;
+        drop(transaction);
+
+        assert!(matches!(batch_result, Err(AppError::BadRequest(_))));
+        assert!(matches!(crop_result, Err(AppError::BadRequest(_))));
+        let batch = service.find_by_id(id).await.map_err(|e| e.to_string())?;
+        assert_eq!(batch.version(), 3);
 
         Ok(())
     }
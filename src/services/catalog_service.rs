@@ -0,0 +1,98 @@
+use axum::{async_trait, extract::FromRequestParts, http::request::Parts};
+use sqlx::SqlitePool;
+
+use crate::{
+    errors::AppError,
+    models::{CatalogEntry, CatalogKind},
+    repositories::CatalogRepository,
+    StateTrait,
+};
+
+pub struct CatalogService {
+    repository: CatalogRepository,
+}
+
+impl CatalogService {
+    pub fn new(pool: Box<SqlitePool>) -> Self {
+        Self {
+            repository: CatalogRepository::new(pool),
+        }
+    }
+
+    async fn validate(&self, id: Option<i64>, entry: &CatalogEntry) -> Result<(), AppError> {
+        if let Some(existing) = self
+            .repository
+            .find_by_code(entry.kind(), entry.code())
+            .await?
+        {
+            if *existing.id() != id {
+                return Err(AppError::BadRequest(format!(
+                    "O código {} já existe no catálogo de {}",
+                    existing.code(),
+                    entry.kind().catalog_name()
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    pub async fn list(
+        &self,
+        kind: CatalogKind,
+        active: Option<bool>,
+    ) -> Result<Vec<CatalogEntry>, AppError> {
+        self.repository.list(kind, active).await
+    }
+
+    pub async fn find_by_id(&self, kind: CatalogKind, id: i64) -> Result<CatalogEntry, AppError> {
+        self.repository.find_by_id(kind, id).await
+    }
+
+    pub async fn insert(&self, entry: &CatalogEntry) -> Result<CatalogEntry, AppError> {
+        self.validate(None, entry).await?;
+        self.repository.insert(entry.clone()).await
+    }
+
+    /// Codes already used by batches cannot change, only their description
+    /// and whether they are active.
+    pub async fn update(&self, id: i64, entry: &CatalogEntry) -> Result<CatalogEntry, AppError> {
+        let current = self.find_by_id(entry.kind(), id).await?;
+        self.validate(Some(id), entry).await?;
+        if current.code() != entry.code()
+            && self
+                .repository
+                .count_batches(entry.kind(), current.code())
+                .await?
+                > 0
+        {
+            return Err(AppError::BadRequest(format!(
+                "O código {} está em uso por lotes, e portanto não pode ser alterado. Desative-o e cadastre um novo.",
+                current.code()
+            )));
+        }
+        self.repository.update(id, entry.clone()).await
+    }
+
+    pub async fn delete(&self, kind: CatalogKind, id: i64) -> Result<(), AppError> {
+        let entry = self.find_by_id(kind, id).await?;
+        if self.repository.count_batches(kind, entry.code()).await? > 0 {
+            return Err(AppError::BadRequest(format!(
+                "O código {} está em uso por lotes, e portanto não pode ser excluído. Desative-o em vez disso.",
+                entry.code()
+            )));
+        }
+        self.repository.delete(id).await
+    }
+}
+
+#[async_trait]
+impl<S> FromRequestParts<S> for CatalogService
+where
+    S: Send + Sync + StateTrait,
+{
+    type Rejection = AppError;
+    async fn from_request_parts(_parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        Ok(Self::new(state.get_pool()))
+    }
+}
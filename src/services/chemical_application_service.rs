@@ -0,0 +1,90 @@
+use axum::{async_trait, extract::FromRequestParts, http::request::Parts};
+use chrono::NaiveDate;
+use sqlx::SqlitePool;
+
+use crate::{
+    errors::AppError,
+    models::{ChemicalApplication, Crop},
+    repositories::ChemicalApplicationRepository,
+    StateTrait,
+};
+
+use super::CropService;
+
+pub struct ChemicalApplicationService {
+    repository: ChemicalApplicationRepository,
+    crop_service: CropService,
+}
+
+impl ChemicalApplicationService {
+    pub fn new(pool: Box<SqlitePool>) -> Self {
+        Self {
+            repository: ChemicalApplicationRepository::new(pool.clone()),
+            crop_service: CropService::new(pool),
+        }
+    }
+
+    pub async fn list(&self, crop_id: i64) -> Result<Vec<ChemicalApplication>, AppError> {
+        self.crop_service.find_by_id(crop_id).await?;
+        self.repository.list_by_crop_id(crop_id).await
+    }
+
+    /// Crops still inside a withdrawal period on `date`, each with the
+    /// applications keeping it there.
+    pub async fn in_withdrawal(
+        &self,
+        date: NaiveDate,
+    ) -> Result<Vec<(Crop, Vec<ChemicalApplication>)>, AppError> {
+        let mut crops: Vec<(Crop, Vec<ChemicalApplication>)> = vec![];
+        for application in self.repository.list_active_on(date).await? {
+            match crops
+                .iter_mut()
+                .find(|(crop, _)| *crop.id() == Some(application.crop_id()))
+            {
+                Some((_, applications)) => applications.push(application),
+                None => {
+                    let crop = self.crop_service.find_by_id(application.crop_id()).await?;
+                    crops.push((crop, vec![application]));
+                }
+            }
+        }
+
+        Ok(crops)
+    }
+
+    pub async fn insert(
+        &self,
+        application: &ChemicalApplication,
+    ) -> Result<ChemicalApplication, AppError> {
+        let crop = self.crop_service.find_by_id(application.crop_id()).await?;
+        if application.applied_at() < crop.planted_at() {
+            return Err(AppError::BadRequest(format!(
+                "A data da aplicação ({}) não pode ser anterior a data do plantio ({})",
+                application.applied_at().format("%d/%m/%Y"),
+                crop.planted_at().format("%d/%m/%Y")
+            )));
+        }
+        self.repository.insert(application.clone()).await
+    }
+
+    pub async fn delete(&self, crop_id: i64, id: i64) -> Result<(), AppError> {
+        let application = self.repository.find_by_id(id).await?;
+        if application.crop_id() != crop_id {
+            return Err(AppError::NotFound(format!(
+                "Aplicação de ID {id} não encontrada no plantio de ID {crop_id}"
+            )));
+        }
+        self.repository.delete(id).await
+    }
+}
+
+#[async_trait]
+impl<S> FromRequestParts<S> for ChemicalApplicationService
+where
+    S: Send + Sync + StateTrait,
+{
+    type Rejection = AppError;
+    async fn from_request_parts(_parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        Ok(Self::new(state.get_pool()))
+    }
+}
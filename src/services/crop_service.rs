@@ -1,30 +1,69 @@
 use axum::{async_trait, extract::FromRequestParts, http::request::Parts};
+use chrono::{Local, NaiveDate};
+use futures::{Stream, TryStreamExt};
 use sqlx::SqlitePool;
 
-use crate::{errors::AppError, models::Crop, repositories::CropRepository, StateTrait};
+use crate::{
+    errors::AppError,
+    misc::{
+        audit::{actor, crop_snapshot},
+        etag::IfMatch,
+    },
+    models::{AreaUnit, AuditEntity, Crop},
+    repositories::{
+        ChemicalApplicationRepository, CropRepository, HarvestRepository, PlotRepository,
+    },
+    StateTrait,
+};
 
-use super::BatchService;
+use super::{AuditService, BatchService};
+
+/// How much a crop's area may exceed its plot's, to absorb measurement
+/// differences between the field survey and the boundary.
+const PLOT_AREA_TOLERANCE: f64 = 0.01;
 
 pub struct CropService {
     repository: CropRepository,
+    plot_repository: PlotRepository,
+    application_repository: ChemicalApplicationRepository,
+    harvest_repository: HarvestRepository,
     batch_service: BatchService,
+    audit_service: AuditService,
 }
 
 impl CropService {
     pub fn new(pool: Box<SqlitePool>) -> Self {
         Self {
             repository: CropRepository::new(pool.clone()),
-            batch_service: BatchService::new(pool),
+            plot_repository: PlotRepository::new(pool.clone()),
+            application_repository: ChemicalApplicationRepository::new(pool.clone()),
+            harvest_repository: HarvestRepository::new(pool.clone()),
+            batch_service: BatchService::new(pool.clone()),
+            audit_service: AuditService::new(pool),
         }
     }
 
-    fn validate(&self, crop: &Crop) -> Result<(), AppError> {
-        if let Some(harvested_at) = crop.harvested_at() {
-            if *harvested_at < crop.planted_at() {
+    /// Records the changes made through this service as done by `actor`.
+    pub fn with_actor(mut self, actor: Option<String>) -> Self {
+        self.batch_service = self.batch_service.with_actor(actor.clone());
+        self.audit_service = self.audit_service.with_actor(actor);
+        self
+    }
+
+    /// Fills in the area of a crop planted on a plot when it was left as
+    /// zero, and rejects areas larger than the plot.
+    async fn validate(&self, crop: &mut Crop) -> Result<(), AppError> {
+        if let Some(plot_id) = crop.plot_id() {
+            let plot = self.plot_repository.find_by_id(plot_id).await?;
+            let plot_area = AreaUnit::Ha.convert(plot.area(), crop.area_unit());
+            if crop.area() == 0.0 {
+                crop.set_area(plot_area);
+            } else if crop.area() > plot_area * (1.0 + PLOT_AREA_TOLERANCE) {
                 return Err(AppError::BadRequest(format!(
-                    "A data da colheita ({}) não pode ser anterior a data do plantio ({})",
-                    harvested_at.format("%d/%m/%Y"),
-                    crop.planted_at().format("%d/%m/%Y")
+                    "A área do plantio ({:.2}) não pode exceder a área do talhão {} ({:.2})",
+                    crop.area(),
+                    plot.name(),
+                    plot_area
                 )));
             }
         }
@@ -32,36 +71,236 @@ impl CropService {
         Ok(())
     }
 
-    pub async fn list(&self) -> Result<Vec<Crop>, AppError> {
-        self.repository.list().await
+    pub async fn is_plot_in_use(&self, plot_id: i64) -> Result<bool, AppError> {
+        Ok(self.repository.count_by_plot_id(plot_id).await? > 0)
+    }
+
+    pub async fn list(&self, include_deleted: bool) -> Result<Vec<Crop>, AppError> {
+        self.repository.list(include_deleted).await
     }
 
+    /// Every crop, fetched as it is read and with its area in `unit` when
+    /// given.
+    pub fn stream(
+        &self,
+        unit: Option<AreaUnit>,
+        include_deleted: bool,
+    ) -> impl Stream<Item = Result<Crop, AppError>> + Send + '_ {
+        self.repository
+            .stream(include_deleted)
+            .map_ok(move |mut crop| {
+                if let Some(unit) = unit {
+                    crop.convert_area(unit);
+                }
+                crop
+            })
+    }
+
+    /// The crop, unless it was deleted.
     pub async fn find_by_id(&self, id: i64) -> Result<Crop, AppError> {
+        let crop = self.repository.find_by_id(id).await?;
+        if crop.deleted_at().is_some() {
+            return Err(AppError::NotFound(format!(
+                "Plantio de ID {id} não encontrado"
+            )));
+        }
+        Ok(crop)
+    }
+
+    pub async fn find_by_id_including_deleted(&self, id: i64) -> Result<Crop, AppError> {
         self.repository.find_by_id(id).await
     }
 
+    /// Checks `crop` as [`CropService::insert`] would, without inserting it.
+    pub async fn prepare_insert(&self, crop: &Crop) -> Result<Crop, AppError> {
+        let mut crop = crop.clone();
+        self.validate(&mut crop).await?;
+        Ok(crop)
+    }
+
     pub async fn insert(&self, crop: &Crop) -> Result<Crop, AppError> {
-        self.validate(crop)?;
-        self.repository.insert(crop.clone()).await
+        let crop = self.prepare_insert(crop).await?;
+        let mut transaction = self.repository.begin().await?;
+        let crop = self.repository.insert(&mut transaction, crop).await?;
+        self.audit_service
+            .created(
+                &mut transaction,
+                AuditEntity::Crop,
+                crop.id().unwrap(),
+                crop_snapshot(&crop),
+            )
+            .await?;
+        transaction.commit().await?;
+        Ok(crop)
     }
 
-    pub async fn update(&self, id: i64, crop: &Crop) -> Result<Crop, AppError> {
-        self.validate(crop)?;
+    /// Inserts crops checked by [`CropService::prepare_insert`] in a single
+    /// transaction.
+    pub async fn insert_all(&self, crops: Vec<Crop>) -> Result<Vec<Crop>, AppError> {
+        let mut transaction = self.repository.begin().await?;
+        let crops = self.repository.insert_all(&mut transaction, crops).await?;
+        for crop in &crops {
+            self.audit_service
+                .created(
+                    &mut transaction,
+                    AuditEntity::Crop,
+                    crop.id().unwrap(),
+                    crop_snapshot(crop),
+                )
+                .await?;
+        }
+        transaction.commit().await?;
+        Ok(crops)
+    }
+
+    /// The closing date is only changed through [`CropService::close`], so the
+    /// current one is kept. Refused unless `if_match` names the current
+    /// version.
+    pub async fn update(&self, id: i64, crop: &Crop, if_match: &IfMatch) -> Result<Crop, AppError> {
+        let current = self.find_by_id(id).await?;
+        if !if_match.matches(current.version()) {
+            return Err(CropRepository::stale(id));
+        }
+        let mut crop = crop.clone();
+        crop.set_closed_at(*current.closed_at());
+        self.validate(&mut crop).await?;
         if self.batch_service.is_crop_in_use(id).await? {
             return Err(AppError::BadRequest(format!(
                 "O plantio de ID {id} está em um lote, e portanto não pode ser alterado."
             )));
         }
-        self.repository.update(id, crop.clone()).await
+        let mut transaction = self.repository.begin().await?;
+        let crop = self
+            .repository
+            .update(&mut transaction, id, current.version(), crop)
+            .await?;
+        self.audit_service
+            .updated(
+                &mut transaction,
+                AuditEntity::Crop,
+                id,
+                &crop_snapshot(&current),
+                &crop_snapshot(&crop),
+            )
+            .await?;
+        transaction.commit().await?;
+        Ok(crop)
+    }
+
+    /// Ends the crop cycle. No harvests can be recorded after it is closed.
+    pub async fn close(&self, id: i64, closed_at: NaiveDate) -> Result<Crop, AppError> {
+        let mut crop = self.find_by_id(id).await?;
+        if let Some(current) = crop.closed_at() {
+            return Err(CropRepository::closed(id, *current));
+        }
+        if closed_at < crop.planted_at() {
+            return Err(AppError::BadRequest(format!(
+                "A data de encerramento ({}) não pode ser anterior a data do plantio ({})",
+                closed_at.format("%d/%m/%Y"),
+                crop.planted_at().format("%d/%m/%Y")
+            )));
+        }
+        let harvests = self.harvest_repository.list_by_crop_id(id).await?;
+        if let Some(last) = harvests.iter().map(|harvest| harvest.harvested_at()).max() {
+            if closed_at < last {
+                return Err(CropRepository::harvested_after(closed_at, last));
+            }
+        }
+
+        let mut transaction = self.repository.begin().await?;
+        self.repository
+            .close(&mut transaction, id, closed_at)
+            .await?;
+        let before = crop_snapshot(&crop);
+        crop.set_closed_at(Some(closed_at));
+        crop.set_version(crop.version() + 1);
+        self.audit_service
+            .updated(
+                &mut transaction,
+                AuditEntity::Crop,
+                id,
+                &before,
+                &crop_snapshot(&crop),
+            )
+            .await?;
+        transaction.commit().await?;
+        Ok(crop)
     }
 
-    pub async fn delete(&self, id: i64) -> Result<(), AppError> {
+    /// Refused unless `if_match` names the current version.
+    pub async fn delete(&self, id: i64, if_match: &IfMatch) -> Result<(), AppError> {
+        let current = self.find_by_id(id).await?;
+        if !if_match.matches(current.version()) {
+            return Err(CropRepository::stale(id));
+        }
         if self.batch_service.is_crop_in_use(id).await? {
             return Err(AppError::BadRequest(format!(
                 "O plantio de ID {id} está em um lote, e portanto não pode ser alterado."
             )));
         }
-        self.repository.delete(id).await
+        if !self
+            .application_repository
+            .list_by_crop_id(id)
+            .await?
+            .is_empty()
+        {
+            return Err(AppError::BadRequest(format!(
+                "O plantio de ID {id} possui aplicações de defensivos, e portanto não pode ser excluído."
+            )));
+        }
+        if !self
+            .harvest_repository
+            .list_by_crop_id(id)
+            .await?
+            .is_empty()
+        {
+            return Err(AppError::BadRequest(format!(
+                "O plantio de ID {id} possui colheitas, e portanto não pode ser excluído."
+            )));
+        }
+        let mut transaction = self.repository.begin().await?;
+        self.repository
+            .delete(
+                &mut transaction,
+                id,
+                current.version(),
+                Local::now().naive_local(),
+            )
+            .await?;
+        self.audit_service
+            .deleted(
+                &mut transaction,
+                AuditEntity::Crop,
+                id,
+                crop_snapshot(&current),
+            )
+            .await?;
+        transaction.commit().await?;
+        Ok(())
+    }
+
+    /// Undoes the deletion of a crop. Its batches stay deleted until each is
+    /// restored.
+    pub async fn restore(&self, id: i64) -> Result<Crop, AppError> {
+        let mut crop = self.find_by_id_including_deleted(id).await?;
+        if crop.deleted_at().is_none() {
+            return Err(CropRepository::not_deleted(id));
+        }
+
+        let mut transaction = self.repository.begin().await?;
+        self.repository.restore(&mut transaction, id).await?;
+        crop.set_deleted_at(None);
+        crop.set_version(crop.version() + 1);
+        self.audit_service
+            .restored(
+                &mut transaction,
+                AuditEntity::Crop,
+                id,
+                crop_snapshot(&crop),
+            )
+            .await?;
+        transaction.commit().await?;
+        Ok(crop)
     }
 }
 
@@ -71,7 +310,7 @@ where
     S: Send + Sync + StateTrait,
 {
     type Rejection = AppError;
-    async fn from_request_parts(_parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
-        Ok(Self::new(state.get_pool()))
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        Ok(Self::new(state.get_pool()).with_actor(actor(parts)?))
     }
 }
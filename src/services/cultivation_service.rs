@@ -0,0 +1,97 @@
+use axum::{async_trait, extract::FromRequestParts, http::request::Parts};
+use sqlx::SqlitePool;
+
+use crate::{
+    errors::AppError, models::Cultivation, repositories::CultivationRepository, StateTrait,
+};
+
+pub struct CultivationService {
+    repository: CultivationRepository,
+}
+
+impl CultivationService {
+    pub fn new(pool: Box<SqlitePool>) -> Self {
+        Self {
+            repository: CultivationRepository::new(pool),
+        }
+    }
+
+    async fn validate(&self, id: Option<i64>, cultivation: &Cultivation) -> Result<(), AppError> {
+        if let Some(existing) = self
+            .repository
+            .find_by_name(cultivation.name(), cultivation.variety().as_deref())
+            .await?
+        {
+            if *existing.id() != id {
+                return Err(AppError::BadRequest(format!(
+                    "A cultura {} já está cadastrada",
+                    existing.full_name()
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    pub async fn list(&self) -> Result<Vec<Cultivation>, AppError> {
+        self.repository.list().await
+    }
+
+    pub async fn find_by_id(&self, id: i64) -> Result<Cultivation, AppError> {
+        self.repository.find_by_id(id).await
+    }
+
+    pub async fn insert(&self, cultivation: &Cultivation) -> Result<Cultivation, AppError> {
+        self.validate(None, cultivation).await?;
+        self.repository.insert(cultivation.clone()).await
+    }
+
+    pub async fn update(
+        &self,
+        id: i64,
+        cultivation: &Cultivation,
+    ) -> Result<Cultivation, AppError> {
+        self.find_by_id(id).await?;
+        self.validate(Some(id), cultivation).await?;
+        self.repository.update(id, cultivation.clone()).await
+    }
+
+    /// Registers new cultivations and refreshes the metadata of those already
+    /// in the catalog. Returns how many were inserted and updated.
+    pub async fn import(&self, cultivations: &[Cultivation]) -> Result<(usize, usize), AppError> {
+        for (index, cultivation) in cultivations.iter().enumerate() {
+            if let Some(duplicate) = cultivations[..index].iter().find(|other| {
+                other.name().to_lowercase() == cultivation.name().to_lowercase()
+                    && other.variety().as_deref().map(str::to_lowercase)
+                        == cultivation.variety().as_deref().map(str::to_lowercase)
+            }) {
+                return Err(AppError::BadRequest(format!(
+                    "A cultura {} aparece mais de uma vez no arquivo",
+                    duplicate.full_name()
+                )));
+            }
+        }
+        self.repository.import(cultivations).await
+    }
+
+    pub async fn delete(&self, id: i64) -> Result<(), AppError> {
+        self.find_by_id(id).await?;
+        if self.repository.count_crops(id).await? > 0 {
+            return Err(AppError::BadRequest(format!(
+                "A cultura de ID {id} possui plantios, e portanto não pode ser excluída."
+            )));
+        }
+        self.repository.delete(id).await
+    }
+}
+
+#[async_trait]
+impl<S> FromRequestParts<S> for CultivationService
+where
+    S: Send + Sync + StateTrait,
+{
+    type Rejection = AppError;
+    async fn from_request_parts(_parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        Ok(Self::new(state.get_pool()))
+    }
+}
@@ -0,0 +1,60 @@
+use axum::{async_trait, extract::FromRequestParts, http::request::Parts};
+use sqlx::SqlitePool;
+
+use crate::{
+    errors::AppError,
+    models::Customer,
+    repositories::{CustomerRepository, ShipmentRepository},
+    StateTrait,
+};
+
+pub struct CustomerService {
+    repository: CustomerRepository,
+    shipment_repository: ShipmentRepository,
+}
+
+impl CustomerService {
+    pub fn new(pool: Box<SqlitePool>) -> Self {
+        Self {
+            repository: CustomerRepository::new(pool.clone()),
+            shipment_repository: ShipmentRepository::new(pool),
+        }
+    }
+
+    pub async fn list(&self) -> Result<Vec<Customer>, AppError> {
+        self.repository.list().await
+    }
+
+    pub async fn find_by_id(&self, id: i64) -> Result<Customer, AppError> {
+        self.repository.find_by_id(id).await
+    }
+
+    pub async fn insert(&self, customer: &Customer) -> Result<Customer, AppError> {
+        self.repository.insert(customer.clone()).await
+    }
+
+    pub async fn update(&self, id: i64, customer: &Customer) -> Result<Customer, AppError> {
+        self.find_by_id(id).await?;
+        self.repository.update(id, customer.clone()).await
+    }
+
+    pub async fn delete(&self, id: i64) -> Result<(), AppError> {
+        if self.shipment_repository.count_by_customer_id(id).await? > 0 {
+            return Err(AppError::BadRequest(format!(
+                "O cliente de ID {id} possui remessas, e portanto não pode ser excluído."
+            )));
+        }
+        self.repository.delete(id).await
+    }
+}
+
+#[async_trait]
+impl<S> FromRequestParts<S> for CustomerService
+where
+    S: Send + Sync + StateTrait,
+{
+    type Rejection = AppError;
+    async fn from_request_parts(_parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        Ok(Self::new(state.get_pool()))
+    }
+}
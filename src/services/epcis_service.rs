@@ -0,0 +1,178 @@
+use std::collections::{HashMap, HashSet};
+
+use axum::{async_trait, extract::FromRequestParts, http::request::Parts};
+use chrono::NaiveDate;
+use sqlx::SqlitePool;
+
+use crate::{
+    errors::AppError,
+    misc::epcis::{build_events, EpcisSource},
+    models::{Batch, EpcisEvent},
+    repositories::{
+        BatchEventRepository, BatchRepository, EpcisRepository, ProductRepository,
+        ShipmentRepository,
+    },
+    StateTrait,
+};
+
+use super::LabelService;
+
+pub struct EpcisService {
+    repository: EpcisRepository,
+    batch_repository: BatchRepository,
+    batch_event_repository: BatchEventRepository,
+    shipment_repository: ShipmentRepository,
+    product_repository: ProductRepository,
+    label_service: LabelService,
+    base_url: String,
+}
+
+impl EpcisService {
+    pub fn new(
+        pool: Box<SqlitePool>,
+        tracking_url_template: String,
+        digital_link_base_url: String,
+    ) -> Self {
+        Self {
+            repository: EpcisRepository::new(pool.clone()),
+            batch_repository: BatchRepository::new(pool.clone()),
+            batch_event_repository: BatchEventRepository::new(pool.clone()),
+            shipment_repository: ShipmentRepository::new(pool.clone()),
+            product_repository: ProductRepository::new(pool.clone()),
+            // Only used for URLs, never to print labels.
+            label_service: LabelService::new(
+                pool,
+                tracking_url_template,
+                digital_link_base_url.clone(),
+                None,
+            ),
+            base_url: digital_link_base_url,
+        }
+    }
+
+    /// URI identifying the batch as an EPC class: its GS1 Digital Link when
+    /// it has a product, its tracking URL otherwise.
+    async fn classes(
+        &self,
+        batches: impl Iterator<Item = &Batch>,
+    ) -> Result<HashMap<i64, String>, AppError> {
+        let products: HashMap<i64, String> = self
+            .product_repository
+            .list()
+            .await?
+            .into_iter()
+            .map(|product| (product.id().unwrap(), product.gtin().to_string()))
+            .collect();
+
+        Ok(batches
+            .map(|batch| {
+                let lot = batch.tracking_code().as_ref().unwrap();
+                let class = match batch.product_id().and_then(|id| products.get(&id)) {
+                    Some(gtin) => self.label_service.digital_link(gtin, lot),
+                    None => self.label_service.tracking_url(lot),
+                };
+                (batch.id().unwrap(), class)
+            })
+            .collect())
+    }
+
+    /// Events of our own batches followed by the ones captured from partners,
+    /// dated between `from` and `to`, both inclusive, and ordered by time.
+    pub async fn list(
+        &self,
+        from: Option<NaiveDate>,
+        to: Option<NaiveDate>,
+    ) -> Result<Vec<EpcisEvent>, AppError> {
+        if let (Some(from), Some(to)) = (from, to) {
+            if to < from {
+                return Err(AppError::BadRequest(format!(
+                    "A data final ({}) não pode ser anterior a data inicial ({})",
+                    to.format("%d/%m/%Y"),
+                    from.format("%d/%m/%Y")
+                )));
+            }
+        }
+
+        let batches = self.batch_repository.list_dated(from, to).await?;
+        let links = self.batch_repository.list_links_dated(from, to).await?;
+        let events = self.batch_event_repository.list(from, to).await?;
+        let shipments = self.shipment_repository.list(from, to).await?;
+
+        // Transformations, processing and shipments in the period may refer to
+        // batches dated before it.
+        let dated: HashSet<i64> = batches.iter().map(|batch| batch.id().unwrap()).collect();
+        let inputs = links
+            .iter()
+            .filter(|(_, child_id, _)| dated.contains(child_id))
+            .map(|(parent_id, _, _)| *parent_id);
+        let processed = events.iter().map(|event| event.batch_id());
+        let shipped = shipments
+            .iter()
+            .flat_map(|shipment| shipment.items().iter().map(|item| item.batch_id()));
+        let referenced = inputs
+            .chain(processed)
+            .chain(shipped)
+            .filter(|id| !dated.contains(id))
+            .collect::<HashSet<i64>>()
+            .into_iter()
+            .collect::<Vec<_>>();
+        let referenced = self.batch_repository.find_by_ids(&referenced).await?;
+        let classes = self.classes(batches.iter().chain(&referenced)).await?;
+
+        let mut events = build_events(&EpcisSource {
+            base_url: &self.base_url,
+            batches: &batches,
+            referenced: &referenced,
+            classes: &classes,
+            links: &links,
+            events: &events,
+            shipments: &shipments,
+        });
+        events.extend(self.repository.list(from, to).await?);
+        events.sort_by_key(|event| event.event_time);
+
+        Ok(events)
+    }
+
+    /// Validates every event of a partner's document before storing any.
+    pub async fn capture(
+        &self,
+        events: Vec<serde_json::Value>,
+    ) -> Result<(usize, usize), AppError> {
+        if events.is_empty() {
+            return Err(AppError::BadRequest(
+                "O documento EPCIS não possui eventos".to_string(),
+            ));
+        }
+
+        let events = events
+            .into_iter()
+            .enumerate()
+            .map(|(index, event)| {
+                let event: EpcisEvent = serde_json::from_value(event)
+                    .map_err(|err| AppError::BadRequest(format!("Evento {}: {err}", index + 1)))?;
+                event
+                    .check()
+                    .map_err(|err| AppError::BadRequest(format!("Evento {}: {err}", index + 1)))?;
+                Ok(event)
+            })
+            .collect::<Result<Vec<EpcisEvent>, AppError>>()?;
+
+        self.repository.capture(&events).await
+    }
+}
+
+#[async_trait]
+impl<S> FromRequestParts<S> for EpcisService
+where
+    S: Send + Sync + StateTrait,
+{
+    type Rejection = AppError;
+    async fn from_request_parts(_parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        Ok(Self::new(
+            state.get_pool(),
+            state.get_tracking_url_template(),
+            state.get_digital_link_base_url(),
+        ))
+    }
+}
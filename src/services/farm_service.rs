@@ -0,0 +1,60 @@
+use axum::{async_trait, extract::FromRequestParts, http::request::Parts};
+use sqlx::SqlitePool;
+
+use crate::{
+    errors::AppError,
+    models::Farm,
+    repositories::{FarmRepository, PlotRepository},
+    StateTrait,
+};
+
+pub struct FarmService {
+    repository: FarmRepository,
+    plot_repository: PlotRepository,
+}
+
+impl FarmService {
+    pub fn new(pool: Box<SqlitePool>) -> Self {
+        Self {
+            repository: FarmRepository::new(pool.clone()),
+            plot_repository: PlotRepository::new(pool),
+        }
+    }
+
+    pub async fn list(&self) -> Result<Vec<Farm>, AppError> {
+        self.repository.list().await
+    }
+
+    pub async fn find_by_id(&self, id: i64) -> Result<Farm, AppError> {
+        self.repository.find_by_id(id).await
+    }
+
+    pub async fn insert(&self, farm: &Farm) -> Result<Farm, AppError> {
+        self.repository.insert(farm.clone()).await
+    }
+
+    pub async fn update(&self, id: i64, farm: &Farm) -> Result<Farm, AppError> {
+        self.find_by_id(id).await?;
+        self.repository.update(id, farm.clone()).await
+    }
+
+    pub async fn delete(&self, id: i64) -> Result<(), AppError> {
+        if !self.plot_repository.list(Some(id)).await?.is_empty() {
+            return Err(AppError::BadRequest(format!(
+                "A fazenda de ID {id} possui talhões, e portanto não pode ser excluída."
+            )));
+        }
+        self.repository.delete(id).await
+    }
+}
+
+#[async_trait]
+impl<S> FromRequestParts<S> for FarmService
+where
+    S: Send + Sync + StateTrait,
+{
+    type Rejection = AppError;
+    async fn from_request_parts(_parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        Ok(Self::new(state.get_pool()))
+    }
+}
@@ -0,0 +1,78 @@
+use axum::{async_trait, extract::FromRequestParts, http::request::Parts};
+use sqlx::SqlitePool;
+
+use crate::{errors::AppError, models::Harvest, repositories::HarvestRepository, StateTrait};
+
+use super::CropService;
+
+pub struct HarvestService {
+    repository: HarvestRepository,
+    crop_service: CropService,
+}
+
+impl HarvestService {
+    pub fn new(pool: Box<SqlitePool>) -> Self {
+        Self {
+            repository: HarvestRepository::new(pool.clone()),
+            crop_service: CropService::new(pool),
+        }
+    }
+
+    pub async fn list(&self, crop_id: i64) -> Result<Vec<Harvest>, AppError> {
+        self.crop_service.find_by_id(crop_id).await?;
+        self.repository.list_by_crop_id(crop_id).await
+    }
+
+    /// Harvests that went into the batch, including through the batches it
+    /// was split or merged from.
+    pub async fn find_by_batch_lineage(&self, batch_id: i64) -> Result<Vec<Harvest>, AppError> {
+        self.repository.find_by_batch_lineage(batch_id).await
+    }
+
+    pub async fn insert(&self, harvest: &Harvest) -> Result<Harvest, AppError> {
+        let crop = self.crop_service.find_by_id(harvest.crop_id()).await?;
+        if harvest.harvested_at() < crop.planted_at() {
+            return Err(AppError::BadRequest(format!(
+                "A data da colheita ({}) não pode ser anterior a data do plantio ({})",
+                harvest.harvested_at().format("%d/%m/%Y"),
+                crop.planted_at().format("%d/%m/%Y")
+            )));
+        }
+        if let Some(closed_at) = crop.closed_at() {
+            if harvest.harvested_at() > *closed_at {
+                return Err(AppError::BadRequest(format!(
+                    "A data da colheita ({}) não pode ser posterior ao encerramento do plantio ({})",
+                    harvest.harvested_at().format("%d/%m/%Y"),
+                    closed_at.format("%d/%m/%Y")
+                )));
+            }
+        }
+        self.repository.insert(harvest.clone()).await
+    }
+
+    pub async fn delete(&self, crop_id: i64, id: i64) -> Result<(), AppError> {
+        let harvest = self.repository.find_by_id(id).await?;
+        if harvest.crop_id() != crop_id {
+            return Err(AppError::NotFound(format!(
+                "Colheita de ID {id} não encontrada no plantio de ID {crop_id}"
+            )));
+        }
+        if self.repository.count_batches(id).await? > 0 {
+            return Err(AppError::BadRequest(format!(
+                "A colheita de ID {id} possui lotes, e portanto não pode ser excluída."
+            )));
+        }
+        self.repository.delete(id).await
+    }
+}
+
+#[async_trait]
+impl<S> FromRequestParts<S> for HarvestService
+where
+    S: Send + Sync + StateTrait,
+{
+    type Rejection = AppError;
+    async fn from_request_parts(_parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        Ok(Self::new(state.get_pool()))
+    }
+}
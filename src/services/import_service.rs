@@ -0,0 +1,157 @@
+use axum::{async_trait, extract::FromRequestParts, http::request::Parts};
+use serde::de::DeserializeOwned;
+use sqlx::SqlitePool;
+use validator::Validate;
+
+use crate::{
+    dtos::{BatchRequestDTO, CropRequestDTO},
+    errors::AppError,
+    misc::{audit::actor, import::read_rows},
+    models::{Batch, Crop, ImportReport},
+    StateTrait,
+};
+
+use super::{BatchService, CropService, CultivationService};
+
+pub struct ImportService {
+    crop_service: CropService,
+    cultivation_service: CultivationService,
+    batch_service: BatchService,
+}
+
+/// Message of an error caused by the row itself, which is reported instead
+/// of failing the whole import.
+fn row_error(err: AppError) -> Result<String, AppError> {
+    match err {
+        AppError::ValidationError(errors) => Ok(errors.to_string()),
+        AppError::NotFound(message) | AppError::BadRequest(message) => Ok(message),
+        err => Err(err),
+    }
+}
+
+/// Reads the rows of `data` and checks each with `prepare`, collecting the
+/// errors of every row instead of stopping at the first.
+async fn prepare_rows<T, M, F, Fut>(
+    data: &[u8],
+    report: &mut ImportReport,
+    prepare: F,
+) -> Result<Vec<M>, AppError>
+where
+    T: DeserializeOwned + Validate,
+    F: Fn(T) -> Fut,
+    Fut: std::future::Future<Output = Result<M, AppError>>,
+{
+    let mut prepared = vec![];
+    for (line, row) in read_rows::<T>(data)? {
+        report.rows += 1;
+        let row = match row {
+            Ok(row) => row,
+            Err(message) => {
+                report.add_error(line, message);
+                continue;
+            }
+        };
+        let result = match row.validate() {
+            Ok(()) => prepare(row).await,
+            Err(errors) => Err(errors.into()),
+        };
+        match result {
+            Ok(model) => prepared.push(model),
+            Err(err) => report.add_error(line, row_error(err)?),
+        }
+    }
+    Ok(prepared)
+}
+
+impl ImportService {
+    pub fn new(pool: Box<SqlitePool>) -> Self {
+        Self {
+            crop_service: CropService::new(pool.clone()),
+            cultivation_service: CultivationService::new(pool.clone()),
+            batch_service: BatchService::new(pool),
+        }
+    }
+
+    /// Records the imported crops and batches as created by `actor`.
+    pub fn with_actor(mut self, actor: Option<String>) -> Self {
+        self.crop_service = self.crop_service.with_actor(actor.clone());
+        self.batch_service = self.batch_service.with_actor(actor);
+        self
+    }
+
+    async fn prepare_crop(&self, row: CropRequestDTO) -> Result<Crop, AppError> {
+        let cultivation = self
+            .cultivation_service
+            .find_by_id(row.cultivation_id)
+            .await?;
+        let crop = Crop::new(
+            None,
+            row.name,
+            row.area.unwrap_or_default(),
+            row.area_unit,
+            cultivation,
+            row.planted_at,
+            None,
+            row.plot_id,
+        )?;
+        self.crop_service.prepare_insert(&crop).await
+    }
+
+    async fn prepare_batch(&self, row: BatchRequestDTO) -> Result<Batch, AppError> {
+        let crop = self.crop_service.find_by_id(row.crop_id).await?;
+        let batch = Batch::new(
+            None,
+            crop,
+            row.classification,
+            row.processing,
+            row.packing,
+            row.quantity,
+            row.quantity_unit,
+            None,
+            row.date,
+            row.harvest_id,
+            row.product_id,
+        )?;
+        self.batch_service.prepare_insert(batch).await
+    }
+
+    /// Checks every row of the file with the same rules as
+    /// [`CropService::insert`] and, unless `dry_run` is set or a row has
+    /// errors, inserts all of them in one transaction.
+    pub async fn import_crops(&self, data: &[u8], dry_run: bool) -> Result<ImportReport, AppError> {
+        let mut report = ImportReport::default();
+        let crops = prepare_rows(data, &mut report, |row| self.prepare_crop(row)).await?;
+
+        if !dry_run && report.errors.is_empty() {
+            report.inserted = self.crop_service.insert_all(crops).await?.len();
+        }
+        Ok(report)
+    }
+
+    /// Same as [`ImportService::import_crops`], with the rules of
+    /// [`BatchService::insert`].
+    pub async fn import_batches(
+        &self,
+        data: &[u8],
+        dry_run: bool,
+    ) -> Result<ImportReport, AppError> {
+        let mut report = ImportReport::default();
+        let batches = prepare_rows(data, &mut report, |row| self.prepare_batch(row)).await?;
+
+        if !dry_run && report.errors.is_empty() {
+            report.inserted = self.batch_service.insert_all(batches).await?.len();
+        }
+        Ok(report)
+    }
+}
+
+#[async_trait]
+impl<S> FromRequestParts<S> for ImportService
+where
+    S: Send + Sync + StateTrait,
+{
+    type Rejection = AppError;
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        Ok(Self::new(state.get_pool()).with_actor(actor(parts)?))
+    }
+}
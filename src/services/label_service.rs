@@ -0,0 +1,150 @@
+use axum::{async_trait, extract::FromRequestParts, http::request::Parts};
+use sqlx::SqlitePool;
+
+use crate::{
+    errors::AppError,
+    misc::{
+        codes::{render_barcode, render_code128, render_qrcode, ImageFormat},
+        gs1::{digital_link, gs1_128_data, gtin_validation, normalize_gtin},
+        labels::{render_label_pdf, render_label_zpl},
+    },
+    models::{Batch, LabelTemplate, Product},
+    repositories::ProductRepository,
+    StateTrait,
+};
+
+use super::{BatchService, LabelTemplateService};
+
+pub struct LabelService {
+    batch_service: BatchService,
+    label_template_service: LabelTemplateService,
+    product_repository: ProductRepository,
+    tracking_url_template: String,
+    digital_link_base_url: String,
+    organization: Option<String>,
+}
+
+impl LabelService {
+    pub fn new(
+        pool: Box<SqlitePool>,
+        tracking_url_template: String,
+        digital_link_base_url: String,
+        organization: Option<String>,
+    ) -> Self {
+        Self {
+            batch_service: BatchService::new(pool.clone()),
+            label_template_service: LabelTemplateService::new(pool.clone()),
+            product_repository: ProductRepository::new(pool),
+            tracking_url_template,
+            digital_link_base_url,
+            organization,
+        }
+    }
+
+    pub fn tracking_url(&self, code: &str) -> String {
+        self.tracking_url_template.replace("{code}", code)
+    }
+
+    pub fn digital_link(&self, gtin: &str, lot: &str) -> String {
+        digital_link(&self.digital_link_base_url, gtin, lot)
+    }
+
+    /// The batch as a GS1 lot of its product. The lot number is the tracking
+    /// code.
+    pub async fn gs1_lot(&self, id: i64) -> Result<(Product, Batch), AppError> {
+        let batch = self.batch_service.find_by_id(id).await?;
+        let Some(product_id) = batch.product_id() else {
+            return Err(AppError::BadRequest(format!(
+                "O lote de ID {id} não está associado a um produto com GTIN"
+            )));
+        };
+        let product = self.product_repository.find_by_id(product_id).await?;
+        Ok((product, batch))
+    }
+
+    pub async fn gs1_128(&self, id: i64, format: ImageFormat) -> Result<Vec<u8>, AppError> {
+        let (product, batch) = self.gs1_lot(id).await?;
+        let lot = batch.tracking_code().as_ref().unwrap();
+        render_code128(&gs1_128_data(product.gtin(), lot, batch.date()), format)
+    }
+
+    /// Tracking URL of the lot a GS1 Digital Link points to. Unknown GTINs and
+    /// lots of other products are not found.
+    pub async fn resolve_digital_link(&self, gtin: &str, lot: &str) -> Result<String, AppError> {
+        let not_found = || AppError::NotFound(format!("Lote {lot} do GTIN {gtin} não encontrado"));
+        gtin_validation(gtin).map_err(|_| not_found())?;
+        let product = self
+            .product_repository
+            .find_by_gtin(&normalize_gtin(gtin))
+            .await?
+            .ok_or_else(not_found)?;
+        let batch = self
+            .batch_service
+            .find_by_tracking_code(lot)
+            .await
+            .map_err(|_| not_found())?;
+        if batch.product_id() != *product.id() {
+            return Err(not_found());
+        }
+        Ok(self.tracking_url(lot))
+    }
+
+    pub async fn qrcode(&self, id: i64, format: ImageFormat) -> Result<Vec<u8>, AppError> {
+        let batch = self.batch_service.find_by_id(id).await?;
+        let code = batch.tracking_code().as_ref().unwrap();
+        render_qrcode(&self.tracking_url(code), format)
+    }
+
+    pub async fn barcode(&self, id: i64, format: ImageFormat) -> Result<Vec<u8>, AppError> {
+        let batch = self.batch_service.find_by_id(id).await?;
+        let code = batch.tracking_code().as_ref().unwrap();
+        render_barcode(code, format)
+    }
+
+    /// The chosen template or, when none is, the default of the organization
+    /// this server prints labels for.
+    async fn template(&self, template_id: Option<i64>) -> Result<LabelTemplate, AppError> {
+        match template_id {
+            Some(template_id) => self.label_template_service.find_by_id(template_id).await,
+            None => {
+                self.label_template_service
+                    .find_default(self.organization.as_deref())
+                    .await
+            }
+        }
+    }
+
+    pub async fn label_pdf(&self, id: i64, template_id: Option<i64>) -> Result<Vec<u8>, AppError> {
+        let batch = self.batch_service.find_by_id(id).await?;
+        let template = self.template(template_id).await?;
+        let code = batch.tracking_code().as_ref().unwrap();
+        render_label_pdf(&batch, &template, &self.tracking_url(code))
+    }
+
+    pub async fn label_zpl(&self, id: i64, template_id: Option<i64>) -> Result<String, AppError> {
+        let batch = self.batch_service.find_by_id(id).await?;
+        let template = self.template(template_id).await?;
+        let code = batch.tracking_code().as_ref().unwrap();
+        Ok(render_label_zpl(
+            &batch,
+            &template,
+            &self.tracking_url(code),
+        ))
+    }
+}
+
+#[async_trait]
+impl<S> FromRequestParts<S> for LabelService
+where
+    S: Send + Sync + StateTrait,
+{
+    type Rejection = AppError;
+    async fn from_request_parts(_parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        Ok(Self::new(
+            state.get_pool(),
+            state.get_tracking_url_template(),
+            state.get_digital_link_base_url(),
+            state.get_organization(),
+        ))
+    }
+}
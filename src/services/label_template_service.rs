@@ -0,0 +1,67 @@
+use axum::{async_trait, extract::FromRequestParts, http::request::Parts};
+use sqlx::SqlitePool;
+
+use crate::{
+    errors::AppError, models::LabelTemplate, repositories::LabelTemplateRepository, StateTrait,
+};
+
+pub struct LabelTemplateService {
+    repository: LabelTemplateRepository,
+}
+
+impl LabelTemplateService {
+    pub fn new(pool: Box<SqlitePool>) -> Self {
+        Self {
+            repository: LabelTemplateRepository::new(pool),
+        }
+    }
+
+    pub async fn list(&self, organization: Option<String>) -> Result<Vec<LabelTemplate>, AppError> {
+        self.repository.list(organization).await
+    }
+
+    pub async fn find_by_id(&self, id: i64) -> Result<LabelTemplate, AppError> {
+        self.repository.find_by_id(id).await
+    }
+
+    /// Template labels of `organization` use when none is chosen: its
+    /// default, or the built-in one.
+    pub async fn find_default(
+        &self,
+        organization: Option<&str>,
+    ) -> Result<LabelTemplate, AppError> {
+        let template = match organization {
+            Some(organization) => self.repository.find_default(organization).await?,
+            None => None,
+        };
+        Ok(template.unwrap_or_else(LabelTemplate::default_template))
+    }
+
+    pub async fn insert(&self, template: &LabelTemplate) -> Result<LabelTemplate, AppError> {
+        self.repository.insert(template.clone()).await
+    }
+
+    pub async fn update(
+        &self,
+        id: i64,
+        template: &LabelTemplate,
+    ) -> Result<LabelTemplate, AppError> {
+        self.repository.find_by_id(id).await?;
+        self.repository.update(id, template.clone()).await
+    }
+
+    pub async fn delete(&self, id: i64) -> Result<(), AppError> {
+        self.repository.delete(id).await
+    }
+}
+
+#[async_trait]
+impl<S> FromRequestParts<S> for LabelTemplateService
+where
+    S: Send + Sync + StateTrait,
+{
+    type Rejection = AppError;
+    async fn from_request_parts(_parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        Ok(Self::new(state.get_pool()))
+    }
+}
@@ -1,4 +1,34 @@
+mod audit_service;
+mod batch_event_service;
 mod batch_service;
+mod catalog_service;
+mod chemical_application_service;
 mod crop_service;
+mod cultivation_service;
+mod customer_service;
+mod epcis_service;
+mod farm_service;
+mod harvest_service;
+mod import_service;
+mod label_service;
+mod label_template_service;
+mod packing_service;
+mod plot_service;
+mod product_service;
+mod recall_service;
+mod report_service;
+mod shipment_service;
+#[cfg(test)]
+mod test_support;
 
-pub use self::{batch_service::BatchService, crop_service::CropService};
+pub use self::{
+    audit_service::AuditService, batch_event_service::BatchEventService,
+    batch_service::BatchService, catalog_service::CatalogService,
+    chemical_application_service::ChemicalApplicationService, crop_service::CropService,
+    cultivation_service::CultivationService, customer_service::CustomerService,
+    epcis_service::EpcisService, farm_service::FarmService, harvest_service::HarvestService,
+    import_service::ImportService, label_service::LabelService,
+    label_template_service::LabelTemplateService, packing_service::PackingService,
+    plot_service::PlotService, product_service::ProductService, recall_service::RecallService,
+    report_service::ReportService, shipment_service::ShipmentService,
+};
@@ -0,0 +1,80 @@
+use axum::{async_trait, extract::FromRequestParts, http::request::Parts};
+use sqlx::SqlitePool;
+
+use crate::{errors::AppError, models::Packing, repositories::PackingRepository, StateTrait};
+
+pub struct PackingService {
+    repository: PackingRepository,
+}
+
+impl PackingService {
+    pub fn new(pool: Box<SqlitePool>) -> Self {
+        Self {
+            repository: PackingRepository::new(pool),
+        }
+    }
+
+    async fn validate(&self, id: Option<i64>, packing: &Packing) -> Result<(), AppError> {
+        if let Some(existing) = self.repository.find_by_code(packing.code()).await? {
+            if *existing.id() != id {
+                return Err(AppError::BadRequest(format!(
+                    "O código {} já existe no catálogo de embalagens",
+                    existing.code()
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    pub async fn list(&self, active: Option<bool>) -> Result<Vec<Packing>, AppError> {
+        self.repository.list(active).await
+    }
+
+    pub async fn find_by_id(&self, id: i64) -> Result<Packing, AppError> {
+        self.repository.find_by_id(id).await
+    }
+
+    pub async fn insert(&self, packing: &Packing) -> Result<Packing, AppError> {
+        self.validate(None, packing).await?;
+        self.repository.insert(packing.clone()).await
+    }
+
+    /// Codes already used by batches cannot change, only their description,
+    /// weight and whether they are active.
+    pub async fn update(&self, id: i64, packing: &Packing) -> Result<Packing, AppError> {
+        let current = self.find_by_id(id).await?;
+        self.validate(Some(id), packing).await?;
+        if current.code() != packing.code()
+            && self.repository.count_batches(current.code()).await? > 0
+        {
+            return Err(AppError::BadRequest(format!(
+                "O código {} está em uso por lotes, e portanto não pode ser alterado. Desative-o e cadastre um novo.",
+                current.code()
+            )));
+        }
+        self.repository.update(id, packing.clone()).await
+    }
+
+    pub async fn delete(&self, id: i64) -> Result<(), AppError> {
+        let packing = self.find_by_id(id).await?;
+        if self.repository.count_batches(packing.code()).await? > 0 {
+            return Err(AppError::BadRequest(format!(
+                "O código {} está em uso por lotes, e portanto não pode ser excluído. Desative-o em vez disso.",
+                packing.code()
+            )));
+        }
+        self.repository.delete(id).await
+    }
+}
+
+#[async_trait]
+impl<S> FromRequestParts<S> for PackingService
+where
+    S: Send + Sync + StateTrait,
+{
+    type Rejection = AppError;
+    async fn from_request_parts(_parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        Ok(Self::new(state.get_pool()))
+    }
+}
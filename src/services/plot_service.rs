@@ -0,0 +1,61 @@
+use axum::{async_trait, extract::FromRequestParts, http::request::Parts};
+use sqlx::SqlitePool;
+
+use crate::{errors::AppError, models::Plot, repositories::PlotRepository, StateTrait};
+
+use super::{CropService, FarmService};
+
+pub struct PlotService {
+    repository: PlotRepository,
+    farm_service: FarmService,
+    crop_service: CropService,
+}
+
+impl PlotService {
+    pub fn new(pool: Box<SqlitePool>) -> Self {
+        Self {
+            repository: PlotRepository::new(pool.clone()),
+            farm_service: FarmService::new(pool.clone()),
+            crop_service: CropService::new(pool),
+        }
+    }
+
+    pub async fn list(&self, farm_id: Option<i64>) -> Result<Vec<Plot>, AppError> {
+        self.repository.list(farm_id).await
+    }
+
+    pub async fn find_by_id(&self, id: i64) -> Result<Plot, AppError> {
+        self.repository.find_by_id(id).await
+    }
+
+    pub async fn insert(&self, plot: &Plot) -> Result<Plot, AppError> {
+        self.farm_service.find_by_id(plot.farm_id()).await?;
+        self.repository.insert(plot.clone()).await
+    }
+
+    pub async fn update(&self, id: i64, plot: &Plot) -> Result<Plot, AppError> {
+        self.find_by_id(id).await?;
+        self.farm_service.find_by_id(plot.farm_id()).await?;
+        self.repository.update(id, plot.clone()).await
+    }
+
+    pub async fn delete(&self, id: i64) -> Result<(), AppError> {
+        if self.crop_service.is_plot_in_use(id).await? {
+            return Err(AppError::BadRequest(format!(
+                "O talhão de ID {id} possui plantios, e portanto não pode ser excluído."
+            )));
+        }
+        self.repository.delete(id).await
+    }
+}
+
+#[async_trait]
+impl<S> FromRequestParts<S> for PlotService
+where
+    S: Send + Sync + StateTrait,
+{
+    type Rejection = AppError;
+    async fn from_request_parts(_parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        Ok(Self::new(state.get_pool()))
+    }
+}
@@ -0,0 +1,77 @@
+use axum::{async_trait, extract::FromRequestParts, http::request::Parts};
+use sqlx::SqlitePool;
+
+use crate::{errors::AppError, models::Product, repositories::ProductRepository, StateTrait};
+
+pub struct ProductService {
+    repository: ProductRepository,
+}
+
+impl ProductService {
+    pub fn new(pool: Box<SqlitePool>) -> Self {
+        Self {
+            repository: ProductRepository::new(pool),
+        }
+    }
+
+    async fn validate(&self, id: Option<i64>, product: &Product) -> Result<(), AppError> {
+        if let Some(existing) = self.repository.find_by_gtin(product.gtin()).await? {
+            if *existing.id() != id {
+                return Err(AppError::BadRequest(format!(
+                    "O GTIN {} já está cadastrado no produto {}",
+                    existing.gtin(),
+                    existing.name()
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    pub async fn list(&self) -> Result<Vec<Product>, AppError> {
+        self.repository.list().await
+    }
+
+    pub async fn find_by_id(&self, id: i64) -> Result<Product, AppError> {
+        self.repository.find_by_id(id).await
+    }
+
+    pub async fn insert(&self, product: &Product) -> Result<Product, AppError> {
+        self.validate(None, product).await?;
+        self.repository.insert(product.clone()).await
+    }
+
+    /// The GTIN of a product with batches is printed on their labels, so it
+    /// cannot change.
+    pub async fn update(&self, id: i64, product: &Product) -> Result<Product, AppError> {
+        let current = self.find_by_id(id).await?;
+        self.validate(Some(id), product).await?;
+        if current.gtin() != product.gtin() && self.repository.count_batches(id).await? > 0 {
+            return Err(AppError::BadRequest(format!(
+                "O produto de ID {id} possui lotes, e portanto seu GTIN não pode ser alterado."
+            )));
+        }
+        self.repository.update(id, product.clone()).await
+    }
+
+    pub async fn delete(&self, id: i64) -> Result<(), AppError> {
+        self.find_by_id(id).await?;
+        if self.repository.count_batches(id).await? > 0 {
+            return Err(AppError::BadRequest(format!(
+                "O produto de ID {id} possui lotes, e portanto não pode ser excluído."
+            )));
+        }
+        self.repository.delete(id).await
+    }
+}
+
+#[async_trait]
+impl<S> FromRequestParts<S> for ProductService
+where
+    S: Send + Sync + StateTrait,
+{
+    type Rejection = AppError;
+    async fn from_request_parts(_parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        Ok(Self::new(state.get_pool()))
+    }
+}
@@ -0,0 +1,224 @@
+use axum::{async_trait, extract::FromRequestParts, http::request::Parts};
+use sqlx::SqlitePool;
+
+use crate::{
+    errors::AppError,
+    models::{Batch, Customer, Recall, RecallReport, RecallStatus, Shipment},
+    repositories::{CropRepository, CustomerRepository, RecallRepository, ShipmentRepository},
+    StateTrait,
+};
+
+use super::BatchService;
+
+pub struct RecallService {
+    repository: RecallRepository,
+    batch_service: BatchService,
+    crop_repository: CropRepository,
+    customer_repository: CustomerRepository,
+    shipment_repository: ShipmentRepository,
+}
+
+impl RecallService {
+    pub fn new(pool: Box<SqlitePool>) -> Self {
+        Self {
+            repository: RecallRepository::new(pool.clone()),
+            batch_service: BatchService::new(pool.clone()),
+            crop_repository: CropRepository::new(pool.clone()),
+            customer_repository: CustomerRepository::new(pool.clone()),
+            shipment_repository: ShipmentRepository::new(pool),
+        }
+    }
+
+    /// Batches the recall starts from, before following splits and merges.
+    /// Deleted batches are kept, since they may have left the farm before
+    /// they were deleted.
+    async fn find_source_batches(&self, recall: &Recall) -> Result<Vec<Batch>, AppError> {
+        match (recall.crop_id(), recall.batch_id(), recall.cultivation()) {
+            (Some(crop_id), None, None) => {
+                self.crop_repository.find_by_id(crop_id).await?;
+                self.batch_service.find_by_crop_id(crop_id).await
+            }
+            (None, Some(batch_id), None) => Ok(vec![
+                self.batch_service
+                    .find_by_id_including_deleted(batch_id)
+                    .await?,
+            ]),
+            (None, None, Some(cultivation)) => {
+                let (Some(start), Some(end)) = (recall.start_date(), recall.end_date()) else {
+                    return Err(AppError::BadRequest(
+                        "Informe o período (data inicial e final) da cultura".to_string(),
+                    ));
+                };
+                if start > end {
+                    return Err(AppError::BadRequest(format!(
+                        "A data inicial ({}) não pode ser posterior à data final ({})",
+                        start.format("%d/%m/%Y"),
+                        end.format("%d/%m/%Y")
+                    )));
+                }
+                self.batch_service
+                    .find_by_cultivation(cultivation, start, end)
+                    .await
+            }
+            _ => Err(AppError::BadRequest(
+                "Informe apenas um plantio, um lote ou uma cultura com período".to_string(),
+            )),
+        }
+    }
+
+    /// Source batches plus every batch derived from them.
+    async fn find_affected_batch_ids(&self, recall: &Recall) -> Result<Vec<i64>, AppError> {
+        let mut ids = vec![];
+        for batch in self.find_source_batches(recall).await? {
+            let id = batch.id().unwrap();
+            ids.push(id);
+            for descendant in self.batch_service.descendants(id).await? {
+                ids.push(descendant.id().unwrap());
+            }
+        }
+        ids.sort_unstable();
+        ids.dedup();
+
+        Ok(ids)
+    }
+
+    pub async fn list(&self) -> Result<Vec<Recall>, AppError> {
+        self.repository.list().await
+    }
+
+    pub async fn find_by_id(&self, id: i64) -> Result<Recall, AppError> {
+        self.repository.find_by_id(id).await
+    }
+
+    pub async fn insert(&self, recall: &Recall) -> Result<Recall, AppError> {
+        let mut recall = recall.clone();
+        recall.set_status(RecallStatus::Open);
+        recall.set_batch_ids(self.find_affected_batch_ids(&recall).await?);
+        self.repository.insert(recall).await
+    }
+
+    pub async fn update_status(&self, id: i64, status: RecallStatus) -> Result<Recall, AppError> {
+        let mut recall = self.find_by_id(id).await?;
+        if recall.status() == RecallStatus::Closed && status != RecallStatus::Closed {
+            return Err(AppError::BadRequest(format!(
+                "O recall de ID {id} está encerrado, e portanto não pode ser reaberto."
+            )));
+        }
+        self.repository.update_status(id, status).await?;
+        recall.set_status(status);
+        Ok(recall)
+    }
+
+    pub async fn report(&self, id: i64) -> Result<RecallReport, AppError> {
+        let recall = self.find_by_id(id).await?;
+
+        let mut batches = Vec::with_capacity(recall.batch_ids().len());
+        let mut shipments: Vec<Shipment> = vec![];
+        for batch_id in recall.batch_ids() {
+            batches.push(
+                self.batch_service
+                    .find_by_id_including_deleted(*batch_id)
+                    .await?,
+            );
+            for shipment in self.shipment_repository.find_by_batch_id(*batch_id).await? {
+                if !shipments.iter().any(|other| other.id() == shipment.id()) {
+                    shipments.push(shipment);
+                }
+            }
+        }
+        shipments.sort_by_key(|shipment| (shipment.dispatched_at(), *shipment.id()));
+
+        let mut customers: Vec<Customer> = vec![];
+        for shipment in &shipments {
+            if !customers
+                .iter()
+                .any(|customer| *customer.id() == Some(shipment.customer_id()))
+            {
+                customers.push(
+                    self.customer_repository
+                        .find_by_id(shipment.customer_id())
+                        .await?,
+                );
+            }
+        }
+
+        Ok(RecallReport {
+            recall,
+            batches,
+            shipments,
+            customers,
+        })
+    }
+}
+
+#[async_trait]
+impl<S> FromRequestParts<S> for RecallService
+where
+    S: Send + Sync + StateTrait,
+{
+    type Rejection = AppError;
+    async fn from_request_parts(_parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        Ok(Self::new(state.get_pool()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Local;
+
+    use super::*;
+    use crate::{misc::etag::IfMatch, services::test_support::*};
+
+    #[tokio::test]
+    async fn reports_must_keep_deleted_batches() -> Result<(), String> {
+        let service = RecallService::new(database().await?);
+
+        let parent = insert_batch(&service.batch_service, 100.0).await?;
+        let children = service
+            .batch_service
+            .split(parent.id().unwrap(), vec![child_of(&parent, 40.0)])
+            .await
+            .map_err(|e| e.to_string())?;
+        let child_id = children[0].id().unwrap();
+        service
+            .batch_service
+            .delete(child_id, &IfMatch::Any)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        let recall_of = |batch_id| {
+            Recall::new(
+                None,
+                None,
+                Some(batch_id),
+                None,
+                None,
+                None,
+                "Resíduo acima do limite".to_string(),
+                RecallStatus::Open,
+                Local::now().naive_local(),
+            )
+            .unwrap()
+        };
+        let recall = service
+            .insert(&recall_of(parent.id().unwrap()))
+            .await
+            .map_err(|e| e.to_string())?;
+        assert_eq!(recall.batch_ids(), &vec![parent.id().unwrap(), child_id]);
+
+        let report = service
+            .report(recall.id().unwrap())
+            .await
+            .map_err(|e| e.to_string())?;
+        assert_eq!(report.batches.len(), 2);
+        assert!(report.batches[1].deleted_at().is_some());
+
+        let recall = service
+            .insert(&recall_of(child_id))
+            .await
+            .map_err(|e| e.to_string())?;
+        assert_eq!(recall.batch_ids(), &vec![child_id]);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,316 @@
+use std::collections::{HashMap, HashSet};
+
+use axum::{async_trait, extract::FromRequestParts, http::request::Parts};
+use chrono::NaiveDate;
+use sqlx::SqlitePool;
+
+use crate::{
+    errors::AppError,
+    models::{AreaUnit, ComplianceReport, ComplianceRow, QuantityUnit, YieldGroupBy, YieldReport},
+    repositories::{
+        BatchRepository, CustomerRepository, FarmRepository, HarvestRepository, PlotRepository,
+        ShipmentRepository,
+    },
+    StateTrait,
+};
+
+use super::BatchService;
+
+pub struct ReportService {
+    batch_repository: BatchRepository,
+    harvest_repository: HarvestRepository,
+    shipment_repository: ShipmentRepository,
+    customer_repository: CustomerRepository,
+    plot_repository: PlotRepository,
+    farm_repository: FarmRepository,
+    batch_service: BatchService,
+}
+
+fn validate_period(from: Option<NaiveDate>, to: Option<NaiveDate>) -> Result<(), AppError> {
+    if let (Some(from), Some(to)) = (from, to) {
+        if to < from {
+            return Err(AppError::BadRequest(format!(
+                "A data final ({}) não pode ser anterior a data inicial ({})",
+                to.format("%d/%m/%Y"),
+                from.format("%d/%m/%Y")
+            )));
+        }
+    }
+
+    Ok(())
+}
+
+impl ReportService {
+    pub fn new(pool: Box<SqlitePool>) -> Self {
+        Self {
+            batch_repository: BatchRepository::new(pool.clone()),
+            harvest_repository: HarvestRepository::new(pool.clone()),
+            shipment_repository: ShipmentRepository::new(pool.clone()),
+            customer_repository: CustomerRepository::new(pool.clone()),
+            plot_repository: PlotRepository::new(pool.clone()),
+            farm_repository: FarmRepository::new(pool.clone()),
+            batch_service: BatchService::new(pool),
+        }
+    }
+
+    /// Yield in kg and productivity in kg/ha of the batches packed within
+    /// the period. Batches in packages whose weight isn't registered are
+    /// counted apart instead of failing the whole report.
+    pub async fn yield_report(
+        &self,
+        from: Option<NaiveDate>,
+        to: Option<NaiveDate>,
+        group_by: YieldGroupBy,
+    ) -> Result<YieldReport, AppError> {
+        validate_period(from, to)?;
+
+        let (mut batches, mut unconverted) = (vec![], vec![]);
+        for mut batch in self.batch_repository.find_packed(from, to).await? {
+            let mut crop = batch.crop().clone();
+            crop.convert_area(AreaUnit::Ha);
+            batch.set_crop(crop);
+            match self
+                .batch_service
+                .convert(batch.clone(), QuantityUnit::Kg)
+                .await
+            {
+                Ok(batch) => batches.push(batch),
+                Err(AppError::BadRequest(_)) => unconverted.push(batch),
+                Err(err) => return Err(err),
+            }
+        }
+
+        Ok(YieldReport::new(from, to, group_by, &batches, &unconverted))
+    }
+
+    /// Lots received within the period, dated by their harvest when it was
+    /// recorded, and the dispatches made within it. The producer is the farm
+    /// of the crop's plot. Lots split or merged from others were received as
+    /// those, so they only appear when dispatched.
+    pub async fn compliance_report(
+        &self,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> Result<ComplianceReport, AppError> {
+        validate_period(Some(from), Some(to))?;
+
+        let farms: HashMap<i64, _> = self
+            .farm_repository
+            .list()
+            .await?
+            .into_iter()
+            .map(|farm| (farm.id().unwrap(), farm))
+            .collect();
+        let plot_farms: HashMap<i64, i64> = self
+            .plot_repository
+            .list(None)
+            .await?
+            .iter()
+            .map(|plot| (plot.id().unwrap(), plot.farm_id()))
+            .collect();
+        let customers: HashMap<i64, _> = self
+            .customer_repository
+            .list()
+            .await?
+            .into_iter()
+            .map(|customer| (customer.id().unwrap(), customer))
+            .collect();
+        let shipments = self.shipment_repository.list(Some(from), Some(to)).await?;
+        let harvests: HashMap<i64, NaiveDate> = self
+            .harvest_repository
+            .list()
+            .await?
+            .into_iter()
+            .map(|harvest| (harvest.id().unwrap(), harvest.harvested_at()))
+            .collect();
+        let links = self.batch_repository.list_links().await?;
+        let derived: HashSet<i64> = links.iter().map(|(_, child_id, _)| *child_id).collect();
+
+        let mut rows = vec![];
+        for batch in self.batch_repository.list(true).await? {
+            let id = batch.id().unwrap();
+            let received_at = batch
+                .harvest_id()
+                .and_then(|harvest_id| harvests.get(&harvest_id).copied())
+                .unwrap_or(batch.date());
+            let farm = batch
+                .crop()
+                .plot_id()
+                .and_then(|plot_id| plot_farms.get(&plot_id))
+                .and_then(|farm_id| farms.get(farm_id));
+            let cultivation = batch.crop().cultivation();
+            let row = ComplianceRow {
+                producer_name: farm.map(|farm| farm.name().to_string()),
+                producer_document: farm.and_then(|farm| farm.document().clone()),
+                producer_address: farm.and_then(|farm| farm.address().clone()),
+                product: cultivation.name().to_string(),
+                variety: cultivation.variety().clone(),
+                lot: batch.tracking_code().clone().unwrap_or_default(),
+                quantity: batch.quantity()
+                    + links
+                        .iter()
+                        .filter(|(parent_id, _, _)| *parent_id == id)
+                        .map(|(_, _, quantity)| quantity)
+                        .sum::<f64>(),
+                quantity_unit: batch.quantity_unit(),
+                received_at,
+                dispatched_at: None,
+                dispatched_quantity: None,
+                invoice_number: None,
+                recipient_name: None,
+                recipient_document: None,
+                recipient_address: None,
+            };
+
+            let mut dispatched = false;
+            for shipment in &shipments {
+                let customer = customers.get(&shipment.customer_id());
+                for item in shipment.items().iter().filter(|item| item.batch_id() == id) {
+                    dispatched = true;
+                    rows.push(ComplianceRow {
+                        dispatched_at: Some(shipment.dispatched_at()),
+                        dispatched_quantity: Some(item.quantity()),
+                        invoice_number: Some(shipment.invoice_number().to_string()),
+                        recipient_name: customer.map(|customer| customer.name().to_string()),
+                        recipient_document: customer
+                            .and_then(|customer| customer.document().clone()),
+                        recipient_address: customer.and_then(|customer| customer.address().clone()),
+                        ..row.clone()
+                    });
+                }
+            }
+            if !dispatched && !derived.contains(&id) && (from..=to).contains(&received_at) {
+                rows.push(row);
+            }
+        }
+        rows.sort_by(|a, b| {
+            (a.received_at, &a.lot, a.dispatched_at).cmp(&(b.received_at, &b.lot, b.dispatched_at))
+        });
+
+        Ok(ComplianceReport { from, to, rows })
+    }
+}
+
+#[async_trait]
+impl<S> FromRequestParts<S> for ReportService
+where
+    S: Send + Sync + StateTrait,
+{
+    type Rejection = AppError;
+    async fn from_request_parts(_parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        Ok(Self::new(state.get_pool()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        models::{Batch, QuantityUnit, Shipment, ShipmentItem},
+        services::test_support::*,
+    };
+
+    fn date(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, 4, day).unwrap()
+    }
+
+    #[tokio::test]
+    async fn yields_must_count_apart_batches_that_cannot_be_weighed() -> Result<(), String> {
+        let service = ReportService::new(database().await?);
+        let crop = insert_crop(&service.batch_service).await?;
+        insert_batch_of(
+            &service.batch_service,
+            &crop,
+            "Caixa",
+            1000.0,
+            QuantityUnit::Kg,
+        )
+        .await?;
+        // "Caixa" has no weight registered.
+        insert_batch_of(
+            &service.batch_service,
+            &crop,
+            "Caixa",
+            10.0,
+            QuantityUnit::Box,
+        )
+        .await?;
+
+        let report = service
+            .yield_report(None, None, YieldGroupBy::Crop)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        assert_eq!(report.rows.len(), 1);
+        let row = &report.rows[0];
+        assert_eq!(row.batches, 1);
+        assert_eq!(row.unconverted, 1);
+        assert_eq!(row.quantity, 1000.0);
+        assert_eq!(row.productivity, Some(500.0));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn derived_lots_must_only_appear_when_dispatched() -> Result<(), String> {
+        let service = ReportService::new(database().await?);
+
+        let crop = insert_crop(&service.batch_service).await?;
+        insert_packing(&service.batch_service, "Caixa", None).await?;
+        let harvest = insert_harvest(&service.batch_service, &crop, 2).await?;
+        let mut parent = dated(&crop, 3, Some(harvest))?;
+        parent.set_quantity(100.0);
+        let parent = service
+            .batch_service
+            .insert(parent)
+            .await
+            .map_err(|e| e.to_string())?;
+        let children = service
+            .batch_service
+            .split(
+                parent.id().unwrap(),
+                vec![child_of(&parent, 40.0), child_of(&parent, 20.0)],
+            )
+            .await
+            .map_err(|e| e.to_string())?;
+        let customer = insert_customer(&service.batch_service).await?;
+        service
+            .shipment_repository
+            .insert(
+                Shipment::new(
+                    None,
+                    customer.id().unwrap(),
+                    date(5),
+                    "NF 1".to_string(),
+                    vec![ShipmentItem::new(None, children[0].id().unwrap(), 30.0).unwrap()],
+                )
+                .unwrap(),
+            )
+            .await
+            .map_err(|e| e.to_string())?;
+
+        let report = service
+            .compliance_report(date(1), date(30))
+            .await
+            .map_err(|e| e.to_string())?;
+
+        assert_eq!(report.rows.len(), 2);
+        let row = |batch: &Batch| {
+            report
+                .rows
+                .iter()
+                .find(|row| Some(&row.lot) == batch.tracking_code().as_ref())
+                .unwrap()
+        };
+        let received = row(&parent);
+        assert_eq!(received.quantity, 100.0);
+        assert_eq!(received.received_at, date(2));
+        assert_eq!(received.dispatched_at, None);
+        let dispatched = row(&children[0]);
+        assert_eq!(dispatched.dispatched_at, Some(date(5)));
+        assert_eq!(dispatched.dispatched_quantity, Some(30.0));
+        assert_eq!(dispatched.recipient_name.as_deref(), Some("Mercado"));
+
+        Ok(())
+    }
+}
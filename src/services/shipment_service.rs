@@ -0,0 +1,217 @@
+use std::collections::BTreeMap;
+
+use axum::{async_trait, extract::FromRequestParts, http::request::Parts};
+use sqlx::SqlitePool;
+
+use crate::{errors::AppError, models::Shipment, repositories::ShipmentRepository, StateTrait};
+
+use super::{BatchService, CustomerService};
+
+pub struct ShipmentService {
+    repository: ShipmentRepository,
+    batch_service: BatchService,
+    customer_service: CustomerService,
+}
+
+impl ShipmentService {
+    pub fn new(pool: Box<SqlitePool>) -> Self {
+        Self {
+            repository: ShipmentRepository::new(pool.clone()),
+            batch_service: BatchService::new(pool.clone()),
+            customer_service: CustomerService::new(pool),
+        }
+    }
+
+    /// Checks that the customer and every batch exist, and that no batch ships
+    /// more than it has available. `previous` is the stored version of the
+    /// shipment being updated, whose quantities are given back to the batches.
+    async fn validate(
+        &self,
+        shipment: &Shipment,
+        previous: Option<&Shipment>,
+    ) -> Result<(), AppError> {
+        self.customer_service
+            .find_by_id(shipment.customer_id())
+            .await?;
+
+        let mut quantities: BTreeMap<i64, f64> = BTreeMap::new();
+        for item in shipment.items() {
+            *quantities.entry(item.batch_id()).or_default() += item.quantity();
+        }
+
+        for (batch_id, quantity) in quantities {
+            let batch = self.batch_service.find_by_id(batch_id).await?;
+            let returned: f64 = previous
+                .map(|previous| {
+                    previous
+                        .items()
+                        .iter()
+                        .filter(|item| item.batch_id() == batch_id)
+                        .map(|item| item.quantity())
+                        .sum()
+                })
+                .unwrap_or_default();
+            let available = batch.available_quantity() + returned;
+
+            if quantity > available {
+                return Err(AppError::BadRequest(format!(
+                    "A quantidade expedida do lote {} ({}) excede a quantidade disponível ({})",
+                    batch.tracking_code().as_ref().unwrap(),
+                    quantity,
+                    available
+                )));
+            }
+            if shipment.dispatched_at() < batch.date() {
+                return Err(AppError::BadRequest(format!(
+                    "A data de expedição ({}) não pode ser anterior à data do lote {} ({})",
+                    shipment.dispatched_at().format("%d/%m/%Y"),
+                    batch.tracking_code().as_ref().unwrap(),
+                    batch.date().format("%d/%m/%Y")
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    pub async fn list(&self) -> Result<Vec<Shipment>, AppError> {
+        self.repository.list(None, None).await
+    }
+
+    pub async fn find_by_id(&self, id: i64) -> Result<Shipment, AppError> {
+        self.repository.find_by_id(id).await
+    }
+
+    pub async fn find_by_batch_id(&self, batch_id: i64) -> Result<Vec<Shipment>, AppError> {
+        self.batch_service.find_by_id(batch_id).await?;
+        self.repository.find_by_batch_id(batch_id).await
+    }
+
+    pub async fn insert(&self, shipment: &Shipment) -> Result<Shipment, AppError> {
+        self.validate(shipment, None).await?;
+        self.repository.insert(shipment.clone()).await
+    }
+
+    pub async fn update(&self, id: i64, shipment: &Shipment) -> Result<Shipment, AppError> {
+        let previous = self.find_by_id(id).await?;
+        self.validate(shipment, Some(&previous)).await?;
+        self.repository.update(id, shipment.clone()).await
+    }
+
+    pub async fn delete(&self, id: i64) -> Result<(), AppError> {
+        self.find_by_id(id).await?;
+        self.repository.delete(id).await
+    }
+}
+
+#[async_trait]
+impl<S> FromRequestParts<S> for ShipmentService
+where
+    S: Send + Sync + StateTrait,
+{
+    type Rejection = AppError;
+    async fn from_request_parts(_parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        Ok(Self::new(state.get_pool()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        misc::etag::IfMatch,
+        models::{QuantityUnit, ShipmentItem},
+        services::test_support::*,
+    };
+
+    /// Service over an empty database holding one customer and one batch of
+    /// `quantity` kg, returned as `(service, customer_id, batch_id)`.
+    async fn init(quantity: f64) -> Result<(ShipmentService, i64, i64), String> {
+        let service = ShipmentService::new(database().await?);
+        let customer = insert_customer(&service.batch_service).await?;
+        let crop = insert_crop(&service.batch_service).await?;
+        let batch = insert_batch_of(
+            &service.batch_service,
+            &crop,
+            "Caixa",
+            quantity,
+            QuantityUnit::Kg,
+        )
+        .await?;
+
+        Ok((service, customer.id().unwrap(), batch.id().unwrap()))
+    }
+
+    fn shipment(customer_id: i64, batch_id: i64, quantity: f64) -> Shipment {
+        Shipment::new(
+            None,
+            customer_id,
+            date(),
+            "NF 1".to_string(),
+            vec![ShipmentItem::new(None, batch_id, quantity).unwrap()],
+        )
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn shipments_must_not_exceed_available_quantity() -> Result<(), String> {
+        let (service, customer_id, batch_id) = init(100.0).await?;
+
+        let first = service
+            .insert(&shipment(customer_id, batch_id, 60.0))
+            .await
+            .map_err(|e| e.to_string())?;
+        let result = service.insert(&shipment(customer_id, batch_id, 50.0)).await;
+        assert!(matches!(result, Err(AppError::BadRequest(_))));
+
+        // An update gets back the quantity of the shipment it replaces.
+        service
+            .update(first.id().unwrap(), &shipment(customer_id, batch_id, 100.0))
+            .await
+            .map_err(|e| e.to_string())?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn shipments_checked_against_a_stale_read_must_not_over_ship() -> Result<(), String> {
+        let (service, customer_id, batch_id) = init(100.0).await?;
+
+        service
+            .insert(&shipment(customer_id, batch_id, 60.0))
+            .await
+            .map_err(|e| e.to_string())?;
+        // As if its check had run before the first shipment was stored.
+        let result = service
+            .repository
+            .insert(shipment(customer_id, batch_id, 50.0))
+            .await;
+
+        assert!(matches!(result, Err(AppError::BadRequest(_))));
+        assert_eq!(service.list().await.map_err(|e| e.to_string())?.len(), 1);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn shipments_checked_against_a_stale_read_must_not_ship_deleted_batches(
+    ) -> Result<(), String> {
+        let (service, customer_id, batch_id) = init(100.0).await?;
+
+        service
+            .batch_service
+            .delete(batch_id, &IfMatch::Any)
+            .await
+            .map_err(|e| e.to_string())?;
+        // As if its check had run before the batch was deleted.
+        let result = service
+            .repository
+            .insert(shipment(customer_id, batch_id, 50.0))
+            .await;
+
+        assert!(matches!(result, Err(AppError::BadRequest(_))));
+        assert!(service.list().await.map_err(|e| e.to_string())?.is_empty());
+
+        Ok(())
+    }
+}
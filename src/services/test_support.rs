@@ -0,0 +1,209 @@
+use chrono::NaiveDate;
+use sqlx::SqlitePool;
+
+use crate::{
+    models::{
+        AreaUnit, Batch, Crop, Cultivation, Customer, Harvest, Packing, QuantityUnit, Shipment,
+        ShipmentItem,
+    },
+    repositories::{
+        CultivationRepository, CustomerRepository, HarvestRepository, PackingRepository,
+    },
+};
+
+use super::{BatchService, CropService, ShipmentService};
+
+/// Empty in-memory database with every migration run.
+pub async fn database() -> Result<Box<SqlitePool>, String> {
+    let pool = SqlitePool::connect("sqlite::memory:")
+        .await
+        .map_err(|e| e.to_string())?;
+    sqlx::migrate!("./migrations")
+        .run(&pool)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    Ok(Box::new(pool))
+}
+
+pub fn date() -> NaiveDate {
+    NaiveDate::from_ymd_opt(2024, 4, 2).unwrap()
+}
+
+/// Crop "Talhão 1" of 2 ha of a new "Tomate" cultivation, planted on
+/// [`date`].
+pub async fn insert_crop(service: &BatchService) -> Result<Crop, String> {
+    let cultivation = CultivationRepository::new(service.pool())
+        .insert(
+            Cultivation::new(None, "Tomate".to_string(), None, None, None, None)
+                .map_err(|e| e.to_string())?,
+        )
+        .await
+        .map_err(|e| e.to_string())?;
+    CropService::new(service.pool())
+        .insert(
+            &Crop::new(
+                None,
+                "Talhão 1".to_string(),
+                2.0,
+                AreaUnit::Ha,
+                cultivation,
+                date(),
+                None,
+                None,
+            )
+            .map_err(|e| e.to_string())?,
+        )
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Registers packing `code` unless it already is.
+pub async fn insert_packing(
+    service: &BatchService,
+    code: &str,
+    kg_per_unit: Option<f64>,
+) -> Result<(), String> {
+    let repository = PackingRepository::new(service.pool());
+    if repository
+        .find_by_code(code)
+        .await
+        .map_err(|e| e.to_string())?
+        .is_none()
+    {
+        let packing = Packing::new(None, code.to_string(), code.to_string(), kg_per_unit, true)
+            .map_err(|e| e.to_string())?;
+        repository
+            .insert(packing)
+            .await
+            .map_err(|e| e.to_string())?;
+    }
+
+    Ok(())
+}
+
+pub async fn insert_batch_of(
+    service: &BatchService,
+    crop: &Crop,
+    packing: &str,
+    quantity: f64,
+    quantity_unit: QuantityUnit,
+) -> Result<Batch, String> {
+    insert_packing(service, packing, None).await?;
+    let batch = Batch::new(
+        None,
+        crop.clone(),
+        None,
+        None,
+        packing.to_string(),
+        quantity,
+        quantity_unit,
+        None,
+        date(),
+        None,
+        None,
+    )
+    .map_err(|e| e.to_string())?;
+
+    service.insert(batch).await.map_err(|e| e.to_string())
+}
+
+/// Batch of `quantity` kg in boxes of a new crop.
+pub async fn insert_batch(service: &BatchService, quantity: f64) -> Result<Batch, String> {
+    let crop = insert_crop(service).await?;
+    insert_batch_of(service, &crop, "Caixa", quantity, QuantityUnit::Kg).await
+}
+
+pub fn child_of(parent: &Batch, quantity: f64) -> Batch {
+    packed_child_of(parent, "Caixa", quantity, QuantityUnit::Kg)
+}
+
+pub fn packed_child_of(
+    parent: &Batch,
+    packing: &str,
+    quantity: f64,
+    quantity_unit: QuantityUnit,
+) -> Batch {
+    Batch::new(
+        None,
+        parent.crop().clone(),
+        None,
+        None,
+        packing.to_string(),
+        quantity,
+        quantity_unit,
+        None,
+        parent.date(),
+        None,
+        None,
+    )
+    .unwrap()
+}
+
+/// Batch of 10 kg in boxes of `crop`, packed on April `day`.
+pub fn dated(crop: &Crop, day: u32, harvest_id: Option<i64>) -> Result<Batch, String> {
+    Batch::new(
+        None,
+        crop.clone(),
+        None,
+        None,
+        "Caixa".to_string(),
+        10.0,
+        QuantityUnit::Kg,
+        None,
+        NaiveDate::from_ymd_opt(2024, 4, day).unwrap(),
+        harvest_id,
+        None,
+    )
+    .map_err(|e| e.to_string())
+}
+
+/// Harvest of 100 kg of `crop` on April `day`, returning its ID.
+pub async fn insert_harvest(service: &BatchService, crop: &Crop, day: u32) -> Result<i64, String> {
+    let harvest = Harvest::new(
+        None,
+        crop.id().unwrap(),
+        NaiveDate::from_ymd_opt(2024, 4, day).unwrap(),
+        100.0,
+        QuantityUnit::Kg,
+        None,
+    )
+    .map_err(|e| e.to_string())?;
+    let harvest = HarvestRepository::new(service.pool())
+        .insert(harvest)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    Ok(harvest.id().unwrap())
+}
+
+pub async fn insert_customer(service: &BatchService) -> Result<Customer, String> {
+    CustomerRepository::new(service.pool())
+        .insert(
+            Customer::new(None, "Mercado".to_string(), None, None, None)
+                .map_err(|e| e.to_string())?,
+        )
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Ships `quantity` of batch `id` to a new customer on [`date`].
+pub async fn ship(service: &BatchService, id: i64, quantity: f64) -> Result<(), String> {
+    let customer = insert_customer(service).await?;
+    let items = vec![ShipmentItem::new(None, id, quantity).map_err(|e| e.to_string())?];
+    ShipmentService::new(service.pool())
+        .insert(
+            &Shipment::new(
+                None,
+                customer.id().unwrap(),
+                date(),
+                "NF 1".to_string(),
+                items,
+            )
+            .map_err(|e| e.to_string())?,
+        )
+        .await
+        .map_err(|e| e.to_string())?;
+
+    Ok(())
+}